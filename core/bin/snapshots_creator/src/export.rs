@@ -0,0 +1,215 @@
+//! Export of snapshot metadata to a local file.
+
+use std::{fmt, io, path::Path, str::FromStr};
+
+use anyhow::Context as _;
+use serde::{Deserialize, Serialize};
+use zksync_dal::{ConnectionPool, Core, CoreDal};
+use zksync_types::{snapshots::SnapshotMetadata, L1BatchNumber};
+
+/// Serialization format for exported snapshot metadata.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum ExportFormat {
+    #[default]
+    Json,
+    Cbor,
+}
+
+impl fmt::Display for ExportFormat {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Json => "json",
+            Self::Cbor => "cbor",
+        })
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "cbor" => Ok(Self::Cbor),
+            _ => anyhow::bail!("unknown snapshot export format `{s}`; expected `json` or `cbor`"),
+        }
+    }
+}
+
+/// Metadata of a complete snapshot, as written to the export file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SnapshotExport {
+    pub version: u16,
+    pub l1_batch_number: L1BatchNumber,
+    pub factory_deps_filepath: String,
+    pub storage_logs_filepaths: Vec<String>,
+}
+
+impl SnapshotExport {
+    fn new(metadata: SnapshotMetadata) -> anyhow::Result<Self> {
+        let storage_logs_filepaths = metadata
+            .storage_logs_filepaths
+            .into_iter()
+            .enumerate()
+            .map(|(chunk_id, path)| {
+                path.with_context(|| format!("storage logs chunk #{chunk_id} is not produced yet"))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self {
+            version: metadata.version.into(),
+            l1_batch_number: metadata.l1_batch_number,
+            factory_deps_filepath: metadata.factory_deps_filepath,
+            storage_logs_filepaths,
+        })
+    }
+
+    pub fn serialize(&self, format: ExportFormat) -> anyhow::Result<Vec<u8>> {
+        Ok(match format {
+            ExportFormat::Json => serde_json::to_vec_pretty(self)?,
+            ExportFormat::Cbor => {
+                let mut bytes = vec![];
+                ciborium::into_writer(self, &mut bytes)?;
+                bytes
+            }
+        })
+    }
+
+    /// Deserializes an export in any supported format. The format is detected from the leading bytes:
+    /// a JSON export is an object (i.e., starts with `{` after optional whitespace), while a CBOR export
+    /// starts with a map header byte, which is never an ASCII whitespace or `{`.
+    pub fn deserialize(bytes: &[u8]) -> anyhow::Result<Self> {
+        let first_byte = bytes.iter().find(|byte| !byte.is_ascii_whitespace());
+        if first_byte == Some(&b'{') {
+            serde_json::from_slice(bytes).context("failed deserializing JSON snapshot export")
+        } else {
+            ciborium::from_reader(bytes).context("failed deserializing CBOR snapshot export")
+        }
+    }
+}
+
+/// Writes metadata of the newest complete snapshot to the specified file.
+pub(crate) async fn export_newest_snapshot(
+    pool: &ConnectionPool<Core>,
+    path: &Path,
+    format: ExportFormat,
+) -> anyhow::Result<()> {
+    let mut conn = pool.connection_tagged("snapshots_creator").await?;
+    let metadata = conn
+        .snapshots_dal()
+        .get_newest_snapshot_metadata()
+        .await?
+        .context("no snapshots to export")?;
+    drop(conn);
+
+    let l1_batch_number = metadata.l1_batch_number;
+    let export = SnapshotExport::new(metadata)?;
+    if let Some(previous_export) = read_export(path).await? {
+        tracing::info!(
+            "Replacing metadata of snapshot for L1 batch {} previously exported to `{}`",
+            previous_export.l1_batch_number,
+            path.display()
+        );
+    }
+    let bytes = export.serialize(format)?;
+    tokio::fs::write(path, bytes)
+        .await
+        .with_context(|| format!("failed writing snapshot export to `{}`", path.display()))?;
+    tracing::info!(
+        "Exported metadata of snapshot for L1 batch {l1_batch_number} to `{}` as {format}",
+        path.display()
+    );
+    Ok(())
+}
+
+/// Reads a previously exported snapshot metadata in any supported format. Returns `Ok(None)` if the file doesn't exist
+/// or doesn't contain an export (in the latter case, a warning is logged since the file will be overwritten).
+async fn read_export(path: &Path) -> anyhow::Result<Option<SnapshotExport>> {
+    let bytes = match tokio::fs::read(path).await {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => {
+            return Err(err).with_context(|| {
+                format!(
+                    "failed reading existing snapshot export `{}`",
+                    path.display()
+                )
+            })
+        }
+    };
+    match SnapshotExport::deserialize(&bytes) {
+        Ok(export) => Ok(Some(export)),
+        Err(err) => {
+            tracing::warn!(
+                "Existing file `{}` is not a snapshot export; it will be overwritten: {err:#}",
+                path.display()
+            );
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_types::snapshots::SnapshotVersion;
+
+    use super::*;
+
+    fn mock_metadata() -> SnapshotMetadata {
+        SnapshotMetadata {
+            version: SnapshotVersion::Version1,
+            l1_batch_number: L1BatchNumber(42),
+            factory_deps_filepath: "snapshot_l1_batch_42_factory_deps.proto.gzip".to_owned(),
+            storage_logs_filepaths: vec![
+                Some("snapshot_l1_batch_42_storage_logs_part_0000.proto.gzip".to_owned()),
+                Some("snapshot_l1_batch_42_storage_logs_part_0001.proto.gzip".to_owned()),
+            ],
+        }
+    }
+
+    #[test]
+    fn export_format_is_parsed() {
+        assert_eq!("json".parse::<ExportFormat>().unwrap(), ExportFormat::Json);
+        assert_eq!("cbor".parse::<ExportFormat>().unwrap(), ExportFormat::Cbor);
+        "yaml".parse::<ExportFormat>().unwrap_err();
+    }
+
+    #[test]
+    fn incomplete_snapshot_cannot_be_exported() {
+        let mut metadata = mock_metadata();
+        metadata.storage_logs_filepaths[1] = None;
+        let err = SnapshotExport::new(metadata).unwrap_err();
+        assert!(err.to_string().contains("#1"), "{err}");
+    }
+
+    #[test]
+    fn export_roundtrip() {
+        let export = SnapshotExport::new(mock_metadata()).unwrap();
+
+        let json = export.serialize(ExportFormat::Json).unwrap();
+        assert_eq!(json[0], b'{');
+        assert_eq!(SnapshotExport::deserialize(&json).unwrap(), export);
+
+        let cbor = export.serialize(ExportFormat::Cbor).unwrap();
+        assert_ne!(cbor, json);
+        assert_eq!(SnapshotExport::deserialize(&cbor).unwrap(), export);
+    }
+
+    #[tokio::test]
+    async fn reading_previous_export() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("snapshot.export");
+        assert_eq!(read_export(&path).await.unwrap(), None);
+
+        let export = SnapshotExport::new(mock_metadata()).unwrap();
+        for format in [ExportFormat::Json, ExportFormat::Cbor] {
+            tokio::fs::write(&path, export.serialize(format).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(read_export(&path).await.unwrap(), Some(export.clone()));
+        }
+
+        tokio::fs::write(&path, b"not an export").await.unwrap();
+        assert_eq!(read_export(&path).await.unwrap(), None);
+    }
+}
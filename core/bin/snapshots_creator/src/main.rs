@@ -18,9 +18,13 @@ use zksync_dal::{ConnectionPool, Core};
 use zksync_object_store::ObjectStoreFactory;
 use zksync_vlog::prometheus::PrometheusExporterConfig;
 
-use crate::creator::SnapshotCreator;
+use crate::{
+    creator::SnapshotCreator,
+    export::{export_newest_snapshot, ExportFormat},
+};
 
 mod creator;
+mod export;
 mod metrics;
 #[cfg(test)]
 mod tests;
@@ -57,6 +61,14 @@ struct Opt {
     /// Path to the secrets file.
     #[structopt(long)]
     secrets_path: Option<std::path::PathBuf>,
+
+    /// If specified, metadata of the newest complete snapshot is written to this file after the snapshot is created.
+    #[structopt(long)]
+    export_path: Option<std::path::PathBuf>,
+
+    /// Format of the exported snapshot metadata: `json` or `cbor`.
+    #[structopt(long, default_value = "json")]
+    export_format: ExportFormat,
 }
 
 #[tokio::main]
@@ -101,12 +113,15 @@ async fn main() -> anyhow::Result<()> {
 
     let creator = SnapshotCreator {
         blob_store,
-        master_pool,
+        master_pool: master_pool.clone(),
         replica_pool,
         #[cfg(test)]
         event_listener: Box::new(()),
     };
     creator.run(creator_config, MIN_CHUNK_COUNT).await?;
+    if let Some(export_path) = &opt.export_path {
+        export_newest_snapshot(&master_pool, export_path, opt.export_format).await?;
+    }
 
     tracing::info!("Finished running snapshot creator!");
     stop_sender.send(true).ok();
@@ -131,6 +131,10 @@ pub struct StateKeeperConfig {
     /// which is capable of saving protective reads is run.
     #[serde(default)]
     pub protective_reads_persistence_enabled: bool,
+    /// Addresses allowed to deploy contracts in L2 transactions. L2 transactions deploying contracts
+    /// from other addresses are rejected. If empty, contract deployments are not restricted.
+    #[serde(default)]
+    pub whitelisted_deployers: Vec<Address>,
 
     // Base system contract hashes, required only for generating genesis config.
     // #PLA-811
@@ -178,6 +182,7 @@ impl StateKeeperConfig {
             save_call_traces: true,
             max_circuits_per_batch: 24100,
             protective_reads_persistence_enabled: true,
+            whitelisted_deployers: vec![],
             bootloader_hash: None,
             default_aa_hash: None,
             evm_emulator_hash: None,
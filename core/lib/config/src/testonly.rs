@@ -194,6 +194,7 @@ impl Distribution<configs::chain::StateKeeperConfig> for EncodeDist {
             save_call_traces: self.sample(rng),
             max_circuits_per_batch: self.sample(rng),
             protective_reads_persistence_enabled: self.sample(rng),
+            whitelisted_deployers: self.sample_range(rng).map(|_| rng.gen()).collect(),
             // These values are not involved into files serialization skip them
             fee_account_addr: None,
             bootloader_hash: None,
@@ -106,6 +106,10 @@ mod tests {
             l1_batch_commit_data_generator_mode,
             max_circuits_per_batch: 24100,
             protective_reads_persistence_enabled: true,
+            whitelisted_deployers: vec![
+                addr("0x0000000000000000000000000000000000000001"),
+                addr("0x0000000000000000000000000000000000000002"),
+            ],
         }
     }
 
@@ -139,6 +143,7 @@ mod tests {
             CHAIN_STATE_KEEPER_BOOTLOADER_HASH=0x010007ede999d096c84553fb514d3d6ca76fbf39789dda76bfeda9f3ae06236e
             CHAIN_STATE_KEEPER_DEFAULT_AA_HASH=0x0100055b041eb28aff6e3a6e0f37c31fd053fc9ef142683b05e5f0aee6934066
             CHAIN_STATE_KEEPER_PROTECTIVE_READS_PERSISTENCE_ENABLED=true
+            CHAIN_STATE_KEEPER_WHITELISTED_DEPLOYERS="0x0000000000000000000000000000000000000001,0x0000000000000000000000000000000000000002"
             CHAIN_STATE_KEEPER_L1_BATCH_COMMIT_DATA_GENERATOR_MODE="{l1_batch_commit_data_generator_mode}"
         "#
         )
@@ -34,7 +34,7 @@ fn encode_function_call(
     name: &str,
     types: &[ParamType],
     params: &[Token],
-) -> Result<String, ethabi::Error> {
+) -> Result<VmRevertReason, ethabi::Error> {
     let short_sig = ethabi::short_signature(name, types);
 
     // Check if the provided number of parameters matches the function's expected inputs
@@ -48,8 +48,7 @@ fn encode_function_call(
     Ok(VmRevertReason::Unknown {
         function_selector: short_sig.to_vec(),
         data: [short_sig.to_vec(), encoded_data].concat(),
-    }
-    .to_string())
+    })
 }
 
 fn get_l1_noop() -> Transaction {
@@ -95,8 +94,8 @@ pub(crate) fn test_l2_block_initialization_timestamp<VM: TestedVm>() {
 
     assert_matches!(
         res.result,
-        ExecutionResult::Halt { reason: Halt::FailedToSetL2Block(msg) }
-            if msg.contains("0x5e9ad9b0")
+        ExecutionResult::Halt { reason: Halt::FailedToSetL2Block(reason) }
+            if reason.to_string().contains("0x5e9ad9b0")
     );
 }
 
@@ -15,13 +15,12 @@ use once_cell::sync::Lazy;
 use zksync_contracts::{
     read_bootloader_code, read_zbin_bytecode, BaseSystemContracts, SystemContractCode,
 };
-use zksync_system_constants::CONTRACT_DEPLOYER_ADDRESS;
 use zksync_types::{
     block::L2BlockHasher,
     bytecode::{pad_evm_bytecode, BytecodeHash},
     fee_model::BatchFeeInput,
-    get_code_key, get_evm_code_hash_key, get_is_account_key, get_known_code_key, h256_to_address,
-    h256_to_u256, u256_to_h256,
+    get_code_key, get_evm_code_hash_key, get_is_account_key, get_known_code_key, h256_to_u256,
+    u256_to_h256,
     utils::storage_key_for_eth_balance,
     web3, Address, L1BatchNumber, L2BlockNumber, L2ChainId, ProtocolVersionId, H256, U256,
 };
@@ -107,6 +106,7 @@ pub(super) fn default_system_env() -> SystemEnv {
         execution_mode: TxExecutionMode::VerifyExecute,
         default_validation_computational_gas_limit: BATCH_COMPUTATIONAL_GAS_LIMIT,
         chain_id: L2ChainId::from(270),
+        whitelisted_deployers: None,
     }
 }
 
@@ -216,16 +216,7 @@ impl ContractToDeploy {
 fn extract_deploy_events(events: &[VmEvent]) -> Vec<(Address, Address)> {
     events
         .iter()
-        .filter_map(|event| {
-            if event.address == CONTRACT_DEPLOYER_ADDRESS
-                && event.indexed_topics[0] == VmEvent::DEPLOY_EVENT_SIGNATURE
-            {
-                let deployer = h256_to_address(&event.indexed_topics[1]);
-                let deployed_address = h256_to_address(&event.indexed_topics[3]);
-                Some((deployer, deployed_address))
-            } else {
-                None
-            }
-        })
+        .filter_map(VmEvent::decode_deploy_event)
+        .map(|event| (event.deployer, event.contract_address))
         .collect()
 }
@@ -1,3 +1,4 @@
+use assert_matches::assert_matches;
 use zksync_contracts::{deployer_contract, load_sys_contract};
 use zksync_test_contracts::{TestContract, TxType};
 use zksync_types::{
@@ -12,7 +13,8 @@ use zksync_types::{
 
 use super::{get_empty_storage, tester::VmTesterBuilder, TestedVm};
 use crate::interface::{
-    ExecutionResult, Halt, InspectExecutionMode, TxExecutionMode, VmInterfaceExt,
+    BootloaderInternalError, ExecutionResult, Halt, InspectExecutionMode, TxExecutionMode,
+    VmInterfaceExt,
 };
 
 /// In this test we ensure that the requirements for protocol upgrade transactions are enforced by the bootloader:
@@ -65,8 +67,14 @@ pub(crate) fn test_protocol_upgrade_is_first<VM: TestedVm>() {
         )
         .tx;
 
-    let expected_error =
-        Halt::UnexpectedVMBehavior("Assertion error: Protocol upgrade tx not first".to_string());
+    let assert_expected_error = |result: ExecutionResult| {
+        assert_matches!(
+            result,
+            ExecutionResult::Halt {
+                reason: Halt::BootloaderInternalError(BootloaderInternalError::AssertionError(reason))
+            } if reason.to_string() == "Protocol upgrade tx not first"
+        );
+    };
 
     vm.vm.make_snapshot();
     // Test 1: there must be only one system transaction in block
@@ -77,12 +85,7 @@ pub(crate) fn test_protocol_upgrade_is_first<VM: TestedVm>() {
     vm.vm.execute(InspectExecutionMode::OneTx);
     vm.vm.execute(InspectExecutionMode::OneTx);
     let result = vm.vm.execute(InspectExecutionMode::OneTx);
-    assert_eq!(
-        result.result,
-        ExecutionResult::Halt {
-            reason: expected_error.clone()
-        }
-    );
+    assert_expected_error(result.result);
 
     // Test 2: the protocol upgrade tx must be the first one in block
     vm.vm.rollback_to_the_latest_snapshot();
@@ -92,12 +95,7 @@ pub(crate) fn test_protocol_upgrade_is_first<VM: TestedVm>() {
 
     vm.vm.execute(InspectExecutionMode::OneTx);
     let result = vm.vm.execute(InspectExecutionMode::OneTx);
-    assert_eq!(
-        result.result,
-        ExecutionResult::Halt {
-            reason: expected_error
-        }
-    );
+    assert_expected_error(result.result);
 
     vm.vm.rollback_to_the_latest_snapshot();
     vm.vm.make_snapshot();
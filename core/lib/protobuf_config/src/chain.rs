@@ -2,7 +2,7 @@ use anyhow::Context as _;
 use zksync_config::configs;
 use zksync_protobuf::{repr::ProtoRepr, required};
 
-use crate::proto::chain as proto;
+use crate::{parse_h160, proto::chain as proto};
 
 impl proto::FeeModelVersion {
     fn new(n: &configs::chain::FeeModelVersion) -> Self {
@@ -81,6 +81,13 @@ impl ProtoRepr for proto::StateKeeper {
             protective_reads_persistence_enabled: self
                 .protective_reads_persistence_enabled
                 .unwrap_or_default(),
+            whitelisted_deployers: self
+                .whitelisted_deployers
+                .iter()
+                .enumerate()
+                .map(|(i, addr)| parse_h160(addr).context(i))
+                .collect::<anyhow::Result<_>>()
+                .context("whitelisted_deployers")?,
 
             // We need these values only for instantiating configs from environmental variables, so it's not
             // needed during the initialization from files
@@ -120,6 +127,11 @@ impl ProtoRepr for proto::StateKeeper {
             save_call_traces: Some(this.save_call_traces),
             max_circuits_per_batch: Some(this.max_circuits_per_batch.try_into().unwrap()),
             protective_reads_persistence_enabled: Some(this.protective_reads_persistence_enabled),
+            whitelisted_deployers: this
+                .whitelisted_deployers
+                .iter()
+                .map(|addr| format!("{addr:?}"))
+                .collect(),
         }
     }
 }
@@ -356,6 +356,7 @@ mod tests {
                 execution_mode: TxExecutionMode::VerifyExecute,
                 default_validation_computational_gas_limit: 0,
                 chain_id: Default::default(),
+                whitelisted_deployers: None,
             },
             Default::default(),
         );
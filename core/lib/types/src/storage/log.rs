@@ -24,7 +24,7 @@ pub struct StorageLog {
     pub value: StorageValue,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct StorageLogWithPreviousValue {
     pub log: StorageLog,
     pub previous_value: StorageValue,
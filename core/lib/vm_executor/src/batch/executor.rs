@@ -9,7 +9,7 @@ use tokio::{
 use zksync_multivm::interface::{
     executor::BatchExecutor,
     storage::{ReadStorage, StorageView},
-    BatchTransactionExecutionResult, FinishedL1Batch, L2BlockEnv,
+    BatchTransactionExecutionResult, FinishedL1Batch, L2BlockEnv, TxExecutionStatus,
 };
 use zksync_types::Transaction;
 
@@ -98,7 +98,7 @@ where
         };
         let elapsed = latency.observe();
 
-        if !res.tx_result.result.is_failed() {
+        if res.execution_status() == TxExecutionStatus::Success {
             let gas_used = res.tx_result.statistics.computational_gas_used;
             EXECUTOR_METRICS
                 .computational_gas_per_nanosecond
@@ -1,4 +1,4 @@
-use std::{fmt, marker::PhantomData, rc::Rc, sync::Arc, time::Duration};
+use std::{collections::HashSet, fmt, marker::PhantomData, rc::Rc, sync::Arc, time::Duration};
 
 use anyhow::Context as _;
 use once_cell::sync::OnceCell;
@@ -9,8 +9,9 @@ use zksync_multivm::{
         pubdata::PubdataBuilder,
         storage::{ReadStorage, StoragePtr, StorageView, StorageViewStats},
         utils::{DivergenceHandler, ShadowMut},
-        BatchTransactionExecutionResult, Call, ExecutionResult, FinishedL1Batch, Halt, L1BatchEnv,
-        L2BlockEnv, SystemEnv, VmFactory, VmInterface, VmInterfaceHistoryEnabled,
+        BatchTransactionExecutionResult, BytecodeCompressionMetrics, Call, ExecutionResult,
+        FinishedL1Batch, Halt, L1BatchEnv, L2BlockEnv, SystemEnv, TxExecutionStatus, VmEvent,
+        VmFactory, VmInterface, VmInterfaceHistoryEnabled,
     },
     is_supported_by_fast_vm,
     pubdata_builders::pubdata_params_to_builder,
@@ -20,7 +21,9 @@ use zksync_multivm::{
     vm_latest::HistoryEnabled,
     FastVmInstance, LegacyVmInstance, MultiVmTracer,
 };
-use zksync_types::{commitment::PubdataParams, vm::FastVmMode, Transaction};
+use zksync_types::{
+    commitment::PubdataParams, vm::FastVmMode, Address, ExecuteTransactionCommon, Transaction,
+};
 
 use super::{
     executor::{Command, MainBatchExecutor},
@@ -271,7 +274,8 @@ impl<S: ReadStorage, Tr: BatchTracer> BatchVm<S, Tr> {
             }
         };
 
-        let compressed_bytecodes = compression_result.map(drop);
+        let compression_metrics = compression_result
+            .map(|bytecodes| BytecodeCompressionMetrics::from_bytecodes(&bytecodes));
         let legacy_traces = Arc::try_unwrap(legacy_tracer_result)
             .expect("failed extracting call traces")
             .take()
@@ -290,8 +294,10 @@ impl<S: ReadStorage, Tr: BatchTracer> BatchVm<S, Tr> {
 
         BatchTransactionExecutionResult {
             tx_result: Box::new(tx_result),
-            compression_result: compressed_bytecodes,
+            compression_result: compression_metrics,
             call_traces,
+            // Set by the caller, which measures the entire execution (including potential re-execution).
+            execution_time: Duration::ZERO,
         }
     }
 }
@@ -325,6 +331,7 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
         tracing::info!("Starting executing L1 batch #{}", &l1_batch_params.number);
 
         let storage_view = StorageView::new(storage).to_rc_ptr();
+        let whitelisted_deployers = system_env.whitelisted_deployers.clone();
         let mut vm = BatchVm::<S, Tr>::new(
             l1_batch_params,
             system_env,
@@ -350,14 +357,20 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
             match cmd {
                 Command::ExecuteTx(tx, resp) => {
                     let tx_hash = tx.hash();
-                    let (result, latency) = self.execute_tx(*tx, &mut vm).with_context(|| {
-                        format!("fatal error executing transaction {tx_hash:?}")
-                    })?;
+                    let result = self
+                        .execute_tx(*tx, &mut vm, whitelisted_deployers.as_ref())
+                        .with_context(|| {
+                            format!("fatal error executing transaction {tx_hash:?}")
+                        })?;
 
                     if self.observe_storage_metrics {
                         let storage_stats = storage_view.borrow().stats();
                         let stats_diff = storage_stats.saturating_sub(&prev_storage_stats);
-                        STORAGE_METRICS.observe(&format!("Tx {tx_hash:?}"), latency, &stats_diff);
+                        STORAGE_METRICS.observe(
+                            &format!("Tx {tx_hash:?}"),
+                            result.execution_time,
+                            &stats_diff,
+                        );
                         prev_storage_stats = storage_stats;
                     }
                     if resp.send(result).is_err() {
@@ -408,7 +421,8 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
         &self,
         transaction: Transaction,
         vm: &mut BatchVm<S, Tr>,
-    ) -> anyhow::Result<(BatchTransactionExecutionResult, Duration)> {
+        whitelisted_deployers: Option<&HashSet<Address>>,
+    ) -> anyhow::Result<BatchTransactionExecutionResult> {
         // Executing a next transaction means that a previous transaction was either rolled back (in which case its snapshot
         // was already removed), or that we build on top of it (in which case, it can be removed now).
         vm.pop_snapshot_no_rollback();
@@ -417,13 +431,24 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
 
         // Execute the transaction.
         let latency = KEEPER_METRICS.tx_execution_time[&TxExecutionStage::Execution].start();
-        let result = if self.optional_bytecode_compression {
+        let mut result = if self.optional_bytecode_compression {
             self.execute_tx_in_vm_with_optional_compression(&transaction, vm)?
         } else {
             self.execute_tx_in_vm(&transaction, vm)?
         };
 
-        Ok((result, latency.observe()))
+        let is_l2_tx = matches!(transaction.common_data, ExecuteTransactionCommon::L2(_));
+        if let Some(whitelisted_deployers) = whitelisted_deployers.filter(|_| is_l2_tx) {
+            let events = &result.tx_result.logs.events;
+            if let Err(reason) = VmEvent::validate_deployments(events, whitelisted_deployers) {
+                // Reject the transaction similarly to failed bytecode compression; the state keeper will roll it back.
+                result.tx_result.result = ExecutionResult::Halt { reason };
+                result.call_traces = vec![];
+            }
+        }
+
+        result.execution_time = latency.observe();
+        Ok(result)
     }
 
     fn rollback_last_tx(&self, vm: &mut BatchVm<S, Tr>) {
@@ -441,7 +466,7 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
         // There is some post-processing work that the VM needs to do before the block is fully processed.
         let result = vm.finish_batch(pubdata_builder);
         anyhow::ensure!(
-            !result.block_tip_execution_result.result.is_failed(),
+            result.block_tip_execution_result.execution_status() == TxExecutionStatus::Success,
             "VM must not fail when finalizing block: {:#?}",
             result.block_tip_execution_result.result
         );
@@ -468,11 +493,7 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
 
         let res = vm.inspect_transaction(tx.clone(), true);
         if res.compression_result.is_ok() {
-            return Ok(BatchTransactionExecutionResult {
-                tx_result: res.tx_result,
-                compression_result: Ok(()),
-                call_traces: res.call_traces,
-            });
+            return Ok(res);
         }
 
         // Roll back to the snapshot just before the transaction execution taken in `Self::execute_tx()`
@@ -483,10 +504,14 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
         let res = vm.inspect_transaction(tx.clone(), false);
         res.compression_result
             .context("compression failed when it wasn't applied")?;
+        // The VM doesn't report bytecodes published without compression, so we report factory deps of the transaction.
+        let compression_metrics =
+            BytecodeCompressionMetrics::uncompressed(&tx.execute.factory_deps);
         Ok(BatchTransactionExecutionResult {
             tx_result: res.tx_result,
-            compression_result: Ok(()),
+            compression_result: Ok(compression_metrics),
             call_traces: res.call_traces,
+            execution_time: res.execution_time,
         })
     }
 
@@ -498,31 +523,37 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
         vm: &mut BatchVm<S, Tr>,
     ) -> anyhow::Result<BatchTransactionExecutionResult> {
         let res = vm.inspect_transaction(tx.clone(), true);
-        if res.compression_result.is_ok() {
-            Ok(BatchTransactionExecutionResult {
-                tx_result: res.tx_result,
-                compression_result: Ok(()),
-                call_traces: res.call_traces,
-            })
-        } else {
-            // Transaction failed to publish bytecodes, we reject it so initiator doesn't pay fee.
-            let mut tx_result = res.tx_result;
-            tx_result.result = ExecutionResult::Halt {
-                reason: Halt::FailedToPublishCompressedBytecodes,
-            };
-            Ok(BatchTransactionExecutionResult {
-                tx_result,
-                compression_result: Ok(()),
-                call_traces: vec![],
-            })
-        }
+        Ok(reject_on_failed_compression(res))
+    }
+}
+
+/// Rejects the transaction if it failed to publish bytecodes, so that the initiator doesn't pay fee.
+/// The compression error is retained in the returned result.
+fn reject_on_failed_compression(
+    res: BatchTransactionExecutionResult,
+) -> BatchTransactionExecutionResult {
+    if res.compression_result.is_ok() {
+        return res;
+    }
+    let mut tx_result = res.tx_result;
+    tx_result.result = ExecutionResult::Halt {
+        reason: Halt::FailedToPublishCompressedBytecodes,
+    };
+    BatchTransactionExecutionResult {
+        tx_result,
+        compression_result: res.compression_result,
+        call_traces: vec![],
+        execution_time: res.execution_time,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use assert_matches::assert_matches;
-    use zksync_multivm::interface::{storage::InMemoryStorage, TxExecutionMode};
+    use zksync_multivm::interface::{
+        storage::InMemoryStorage, BytecodeCompressionError, TxExecutionMode,
+        VmExecutionResultAndLogs,
+    };
     use zksync_types::ProtocolVersionId;
 
     use super::*;
@@ -564,4 +595,35 @@ mod tests {
         let vm = BatchVm::<_, ()>::new(l1_batch_env, system_env, storage, FastVmMode::Shadow);
         assert_matches!(vm, BatchVm::Fast(FastVmInstance::Shadowed(_)));
     }
+
+    #[test]
+    fn rejecting_tx_on_failed_compression() {
+        let metrics = BytecodeCompressionMetrics {
+            original_bytes: 64,
+            compressed_bytes: 32,
+        };
+        let res = BatchTransactionExecutionResult::mock(VmExecutionResultAndLogs::mock_success())
+            .with_compression_result(Ok(metrics));
+        let res = reject_on_failed_compression(res);
+        assert!(res.tx_result.result.is_success());
+        assert_matches!(res.compression_result, Ok(m) if m == metrics);
+
+        let call_traces = vec![Call::default()];
+        let res = BatchTransactionExecutionResult::mock(VmExecutionResultAndLogs::mock_success())
+            .with_call_traces(call_traces)
+            .with_compression_result(Err(BytecodeCompressionError::BytecodeCompressionFailed));
+        let res = reject_on_failed_compression(res);
+        assert_matches!(
+            res.tx_result.result,
+            ExecutionResult::Halt {
+                reason: Halt::FailedToPublishCompressedBytecodes
+            }
+        );
+        assert!(res.is_compression_failed());
+        assert_matches!(
+            res.compression_error(),
+            Some(BytecodeCompressionError::BytecodeCompressionFailed)
+        );
+        assert!(res.call_traces.is_empty());
+    }
 }
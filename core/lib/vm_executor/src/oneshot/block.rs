@@ -266,6 +266,7 @@ impl<C: ContractsKind> OneshotEnvParameters<C> {
             execution_mode,
             default_validation_computational_gas_limit: validation_computational_gas_limit,
             chain_id,
+            whitelisted_deployers: None,
         };
         let l1_batch_env = L1BatchEnv {
             previous_batch_hash: None,
@@ -109,11 +109,7 @@ where
         args: TxExecutionArgs,
         _params: OneshotTracingParams,
     ) -> anyhow::Result<OneshotTransactionExecutionResult> {
-        Ok(OneshotTransactionExecutionResult {
-            tx_result: Box::new(self.mock_inspect(&env, args)),
-            compression_result: Ok(()),
-            call_traces: vec![],
-        })
+        Ok((self.mock_inspect(&env, args), vec![]).into())
     }
 }
 
@@ -20,9 +20,9 @@ use zksync_multivm::{
         storage::{ReadStorage, StoragePtr, StorageView, StorageWithOverrides, WriteStorage},
         tracer::{ValidationError, ValidationParams, ValidationTraces},
         utils::{DivergenceHandler, ShadowMut, ShadowVm},
-        Call, ExecutionResult, Halt, InspectExecutionMode, OneshotEnv, OneshotTracingParams,
-        OneshotTransactionExecutionResult, StoredL2BlockEnv, TxExecutionArgs, TxExecutionMode,
-        VmFactory, VmInterface,
+        BytecodeCompressionMetrics, Call, ExecutionResult, Halt, InspectExecutionMode, OneshotEnv,
+        OneshotTracingParams, OneshotTransactionExecutionResult, StoredL2BlockEnv, TxExecutionArgs,
+        TxExecutionMode, VmFactory, VmInterface,
     },
     is_supported_by_fast_vm,
     tracers::{CallTracer, StorageInvocations, TracerDispatcher, ValidationTracer},
@@ -244,6 +244,7 @@ impl<S: ReadStorage> Vm<S, StorageInvocationsTracer<StorageView<S>>, FastValidat
         with_compression: bool,
     ) -> OneshotTransactionExecutionResult {
         let mut calls_result = Arc::<OnceCell<_>>::default();
+        let started_at = Instant::now();
         let (compression_result, tx_result) = match self {
             Self::Legacy(vm) => {
                 let mut tracers = Self::create_legacy_tracers(
@@ -288,8 +289,10 @@ impl<S: ReadStorage> Vm<S, StorageInvocationsTracer<StorageView<S>>, FastValidat
 
         OneshotTransactionExecutionResult {
             tx_result: Box::new(tx_result),
-            compression_result: compression_result.map(drop),
+            compression_result: compression_result
+                .map(|bytecodes| BytecodeCompressionMetrics::from_bytecodes(&bytecodes)),
             call_traces: Arc::make_mut(&mut calls_result).take().unwrap_or_default(),
+            execution_time: started_at.elapsed(),
         }
     }
 
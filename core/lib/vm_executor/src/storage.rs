@@ -1,7 +1,7 @@
 //! Utils to get data for L1 batch execution from storage.
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     time::{Duration, Instant},
 };
 
@@ -57,6 +57,7 @@ pub fn l1_batch_params(
     protocol_version: ProtocolVersionId,
     virtual_blocks: u32,
     chain_id: L2ChainId,
+    whitelisted_deployers: Option<HashSet<Address>>,
 ) -> (SystemEnv, L1BatchEnv) {
     (
         SystemEnv {
@@ -67,6 +68,7 @@ pub fn l1_batch_params(
             execution_mode: TxExecutionMode::VerifyExecute,
             default_validation_computational_gas_limit: validation_computational_gas_limit,
             chain_id,
+            whitelisted_deployers,
         },
         L1BatchEnv {
             previous_batch_hash: Some(previous_batch_hash),
@@ -261,12 +263,15 @@ impl L1BatchParamsProvider {
     }
 
     /// Loads VM-related L1 batch parameters for the specified batch.
+    ///
+    /// `whitelisted_deployers` are not persisted in storage and are passed through to [`SystemEnv`] as-is.
     pub async fn load_l1_batch_params(
         &self,
         storage: &mut Connection<'_, Core>,
         first_l2_block_in_batch: &FirstL2BlockInBatch,
         validation_computational_gas_limit: u32,
         chain_id: L2ChainId,
+        whitelisted_deployers: Option<HashSet<Address>>,
     ) -> anyhow::Result<(SystemEnv, L1BatchEnv, PubdataParams)> {
         anyhow::ensure!(
             first_l2_block_in_batch.l1_batch_number > L1BatchNumber(0),
@@ -337,6 +342,7 @@ impl L1BatchParamsProvider {
                 .context("`protocol_version` must be set for L2 block")?,
             first_l2_block_in_batch.header.virtual_blocks,
             chain_id,
+            whitelisted_deployers,
         );
 
         Ok((
@@ -356,6 +362,7 @@ impl L1BatchParamsProvider {
         number: L1BatchNumber,
         validation_computational_gas_limit: u32,
         chain_id: L2ChainId,
+        whitelisted_deployers: Option<HashSet<Address>>,
     ) -> anyhow::Result<Option<(SystemEnv, L1BatchEnv, PubdataParams)>> {
         let first_l2_block = self
             .load_first_l2_block_in_batch(storage, number)
@@ -370,6 +377,7 @@ impl L1BatchParamsProvider {
             &first_l2_block,
             validation_computational_gas_limit,
             chain_id,
+            whitelisted_deployers,
         )
         .await
         .with_context(|| format!("failed loading params for L1 batch #{number}"))
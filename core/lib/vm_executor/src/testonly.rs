@@ -27,6 +27,7 @@ pub(crate) fn default_system_env(execution_mode: TxExecutionMode) -> SystemEnv {
         execution_mode,
         default_validation_computational_gas_limit: BATCH_COMPUTATIONAL_GAS_LIMIT,
         chain_id: L2ChainId::default(),
+        whitelisted_deployers: None,
     }
 }
 
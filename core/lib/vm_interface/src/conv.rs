@@ -0,0 +1,844 @@
+//! Protobuf conversions for VM execution outputs.
+
+use std::collections::HashMap;
+
+use anyhow::Context as _;
+use zksync_protobuf::{
+    repr::{read_required_repr, ProtoRepr},
+    required, ProtoFmt,
+};
+use zksync_types::{
+    l2_to_l1_log::{L2ToL1Log, SystemL2ToL1Log, UserL2ToL1Log},
+    parse_h160, parse_h256, AccountTreeId, L1BatchNumber, StorageKey, StorageLog, StorageLogKind,
+    StorageLogWithPreviousValue, H256,
+};
+
+use crate::{
+    proto, BootloaderInternalError, CircuitStatistic, ExecutionResult, Halt, Refunds, VmEvent,
+    VmExecutionLogs, VmExecutionResultAndLogs, VmExecutionStatistics, VmRevertReason,
+};
+
+impl ProtoFmt for VmExecutionResultAndLogs {
+    type Proto = proto::VmExecutionResult;
+
+    fn read(r: &Self::Proto) -> anyhow::Result<Self> {
+        let dynamic_factory_deps: HashMap<_, _> = r
+            .dynamic_factory_deps
+            .iter()
+            .enumerate()
+            .map(|(i, dep)| dep.read().context(i))
+            .collect::<anyhow::Result<_>>()
+            .context("dynamic_factory_deps")?;
+        anyhow::ensure!(
+            dynamic_factory_deps.len() == r.dynamic_factory_deps.len(),
+            "dynamic_factory_deps contain duplicate hashes"
+        );
+        Ok(Self {
+            result: read_required_repr(&r.result).context("result")?,
+            logs: read_required_repr(&r.logs).context("logs")?,
+            statistics: read_required_repr(&r.statistics).context("statistics")?,
+            refunds: read_required_repr(&r.refunds).context("refunds")?,
+            dynamic_factory_deps,
+        })
+    }
+
+    fn build(&self) -> Self::Proto {
+        let mut dynamic_factory_deps: Vec<_> = self
+            .dynamic_factory_deps
+            .iter()
+            .map(|(hash, bytecode)| (*hash, bytecode.clone()))
+            .collect();
+        // Sort dependencies so that the encoding is deterministic.
+        dynamic_factory_deps.sort_unstable_by_key(|(hash, _)| *hash);
+
+        Self::Proto {
+            result: Some(ProtoRepr::build(&self.result)),
+            logs: Some(ProtoRepr::build(&self.logs)),
+            statistics: Some(ProtoRepr::build(&self.statistics)),
+            refunds: Some(ProtoRepr::build(&self.refunds)),
+            dynamic_factory_deps: dynamic_factory_deps.iter().map(ProtoRepr::build).collect(),
+        }
+    }
+}
+
+impl ProtoRepr for proto::FactoryDependency {
+    type Type = (H256, Vec<u8>);
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok((
+            required(&self.hash)
+                .and_then(|h| parse_h256(h))
+                .context("hash")?,
+            required(&self.bytecode).context("bytecode")?.clone(),
+        ))
+    }
+
+    fn build((hash, bytecode): &Self::Type) -> Self {
+        Self {
+            hash: Some(hash.as_bytes().to_vec()),
+            bytecode: Some(bytecode.clone()),
+        }
+    }
+}
+
+impl ProtoRepr for proto::ExecutionResult {
+    type Type = ExecutionResult;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        use proto::execution_result::Kind;
+
+        Ok(match required(&self.kind).context("kind")? {
+            Kind::Success(output) => ExecutionResult::Success {
+                output: output.clone(),
+            },
+            Kind::Revert(output) => ExecutionResult::Revert {
+                output: output.read().context("revert")?,
+            },
+            Kind::Halt(reason) => ExecutionResult::Halt {
+                reason: reason.read().context("halt")?,
+            },
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        use proto::execution_result::Kind;
+
+        let kind = match this {
+            ExecutionResult::Success { output } => Kind::Success(output.clone()),
+            ExecutionResult::Revert { output } => Kind::Revert(ProtoRepr::build(output)),
+            ExecutionResult::Halt { reason } => Kind::Halt(ProtoRepr::build(reason)),
+        };
+        Self { kind: Some(kind) }
+    }
+}
+
+impl ProtoRepr for proto::RevertReason {
+    type Type = VmRevertReason;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        use proto::RevertReasonKind as Kind;
+
+        let kind = required(&self.kind)
+            .and_then(|x| Ok(Kind::try_from(*x)?))
+            .context("kind")?;
+        let data = || required(&self.data).cloned().context("data");
+        Ok(match kind {
+            Kind::General => VmRevertReason::General {
+                msg: required(&self.msg).cloned().context("msg")?,
+                data: data()?,
+            },
+            Kind::InnerTxError => VmRevertReason::InnerTxError,
+            Kind::VmError => VmRevertReason::VmError,
+            Kind::Unknown => VmRevertReason::Unknown {
+                function_selector: required(&self.function_selector)
+                    .cloned()
+                    .context("function_selector")?,
+                data: data()?,
+            },
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        use proto::RevertReasonKind as Kind;
+
+        let with_kind = |kind: Kind| Self {
+            kind: Some(kind as i32),
+            ..Self::default()
+        };
+        match this {
+            VmRevertReason::General { msg, data } => Self {
+                msg: Some(msg.clone()),
+                data: Some(data.clone()),
+                ..with_kind(Kind::General)
+            },
+            VmRevertReason::InnerTxError => with_kind(Kind::InnerTxError),
+            VmRevertReason::VmError => with_kind(Kind::VmError),
+            VmRevertReason::Unknown {
+                function_selector,
+                data,
+            } => Self {
+                function_selector: Some(function_selector.clone()),
+                data: Some(data.clone()),
+                ..with_kind(Kind::Unknown)
+            },
+        }
+    }
+}
+
+impl ProtoRepr for proto::Halt {
+    type Type = Halt;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        use proto::HaltKind as Kind;
+
+        let kind = required(&self.kind)
+            .and_then(|x| Ok(Kind::try_from(*x)?))
+            .context("kind")?;
+        let reason = || read_required_repr(&self.reason).context("reason");
+        let msg = || required(&self.msg).cloned().context("msg");
+        Ok(match kind {
+            Kind::ValidationFailed => Halt::ValidationFailed(reason()?),
+            Kind::PaymasterValidationFailed => Halt::PaymasterValidationFailed(reason()?),
+            Kind::PrePaymasterPreparationFailed => Halt::PrePaymasterPreparationFailed(reason()?),
+            Kind::PayForTxFailed => Halt::PayForTxFailed(reason()?),
+            Kind::FailedToMarkFactoryDependencies => {
+                Halt::FailedToMarkFactoryDependencies(reason()?)
+            }
+            Kind::FailedToChargeFee => Halt::FailedToChargeFee(reason()?),
+            Kind::FromIsNotAnAccount => Halt::FromIsNotAnAccount,
+            Kind::InnerTxError => Halt::InnerTxError,
+            Kind::Unknown => Halt::Unknown(reason()?),
+            Kind::UnexpectedVmBehavior => Halt::UnexpectedVMBehavior(msg()?),
+            Kind::BootloaderInternalError => Halt::BootloaderInternalError(
+                read_required_repr(&self.bootloader_error).context("bootloader_error")?,
+            ),
+            Kind::BootloaderOutOfGas => Halt::BootloaderOutOfGas,
+            Kind::ValidationOutOfGas => Halt::ValidationOutOfGas,
+            Kind::TooBigGasLimit => Halt::TooBigGasLimit,
+            Kind::NotEnoughGasProvided => Halt::NotEnoughGasProvided,
+            Kind::MissingInvocationLimitReached => Halt::MissingInvocationLimitReached,
+            Kind::FailedToSetL2Block => Halt::FailedToSetL2Block(reason()?),
+            Kind::FailedToAppendTransactionToL2Block => {
+                Halt::FailedToAppendTransactionToL2Block(reason()?)
+            }
+            Kind::VmPanic => Halt::VMPanic,
+            Kind::TracerCustom => Halt::TracerCustom(msg()?),
+            Kind::FailedToPublishCompressedBytecodes => Halt::FailedToPublishCompressedBytecodes,
+            Kind::FailedBlockTimestampAssertion => Halt::FailedBlockTimestampAssertion,
+            Kind::UnauthorizedDeployment => Halt::UnauthorizedDeployment(
+                required(&self.deployer)
+                    .and_then(|a| parse_h160(a))
+                    .context("deployer")?,
+            ),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        use proto::HaltKind as Kind;
+
+        let with_kind = |kind: Kind| Self {
+            kind: Some(kind as i32),
+            ..Self::default()
+        };
+        let with_reason = |kind: Kind, reason: &VmRevertReason| Self {
+            reason: Some(ProtoRepr::build(reason)),
+            ..with_kind(kind)
+        };
+        let with_msg = |kind: Kind, msg: &str| Self {
+            msg: Some(msg.to_owned()),
+            ..with_kind(kind)
+        };
+        match this {
+            Halt::ValidationFailed(reason) => with_reason(Kind::ValidationFailed, reason),
+            Halt::PaymasterValidationFailed(reason) => {
+                with_reason(Kind::PaymasterValidationFailed, reason)
+            }
+            Halt::PrePaymasterPreparationFailed(reason) => {
+                with_reason(Kind::PrePaymasterPreparationFailed, reason)
+            }
+            Halt::PayForTxFailed(reason) => with_reason(Kind::PayForTxFailed, reason),
+            Halt::FailedToMarkFactoryDependencies(reason) => {
+                with_reason(Kind::FailedToMarkFactoryDependencies, reason)
+            }
+            Halt::FailedToChargeFee(reason) => with_reason(Kind::FailedToChargeFee, reason),
+            Halt::FromIsNotAnAccount => with_kind(Kind::FromIsNotAnAccount),
+            Halt::InnerTxError => with_kind(Kind::InnerTxError),
+            Halt::Unknown(reason) => with_reason(Kind::Unknown, reason),
+            Halt::UnexpectedVMBehavior(msg) => with_msg(Kind::UnexpectedVmBehavior, msg),
+            Halt::BootloaderInternalError(err) => Self {
+                bootloader_error: Some(ProtoRepr::build(err)),
+                ..with_kind(Kind::BootloaderInternalError)
+            },
+            Halt::BootloaderOutOfGas => with_kind(Kind::BootloaderOutOfGas),
+            Halt::ValidationOutOfGas => with_kind(Kind::ValidationOutOfGas),
+            Halt::TooBigGasLimit => with_kind(Kind::TooBigGasLimit),
+            Halt::NotEnoughGasProvided => with_kind(Kind::NotEnoughGasProvided),
+            Halt::MissingInvocationLimitReached => with_kind(Kind::MissingInvocationLimitReached),
+            Halt::FailedToSetL2Block(reason) => with_reason(Kind::FailedToSetL2Block, reason),
+            Halt::FailedToAppendTransactionToL2Block(reason) => {
+                with_reason(Kind::FailedToAppendTransactionToL2Block, reason)
+            }
+            Halt::VMPanic => with_kind(Kind::VmPanic),
+            Halt::TracerCustom(msg) => with_msg(Kind::TracerCustom, msg),
+            Halt::FailedToPublishCompressedBytecodes => {
+                with_kind(Kind::FailedToPublishCompressedBytecodes)
+            }
+            Halt::FailedBlockTimestampAssertion => with_kind(Kind::FailedBlockTimestampAssertion),
+            Halt::UnauthorizedDeployment(deployer) => Self {
+                deployer: Some(deployer.as_bytes().to_vec()),
+                ..with_kind(Kind::UnauthorizedDeployment)
+            },
+        }
+    }
+}
+
+impl ProtoRepr for proto::BootloaderError {
+    type Type = BootloaderInternalError;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        use proto::BootloaderErrorKind as Kind;
+
+        let kind = required(&self.kind)
+            .and_then(|x| Ok(Kind::try_from(*x)?))
+            .context("kind")?;
+        let reason = || read_required_repr(&self.reason).context("reason");
+        Ok(match kind {
+            Kind::UnacceptableGasPrice => BootloaderInternalError::UnacceptableGasPrice,
+            Kind::FailedToSendFeesToTheOperator => {
+                BootloaderInternalError::FailedToSendFeesToTheOperator
+            }
+            Kind::UnacceptablePubdataPrice => BootloaderInternalError::UnacceptablePubdataPrice,
+            Kind::MaxPriorityFeeGreaterThanMaxFee => {
+                BootloaderInternalError::MaxPriorityFeeGreaterThanMaxFee
+            }
+            Kind::BaseFeeGreaterThanMaxFeePerGas => {
+                BootloaderInternalError::BaseFeeGreaterThanMaxFeePerGas
+            }
+            Kind::AssertionError => BootloaderInternalError::AssertionError(reason()?),
+            Kind::L1MessengerLogSendingFailed => {
+                BootloaderInternalError::L1MessengerLogSendingFailed(reason()?)
+            }
+            Kind::L1MessengerPublishingFailed => {
+                BootloaderInternalError::L1MessengerPublishingFailed(reason()?)
+            }
+            Kind::FailedToCallSystemContext => {
+                BootloaderInternalError::FailedToCallSystemContext(reason()?)
+            }
+            Kind::MintEtherFailed => BootloaderInternalError::MintEtherFailed(reason()?),
+            Kind::FailedToPublishTimestampDataToL1 => {
+                BootloaderInternalError::FailedToPublishTimestampDataToL1(reason()?)
+            }
+            Kind::UnknownErrorCode => BootloaderInternalError::UnknownErrorCode {
+                code: required(&self.code)
+                    .and_then(|x| Ok(u8::try_from(*x)?))
+                    .context("code")?,
+                reason: reason()?,
+            },
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        use proto::BootloaderErrorKind as Kind;
+
+        let with_kind = |kind: Kind| Self {
+            kind: Some(kind as i32),
+            ..Self::default()
+        };
+        let with_reason = |kind: Kind, reason: &VmRevertReason| Self {
+            reason: Some(ProtoRepr::build(reason)),
+            ..with_kind(kind)
+        };
+        match this {
+            BootloaderInternalError::UnacceptableGasPrice => with_kind(Kind::UnacceptableGasPrice),
+            BootloaderInternalError::FailedToSendFeesToTheOperator => {
+                with_kind(Kind::FailedToSendFeesToTheOperator)
+            }
+            BootloaderInternalError::UnacceptablePubdataPrice => {
+                with_kind(Kind::UnacceptablePubdataPrice)
+            }
+            BootloaderInternalError::MaxPriorityFeeGreaterThanMaxFee => {
+                with_kind(Kind::MaxPriorityFeeGreaterThanMaxFee)
+            }
+            BootloaderInternalError::BaseFeeGreaterThanMaxFeePerGas => {
+                with_kind(Kind::BaseFeeGreaterThanMaxFeePerGas)
+            }
+            BootloaderInternalError::AssertionError(reason) => {
+                with_reason(Kind::AssertionError, reason)
+            }
+            BootloaderInternalError::L1MessengerLogSendingFailed(reason) => {
+                with_reason(Kind::L1MessengerLogSendingFailed, reason)
+            }
+            BootloaderInternalError::L1MessengerPublishingFailed(reason) => {
+                with_reason(Kind::L1MessengerPublishingFailed, reason)
+            }
+            BootloaderInternalError::FailedToCallSystemContext(reason) => {
+                with_reason(Kind::FailedToCallSystemContext, reason)
+            }
+            BootloaderInternalError::MintEtherFailed(reason) => {
+                with_reason(Kind::MintEtherFailed, reason)
+            }
+            BootloaderInternalError::FailedToPublishTimestampDataToL1(reason) => {
+                with_reason(Kind::FailedToPublishTimestampDataToL1, reason)
+            }
+            BootloaderInternalError::UnknownErrorCode { code, reason } => Self {
+                code: Some((*code).into()),
+                ..with_reason(Kind::UnknownErrorCode, reason)
+            },
+        }
+    }
+}
+
+impl ProtoRepr for proto::ExecutionLogs {
+    type Type = VmExecutionLogs;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            storage_logs: self
+                .storage_logs
+                .iter()
+                .enumerate()
+                .map(|(i, log)| log.read().context(i))
+                .collect::<anyhow::Result<_>>()
+                .context("storage_logs")?,
+            events: self
+                .events
+                .iter()
+                .enumerate()
+                .map(|(i, event)| event.read().context(i))
+                .collect::<anyhow::Result<_>>()
+                .context("events")?,
+            user_l2_to_l1_logs: self
+                .user_l2_to_l1_logs
+                .iter()
+                .enumerate()
+                .map(|(i, log)| log.read().map(UserL2ToL1Log).context(i))
+                .collect::<anyhow::Result<_>>()
+                .context("user_l2_to_l1_logs")?,
+            system_l2_to_l1_logs: self
+                .system_l2_to_l1_logs
+                .iter()
+                .enumerate()
+                .map(|(i, log)| log.read().map(SystemL2ToL1Log).context(i))
+                .collect::<anyhow::Result<_>>()
+                .context("system_l2_to_l1_logs")?,
+            total_log_queries_count: required(&self.total_log_queries_count)
+                .and_then(|x| Ok((*x).try_into()?))
+                .context("total_log_queries_count")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            storage_logs: this.storage_logs.iter().map(ProtoRepr::build).collect(),
+            events: this.events.iter().map(ProtoRepr::build).collect(),
+            user_l2_to_l1_logs: this
+                .user_l2_to_l1_logs
+                .iter()
+                .map(|log| ProtoRepr::build(&log.0))
+                .collect(),
+            system_l2_to_l1_logs: this
+                .system_l2_to_l1_logs
+                .iter()
+                .map(|log| ProtoRepr::build(&log.0))
+                .collect(),
+            total_log_queries_count: Some(this.total_log_queries_count as u64),
+        }
+    }
+}
+
+impl ProtoRepr for proto::StorageLog {
+    type Type = StorageLogWithPreviousValue;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        use proto::StorageLogKind as Kind;
+
+        let kind = required(&self.kind)
+            .and_then(|x| Ok(Kind::try_from(*x)?))
+            .context("kind")?;
+        let address = required(&self.address)
+            .and_then(|a| parse_h160(a))
+            .context("address")?;
+        let key = required(&self.key)
+            .and_then(|k| parse_h256(k))
+            .context("key")?;
+        Ok(Self::Type {
+            log: StorageLog {
+                kind: match kind {
+                    Kind::Read => StorageLogKind::Read,
+                    Kind::InitialWrite => StorageLogKind::InitialWrite,
+                    Kind::RepeatedWrite => StorageLogKind::RepeatedWrite,
+                },
+                key: StorageKey::new(AccountTreeId::new(address), key),
+                value: required(&self.value)
+                    .and_then(|v| parse_h256(v))
+                    .context("value")?,
+            },
+            previous_value: required(&self.previous_value)
+                .and_then(|v| parse_h256(v))
+                .context("previous_value")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        use proto::StorageLogKind as Kind;
+
+        let kind = match this.log.kind {
+            StorageLogKind::Read => Kind::Read,
+            StorageLogKind::InitialWrite => Kind::InitialWrite,
+            StorageLogKind::RepeatedWrite => Kind::RepeatedWrite,
+        };
+        Self {
+            kind: Some(kind as i32),
+            address: Some(this.log.key.address().as_bytes().to_vec()),
+            key: Some(this.log.key.key().as_bytes().to_vec()),
+            value: Some(this.log.value.as_bytes().to_vec()),
+            previous_value: Some(this.previous_value.as_bytes().to_vec()),
+        }
+    }
+}
+
+impl ProtoRepr for proto::Event {
+    type Type = VmEvent;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            location: (
+                L1BatchNumber(*required(&self.l1_batch_number).context("l1_batch_number")?),
+                *required(&self.tx_index).context("tx_index")?,
+            ),
+            address: required(&self.address)
+                .and_then(|a| parse_h160(a))
+                .context("address")?,
+            indexed_topics: self
+                .indexed_topics
+                .iter()
+                .enumerate()
+                .map(|(i, topic)| parse_h256(topic).context(i))
+                .collect::<anyhow::Result<_>>()
+                .context("indexed_topics")?,
+            value: required(&self.value).context("value")?.clone(),
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            l1_batch_number: Some(this.location.0 .0),
+            tx_index: Some(this.location.1),
+            address: Some(this.address.as_bytes().to_vec()),
+            indexed_topics: this
+                .indexed_topics
+                .iter()
+                .map(|topic| topic.as_bytes().to_vec())
+                .collect(),
+            value: Some(this.value.clone()),
+        }
+    }
+}
+
+impl ProtoRepr for proto::L2ToL1Log {
+    type Type = L2ToL1Log;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            shard_id: required(&self.shard_id)
+                .and_then(|x| Ok(u8::try_from(*x)?))
+                .context("shard_id")?,
+            is_service: *required(&self.is_service).context("is_service")?,
+            tx_number_in_block: required(&self.tx_number_in_block)
+                .and_then(|x| Ok(u16::try_from(*x)?))
+                .context("tx_number_in_block")?,
+            sender: required(&self.sender)
+                .and_then(|a| parse_h160(a))
+                .context("sender")?,
+            key: required(&self.key)
+                .and_then(|k| parse_h256(k))
+                .context("key")?,
+            value: required(&self.value)
+                .and_then(|v| parse_h256(v))
+                .context("value")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            shard_id: Some(this.shard_id.into()),
+            is_service: Some(this.is_service),
+            tx_number_in_block: Some(this.tx_number_in_block.into()),
+            sender: Some(this.sender.as_bytes().to_vec()),
+            key: Some(this.key.as_bytes().to_vec()),
+            value: Some(this.value.as_bytes().to_vec()),
+        }
+    }
+}
+
+impl ProtoRepr for proto::ExecutionStatistics {
+    type Type = VmExecutionStatistics;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            contracts_used: required(&self.contracts_used)
+                .and_then(|x| Ok((*x).try_into()?))
+                .context("contracts_used")?,
+            cycles_used: *required(&self.cycles_used).context("cycles_used")?,
+            gas_used: *required(&self.gas_used).context("gas_used")?,
+            gas_remaining: *required(&self.gas_remaining).context("gas_remaining")?,
+            computational_gas_used: *required(&self.computational_gas_used)
+                .context("computational_gas_used")?,
+            total_log_queries: required(&self.total_log_queries)
+                .and_then(|x| Ok((*x).try_into()?))
+                .context("total_log_queries")?,
+            pubdata_published: *required(&self.pubdata_published).context("pubdata_published")?,
+            circuit_statistic: read_required_repr(&self.circuit_statistic)
+                .context("circuit_statistic")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            contracts_used: Some(this.contracts_used as u64),
+            cycles_used: Some(this.cycles_used),
+            gas_used: Some(this.gas_used),
+            gas_remaining: Some(this.gas_remaining),
+            computational_gas_used: Some(this.computational_gas_used),
+            total_log_queries: Some(this.total_log_queries as u64),
+            pubdata_published: Some(this.pubdata_published),
+            circuit_statistic: Some(ProtoRepr::build(&this.circuit_statistic)),
+        }
+    }
+}
+
+impl ProtoRepr for proto::CircuitStatistic {
+    type Type = CircuitStatistic;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            main_vm: *required(&self.main_vm).context("main_vm")?,
+            ram_permutation: *required(&self.ram_permutation).context("ram_permutation")?,
+            storage_application: *required(&self.storage_application)
+                .context("storage_application")?,
+            storage_sorter: *required(&self.storage_sorter).context("storage_sorter")?,
+            code_decommitter: *required(&self.code_decommitter).context("code_decommitter")?,
+            code_decommitter_sorter: *required(&self.code_decommitter_sorter)
+                .context("code_decommitter_sorter")?,
+            log_demuxer: *required(&self.log_demuxer).context("log_demuxer")?,
+            events_sorter: *required(&self.events_sorter).context("events_sorter")?,
+            keccak256: *required(&self.keccak256).context("keccak256")?,
+            ecrecover: *required(&self.ecrecover).context("ecrecover")?,
+            sha256: *required(&self.sha256).context("sha256")?,
+            secp256k1_verify: *required(&self.secp256k1_verify).context("secp256k1_verify")?,
+            transient_storage_checker: *required(&self.transient_storage_checker)
+                .context("transient_storage_checker")?,
+            modexp: *required(&self.modexp).context("modexp")?,
+            ecadd: *required(&self.ecadd).context("ecadd")?,
+            ecmul: *required(&self.ecmul).context("ecmul")?,
+            ecpairing: *required(&self.ecpairing).context("ecpairing")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            main_vm: Some(this.main_vm),
+            ram_permutation: Some(this.ram_permutation),
+            storage_application: Some(this.storage_application),
+            storage_sorter: Some(this.storage_sorter),
+            code_decommitter: Some(this.code_decommitter),
+            code_decommitter_sorter: Some(this.code_decommitter_sorter),
+            log_demuxer: Some(this.log_demuxer),
+            events_sorter: Some(this.events_sorter),
+            keccak256: Some(this.keccak256),
+            ecrecover: Some(this.ecrecover),
+            sha256: Some(this.sha256),
+            secp256k1_verify: Some(this.secp256k1_verify),
+            transient_storage_checker: Some(this.transient_storage_checker),
+            modexp: Some(this.modexp),
+            ecadd: Some(this.ecadd),
+            ecmul: Some(this.ecmul),
+            ecpairing: Some(this.ecpairing),
+        }
+    }
+}
+
+impl ProtoRepr for proto::Refunds {
+    type Type = Refunds;
+
+    fn read(&self) -> anyhow::Result<Self::Type> {
+        Ok(Self::Type {
+            gas_refunded: *required(&self.gas_refunded).context("gas_refunded")?,
+            operator_suggested_refund: *required(&self.operator_suggested_refund)
+                .context("operator_suggested_refund")?,
+        })
+    }
+
+    fn build(this: &Self::Type) -> Self {
+        Self {
+            gas_refunded: Some(this.gas_refunded),
+            operator_suggested_refund: Some(this.operator_suggested_refund),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_protobuf::{decode, encode};
+    use zksync_types::Address;
+
+    use super::*;
+
+    fn general_revert_reason() -> VmRevertReason {
+        VmRevertReason::General {
+            msg: "oops".to_owned(),
+            data: vec![1, 2, 3],
+        }
+    }
+
+    fn mock_result(result: ExecutionResult) -> VmExecutionResultAndLogs {
+        let storage_key =
+            StorageKey::new(AccountTreeId::new(Address::repeat_byte(1)), H256::zero());
+        let l2_to_l1_log = L2ToL1Log {
+            shard_id: 0,
+            is_service: true,
+            tx_number_in_block: 3,
+            sender: Address::repeat_byte(2),
+            key: H256::repeat_byte(3),
+            value: H256::repeat_byte(4),
+        };
+
+        VmExecutionResultAndLogs {
+            result,
+            logs: VmExecutionLogs {
+                storage_logs: vec![StorageLogWithPreviousValue {
+                    log: StorageLog::new_write_log(storage_key, H256::repeat_byte(5)),
+                    previous_value: H256::repeat_byte(6),
+                }],
+                events: vec![VmEvent {
+                    location: (L1BatchNumber(1), 3),
+                    address: Address::repeat_byte(7),
+                    indexed_topics: vec![H256::repeat_byte(8), H256::repeat_byte(9)],
+                    value: vec![10; 40],
+                }],
+                user_l2_to_l1_logs: vec![UserL2ToL1Log(l2_to_l1_log.clone())],
+                system_l2_to_l1_logs: vec![SystemL2ToL1Log(L2ToL1Log {
+                    is_service: false,
+                    ..l2_to_l1_log
+                })],
+                total_log_queries_count: 5,
+            },
+            statistics: VmExecutionStatistics {
+                contracts_used: 2,
+                cycles_used: 1_000,
+                gas_used: 50_000,
+                gas_remaining: 10_000,
+                computational_gas_used: 30_000,
+                total_log_queries: 5,
+                pubdata_published: 100,
+                circuit_statistic: CircuitStatistic {
+                    main_vm: 0.5,
+                    keccak256: 0.125,
+                    ecpairing: 0.25,
+                    ..CircuitStatistic::default()
+                },
+            },
+            refunds: Refunds {
+                gas_refunded: 1_000,
+                operator_suggested_refund: 2_000,
+            },
+            dynamic_factory_deps: HashMap::from([
+                (H256::repeat_byte(0xee), vec![0xfe; 64]),
+                (H256::repeat_byte(0xff), vec![0xfd; 32]),
+            ]),
+        }
+    }
+
+    fn assert_round_trip(result: ExecutionResult) {
+        let result = mock_result(result);
+        let decoded: VmExecutionResultAndLogs = decode(&encode(&result)).unwrap();
+        assert!(decoded.eq_strict(&result), "{decoded:?}");
+    }
+
+    #[test]
+    fn execution_result_round_trip() {
+        assert_round_trip(ExecutionResult::Success {
+            output: vec![1, 2, 3],
+        });
+
+        let revert_reasons = [
+            general_revert_reason(),
+            VmRevertReason::InnerTxError,
+            VmRevertReason::VmError,
+            VmRevertReason::Unknown {
+                function_selector: vec![0xde, 0xad, 0xbe, 0xef],
+                data: vec![4, 5],
+            },
+        ];
+        for output in revert_reasons {
+            assert_round_trip(ExecutionResult::Revert { output });
+        }
+    }
+
+    #[test]
+    fn halt_round_trip() {
+        let reason = general_revert_reason;
+        let bootloader_errors = [
+            BootloaderInternalError::UnacceptableGasPrice,
+            BootloaderInternalError::FailedToSendFeesToTheOperator,
+            BootloaderInternalError::UnacceptablePubdataPrice,
+            BootloaderInternalError::MaxPriorityFeeGreaterThanMaxFee,
+            BootloaderInternalError::BaseFeeGreaterThanMaxFeePerGas,
+            BootloaderInternalError::AssertionError(reason()),
+            BootloaderInternalError::L1MessengerLogSendingFailed(reason()),
+            BootloaderInternalError::L1MessengerPublishingFailed(reason()),
+            BootloaderInternalError::FailedToCallSystemContext(reason()),
+            BootloaderInternalError::MintEtherFailed(reason()),
+            BootloaderInternalError::FailedToPublishTimestampDataToL1(reason()),
+            BootloaderInternalError::UnknownErrorCode {
+                code: 42,
+                reason: reason(),
+            },
+        ];
+        let halts = [
+            Halt::ValidationFailed(reason()),
+            Halt::PaymasterValidationFailed(reason()),
+            Halt::PrePaymasterPreparationFailed(reason()),
+            Halt::PayForTxFailed(reason()),
+            Halt::FailedToMarkFactoryDependencies(reason()),
+            Halt::FailedToChargeFee(reason()),
+            Halt::FromIsNotAnAccount,
+            Halt::InnerTxError,
+            Halt::Unknown(VmRevertReason::VmError),
+            Halt::UnexpectedVMBehavior("unexpected".to_owned()),
+            Halt::BootloaderOutOfGas,
+            Halt::ValidationOutOfGas,
+            Halt::TooBigGasLimit,
+            Halt::NotEnoughGasProvided,
+            Halt::MissingInvocationLimitReached,
+            Halt::FailedToSetL2Block(reason()),
+            Halt::FailedToAppendTransactionToL2Block(reason()),
+            Halt::VMPanic,
+            Halt::TracerCustom("custom".to_owned()),
+            Halt::FailedToPublishCompressedBytecodes,
+            Halt::FailedBlockTimestampAssertion,
+            Halt::UnauthorizedDeployment(Address::repeat_byte(0x42)),
+        ];
+
+        let halts = halts
+            .into_iter()
+            .chain(bootloader_errors.map(Halt::BootloaderInternalError));
+        for reason in halts {
+            assert_round_trip(ExecutionResult::Halt { reason });
+        }
+    }
+
+    #[test]
+    fn encoding_is_deterministic() {
+        let result = mock_result(ExecutionResult::Success { output: vec![] });
+        let proto = result.build();
+        let dep_hashes: Vec<_> = proto
+            .dynamic_factory_deps
+            .iter()
+            .map(|dep| dep.hash.clone().unwrap())
+            .collect();
+        assert_eq!(
+            dep_hashes,
+            [vec![0xee; 32], vec![0xff; 32]],
+            "dynamic factory deps must be sorted by hash"
+        );
+        assert_eq!(encode(&result), encode(&result.clone()));
+    }
+
+    #[test]
+    fn reading_invalid_proto() {
+        let mut proto = mock_result(ExecutionResult::Success { output: vec![] }).build();
+        proto.logs.as_mut().unwrap().events[0].address = Some(vec![0; 19]);
+        let err = VmExecutionResultAndLogs::read(&proto).unwrap_err();
+        let err = format!("{err:#}");
+        assert!(
+            err.contains("logs") && err.contains("events") && err.contains("address"),
+            "{err}"
+        );
+
+        let mut proto = mock_result(ExecutionResult::Success { output: vec![] }).build();
+        proto.result = None;
+        let err = VmExecutionResultAndLogs::read(&proto).unwrap_err();
+        assert!(format!("{err:#}").contains("result"), "{err:#}");
+    }
+}
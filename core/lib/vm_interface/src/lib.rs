@@ -20,27 +20,34 @@
 pub use crate::{
     types::{
         errors::{
-            BytecodeCompressionError, BytecodeCompressionResult, Halt, TxRevertReason,
-            VmRevertReason, VmRevertReasonParsingError,
+            BootloaderInternalError, BytecodeCompressionError, BytecodeCompressionResult, Halt,
+            L1MessageEventError, TxRevertReason, VmRevertReason, VmRevertReasonParsingError,
         },
         inputs::{
             InspectExecutionMode, L1BatchEnv, L2BlockEnv, OneshotEnv, OneshotTracingParams,
             StoredL2BlockEnv, SystemEnv, TxExecutionArgs, TxExecutionMode, VmExecutionMode,
         },
         outputs::{
-            BatchTransactionExecutionResult, BootloaderMemory, Call, CallType, CircuitStatistic,
-            CompressedBytecodeInfo, CurrentExecutionState, DeduplicatedWritesMetrics,
-            ExecutionResult, FinishedL1Batch, L2Block, OneshotTransactionExecutionResult,
+            AbiDecode, BatchLimits, BatchTransactionExecutionResult, BootloaderMemory,
+            BytecodeCompressionMetrics, Call, CallErrorKind, CallIter, CallRecord, CallType,
+            CircuitStatistic, CircuitType, CompressedBytecodeInfo, CostComponent, CostWeights,
+            CurrentExecutionState, DeduplicatedWritesMetrics, DeployEvent, ExecutionResult,
+            FinishedL1Batch, L2Block, OneshotTransactionExecutionResult, ProxyKind,
             PushTransactionResult, Refunds, TransactionExecutionMetrics,
             TransactionExecutionResult, TxExecutionStatus, VmEvent, VmExecutionLogs,
-            VmExecutionMetrics, VmExecutionResultAndLogs, VmExecutionStatistics, VmMemoryMetrics,
+            VmExecutionMetrics, VmExecutionResultAndLogs, VmExecutionStatistics,
+            VmExecutionSummary, VmMemoryMetrics,
         },
         tracer,
     },
     vm::{VmFactory, VmInterface, VmInterfaceExt, VmInterfaceHistoryEnabled, VmTrackingContracts},
 };
 
+#[cfg(feature = "protobuf")]
+mod conv;
 pub mod executor;
+#[cfg(feature = "protobuf")]
+pub mod proto;
 pub mod pubdata;
 pub mod storage;
 mod types;
@@ -0,0 +1,3 @@
+#![allow(warnings)]
+
+include!(concat!(env!("OUT_DIR"), "/src/proto/gen.rs"));
@@ -1,11 +1,16 @@
 use std::fmt;
 
+use zksync_types::Address;
+
 use super::VmRevertReason;
 
 /// Structure for non-contract errors from the Virtual Machine (EVM).
 
 /// Differentiates VM-specific issues from contract-related errors.
+///
+/// New variants may be added in the future, so matching on `Halt` outside this crate requires a wildcard arm.
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum Halt {
     // Can only be returned in `VerifyAndExecute`
     ValidationFailed(VmRevertReason),
@@ -25,6 +30,9 @@ pub enum Halt {
     // their transaction would simply be rejected and they'll be able to provide
     // information about the cause to us.
     UnexpectedVMBehavior(String),
+    // Bootloader has failed because of an operator or a system contract error rather than
+    // because of the transaction. Rendered the same way as `UnexpectedVMBehavior`.
+    BootloaderInternalError(BootloaderInternalError),
     // Bootloader is out of gas.
     BootloaderOutOfGas,
     // Validation step is out of gas
@@ -36,13 +44,15 @@ pub enum Halt {
     // The tx consumes too much missing invocations to memory
     MissingInvocationLimitReached,
     // Failed to set information about the L2 block
-    FailedToSetL2Block(String),
+    FailedToSetL2Block(VmRevertReason),
     // Failed to publish information about the batch and the L2 block onto L1
-    FailedToAppendTransactionToL2Block(String),
+    FailedToAppendTransactionToL2Block(VmRevertReason),
     VMPanic,
     TracerCustom(String),
     FailedToPublishCompressedBytecodes,
     FailedBlockTimestampAssertion,
+    /// Transaction deployed a contract from an address not included into `SystemEnv::whitelisted_deployers`.
+    UnauthorizedDeployment(Address),
 }
 
 impl fmt::Display for Halt {
@@ -65,12 +75,8 @@ impl fmt::Display for Halt {
                 write!(f, "Pre-paymaster preparation error: {}", reason)
             }
             Halt::Unknown(reason) => write!(f, "Unknown reason: {}", reason),
-            Halt::UnexpectedVMBehavior(problem) => {
-                write!(f,
-                       "virtual machine entered unexpected state. Please contact developers and provide transaction details \
-                    that caused this error. Error description: {problem}"
-                )
-            }
+            Halt::UnexpectedVMBehavior(problem) => write_unexpected_vm_behavior(f, problem),
+            Halt::BootloaderInternalError(err) => write_unexpected_vm_behavior(f, err),
             Halt::BootloaderOutOfGas => write!(f, "Bootloader out of gas"),
             Halt::NotEnoughGasProvided => write!(
                 f,
@@ -120,6 +126,145 @@ impl fmt::Display for Halt {
             Halt::FailedBlockTimestampAssertion => {
                 write!(f, "Transaction failed block.timestamp assertion")
             }
+            Halt::UnauthorizedDeployment(deployer) => {
+                write!(f, "Contract deployment by {deployer:?} is not allowed")
+            }
         }
     }
 }
+
+/// The reason is already a part of the displayed message, so it isn't reported as the error source.
+impl std::error::Error for Halt {}
+
+fn write_unexpected_vm_behavior(
+    f: &mut fmt::Formatter<'_>,
+    problem: impl fmt::Display,
+) -> fmt::Result {
+    write!(
+        f,
+        "virtual machine entered unexpected state. Please contact developers and provide transaction details \
+         that caused this error. Error description: {problem}"
+    )
+}
+
+/// Bootloader failure caused by the operator or a system contract rather than by the transaction itself.
+/// Corresponds to a subset of [`BootloaderErrorCode`](super::BootloaderErrorCode)s.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum BootloaderInternalError {
+    UnacceptableGasPrice,
+    FailedToSendFeesToTheOperator,
+    UnacceptablePubdataPrice,
+    MaxPriorityFeeGreaterThanMaxFee,
+    BaseFeeGreaterThanMaxFeePerGas,
+    AssertionError(VmRevertReason),
+    L1MessengerLogSendingFailed(VmRevertReason),
+    L1MessengerPublishingFailed(VmRevertReason),
+    FailedToCallSystemContext(VmRevertReason),
+    MintEtherFailed(VmRevertReason),
+    FailedToPublishTimestampDataToL1(VmRevertReason),
+    /// Error code not known to this version of the server.
+    UnknownErrorCode {
+        code: u8,
+        reason: VmRevertReason,
+    },
+}
+
+impl fmt::Display for BootloaderInternalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnacceptableGasPrice => write!(
+                f,
+                "The operator included transaction with an unacceptable gas price"
+            ),
+            Self::FailedToSendFeesToTheOperator => write!(f, "FailedToSendFeesToTheOperator"),
+            Self::UnacceptablePubdataPrice => write!(f, "UnacceptablePubdataPrice"),
+            Self::MaxPriorityFeeGreaterThanMaxFee => {
+                write!(f, "Max priority fee greater than max fee")
+            }
+            Self::BaseFeeGreaterThanMaxFeePerGas => {
+                write!(f, "Block.basefee is greater than max fee per gas")
+            }
+            Self::AssertionError(reason) => write!(f, "Assertion error: {reason}"),
+            Self::L1MessengerLogSendingFailed(reason) => {
+                write!(f, "Failed to send log via L1Messenger for: {reason}")
+            }
+            Self::L1MessengerPublishingFailed(reason) => {
+                write!(f, "Failed to publish pubdata via L1Messenger for: {reason}")
+            }
+            Self::FailedToCallSystemContext(reason) => {
+                write!(f, "Failed to call system context contract: {reason}")
+            }
+            Self::MintEtherFailed(reason) => write!(f, "Failed to mint ether: {reason}"),
+            Self::FailedToPublishTimestampDataToL1(reason) => {
+                write!(f, "Failed to publish timestamp data to L1: {reason}")
+            }
+            Self::UnknownErrorCode { code, reason } => {
+                write!(f, "Unsupported error code: {code}. Revert reason: {reason}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use zksync_types::ethabi;
+
+    use super::*;
+    use crate::TxRevertReason;
+
+    fn encode_bootloader_error(code: u8, msg: &str) -> Vec<u8> {
+        let selector = ethabi::short_signature("Error", &[ethabi::ParamType::String]);
+        let encoded_msg = ethabi::encode(&[ethabi::Token::String(msg.to_owned())]);
+        [&[code][..], &selector, &encoded_msg].concat()
+    }
+
+    #[test]
+    fn parsing_bootloader_internal_errors() {
+        let reason = TxRevertReason::parse_error(&encode_bootloader_error(
+            17, // `AssertionError`
+            "Protocol upgrade tx not first",
+        ));
+        assert_matches!(
+            reason,
+            TxRevertReason::Halt(Halt::BootloaderInternalError(
+                BootloaderInternalError::AssertionError(VmRevertReason::General { msg, .. })
+            )) if msg == "Protocol upgrade tx not first"
+        );
+
+        let reason = TxRevertReason::parse_error(&encode_bootloader_error(u8::MAX, "oops"));
+        assert_matches!(
+            reason,
+            TxRevertReason::Halt(Halt::BootloaderInternalError(
+                BootloaderInternalError::UnknownErrorCode { code: u8::MAX, .. }
+            ))
+        );
+    }
+
+    #[test]
+    fn bootloader_internal_errors_are_displayed_as_unexpected_vm_behavior() {
+        let halt = Halt::BootloaderInternalError(BootloaderInternalError::AssertionError(
+            VmRevertReason::General {
+                msg: "Protocol upgrade tx not first".to_owned(),
+                data: vec![],
+            },
+        ));
+        let legacy_halt =
+            Halt::UnexpectedVMBehavior("Assertion error: Protocol upgrade tx not first".to_owned());
+        assert_eq!(halt.to_string(), legacy_halt.to_string());
+    }
+
+    #[test]
+    fn halt_is_propagated_as_error() {
+        fn execute() -> anyhow::Result<()> {
+            Err(Halt::FromIsNotAnAccount)?;
+            Ok(())
+        }
+
+        let err = execute().unwrap_err();
+        assert_eq!(err.to_string(), "Sender is not an account");
+        assert_matches!(err.downcast_ref::<Halt>(), Some(Halt::FromIsNotAnAccount));
+        assert_eq!(err.chain().count(), 1);
+    }
+}
@@ -0,0 +1,15 @@
+use zksync_types::ethabi;
+
+/// Errors that can occur when converting a [`VmEvent`](crate::VmEvent) into a user L2-to-L1 log.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum L1MessageEventError {
+    #[error("Event is not an `L1MessageSent` event emitted by the L1 messenger")]
+    NotAL1MessageEvent,
+    #[error("Malformed `L1MessageSent` event payload: {0}")]
+    MalformedPayload(#[source] ethabi::Error),
+    #[error("Message hash in event topics doesn't match the hash of the message payload")]
+    HashMismatch,
+    #[error("Transaction index {0} doesn't fit into u16")]
+    TxNumberOverflow(u32),
+}
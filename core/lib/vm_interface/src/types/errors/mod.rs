@@ -1,7 +1,8 @@
 pub use self::{
     bootloader_error::BootloaderErrorCode,
     bytecode_compression::{BytecodeCompressionError, BytecodeCompressionResult},
-    halt::Halt,
+    halt::{BootloaderInternalError, Halt},
+    l1_message::L1MessageEventError,
     tx_revert_reason::TxRevertReason,
     vm_revert_reason::{VmRevertReason, VmRevertReasonParsingError},
 };
@@ -9,5 +10,6 @@ pub use self::{
 mod bootloader_error;
 mod bytecode_compression;
 mod halt;
+mod l1_message;
 mod tx_revert_reason;
 mod vm_revert_reason;
@@ -1,6 +1,9 @@
 use std::fmt;
 
-use super::{halt::Halt, BootloaderErrorCode, VmRevertReason};
+use super::{
+    halt::{BootloaderInternalError, Halt},
+    BootloaderErrorCode, VmRevertReason,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TxRevertReason {
@@ -33,9 +36,9 @@ impl TxRevertReason {
                 msg: "Failed to check if `from` is an account. Most likely not enough gas provided".to_string(),
                 data: vec![],
             })),
-            BootloaderErrorCode::UnacceptableGasPrice => Self::Halt(Halt::UnexpectedVMBehavior(
-                "The operator included transaction with an unacceptable gas price".to_owned(),
-            )),
+            BootloaderErrorCode::UnacceptableGasPrice => {
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::UnacceptableGasPrice))
+            }
             BootloaderErrorCode::PrePaymasterPreparationFailed => {
                 Self::Halt(Halt::PrePaymasterPreparationFailed(revert_reason))
             }
@@ -43,7 +46,7 @@ impl TxRevertReason {
                 Self::Halt(Halt::PaymasterValidationFailed(revert_reason))
             }
             BootloaderErrorCode::FailedToSendFeesToTheOperator => {
-                Self::Halt(Halt::UnexpectedVMBehavior("FailedToSendFeesToTheOperator".to_owned()))
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::FailedToSendFeesToTheOperator))
             }
             BootloaderErrorCode::FailedToSetPrevBlockHash => {
                 panic!(
@@ -52,7 +55,7 @@ impl TxRevertReason {
                 )
             }
             BootloaderErrorCode::UnacceptablePubdataPrice => {
-                Self::Halt(Halt::UnexpectedVMBehavior("UnacceptablePubdataPrice".to_owned()))
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::UnacceptablePubdataPrice))
             }
             // This is different from `AccountTxValidationFailed` error in a way that it means that
             // the error was not produced by the account itself, but for some other unknown reason (most likely not enough gas)
@@ -61,7 +64,7 @@ impl TxRevertReason {
             // it is not parsed here. Unknown error means that bootloader failed by a reason
             // that was not specified by the protocol:
             BootloaderErrorCode::MaxPriorityFeeGreaterThanMaxFee => {
-                Self::Halt(Halt::UnexpectedVMBehavior("Max priority fee greater than max fee".to_owned()))
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::MaxPriorityFeeGreaterThanMaxFee))
             }
             BootloaderErrorCode::PaymasterReturnedInvalidContext => {
                 Self::Halt(Halt::PaymasterValidationFailed(VmRevertReason::General {
@@ -76,11 +79,11 @@ impl TxRevertReason {
                 }))
             }
             BootloaderErrorCode::AssertionError => {
-                Self::Halt(Halt::UnexpectedVMBehavior(format!("Assertion error: {}", revert_reason)))
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::AssertionError(revert_reason)))
+            }
+            BootloaderErrorCode::BaseFeeGreaterThanMaxFeePerGas => {
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::BaseFeeGreaterThanMaxFeePerGas))
             }
-            BootloaderErrorCode::BaseFeeGreaterThanMaxFeePerGas => Self::Halt(Halt::UnexpectedVMBehavior(
-                "Block.basefee is greater than max fee per gas".to_owned(),
-            )),
             BootloaderErrorCode::PayForTxFailed => {
                 Self::Halt(Halt::PayForTxFailed(revert_reason))
             },
@@ -107,28 +110,31 @@ impl TxRevertReason {
                 Self::Halt(Halt::ValidationFailed(VmRevertReason::General { msg: String::from("Paymaster validation returned invalid magic value. Please refer to the documentation of the paymaster for more details"), data: vec![] }))
             }
             BootloaderErrorCode::L1MessengerLogSendingFailed => {
-                Self::Halt(Halt::UnexpectedVMBehavior(format!("Failed to send log via L1Messenger for: {}", revert_reason)))
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::L1MessengerLogSendingFailed(revert_reason)))
             },
             BootloaderErrorCode::L1MessengerPublishingFailed => {
-                Self::Halt(Halt::UnexpectedVMBehavior(format!("Failed to publish pubdata via L1Messenger for: {}", revert_reason)))
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::L1MessengerPublishingFailed(revert_reason)))
             },
             BootloaderErrorCode::FailedToCallSystemContext => {
-                Self::Halt(Halt::UnexpectedVMBehavior(format!("Failed to call system context contract: {}", revert_reason)))
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::FailedToCallSystemContext(revert_reason)))
             },
-            BootloaderErrorCode::Unknown => Self::Halt(Halt::UnexpectedVMBehavior(format!(
-                "Unsupported error code: {}. Revert reason: {}",
-                error_code[0], revert_reason
-            ))),
-            BootloaderErrorCode::MintEtherFailed => Self::Halt(Halt::UnexpectedVMBehavior(format!("Failed to mint ether: {}", revert_reason))),
+            BootloaderErrorCode::Unknown => {
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::UnknownErrorCode {
+                    code: error_code[0],
+                    reason: revert_reason,
+                }))
+            }
+            BootloaderErrorCode::MintEtherFailed => {
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::MintEtherFailed(revert_reason)))
+            }
             BootloaderErrorCode::FailedToAppendTransactionToL2Block => {
-                Self::Halt(Halt::FailedToAppendTransactionToL2Block(format!("Failed to append transaction to L2 block: {}", revert_reason)))
+                Self::Halt(Halt::FailedToAppendTransactionToL2Block(revert_reason))
             }
             BootloaderErrorCode::FailedToSetL2Block => {
-                Self::Halt(Halt::FailedToSetL2Block(format!("{}", revert_reason)))
-
+                Self::Halt(Halt::FailedToSetL2Block(revert_reason))
             }
             BootloaderErrorCode::FailedToPublishTimestampDataToL1 => {
-                Self::Halt(Halt::UnexpectedVMBehavior(format!("Failed to publish timestamp data to L1: {}", revert_reason)))
+                Self::Halt(Halt::BootloaderInternalError(BootloaderInternalError::FailedToPublishTimestampDataToL1(revert_reason)))
             }
         }
     }
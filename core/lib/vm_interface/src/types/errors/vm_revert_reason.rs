@@ -30,23 +30,25 @@ pub enum VmRevertReason {
 }
 
 impl VmRevertReason {
-    const GENERAL_ERROR_SELECTOR: &'static [u8] = &[0x08, 0xc3, 0x79, 0xa0];
+    /// Selector of the Solidity `Error(string)` error.
+    pub(crate) const GENERAL_ERROR_SELECTOR: &'static [u8] = &[0x08, 0xc3, 0x79, 0xa0];
 
     fn parse_general_error(raw_bytes: &[u8]) -> Result<Self, VmRevertReasonParsingError> {
         let bytes = &raw_bytes[4..];
         if bytes.len() < 32 {
             return Err(VmRevertReasonParsingError::InputIsTooShort(bytes.to_vec()));
         }
-        let data_offset = U256::from_big_endian(&bytes[0..32]).as_usize();
+        let data_offset = U256::from_big_endian(&bytes[0..32]);
 
         // Data offset couldn't be less than 32 because data offset size is 32 bytes
         // and data offset bytes are part of the offset. Also data offset couldn't be greater than
         // data length
-        if data_offset > bytes.len() || data_offset < 32 {
+        if data_offset > U256::from(bytes.len()) || data_offset < U256::from(32) {
             return Err(VmRevertReasonParsingError::IncorrectDataOffset(
                 bytes.to_vec(),
             ));
         };
+        let data_offset = data_offset.as_usize();
 
         let data = &bytes[data_offset..];
 
@@ -54,13 +56,14 @@ impl VmRevertReason {
             return Err(VmRevertReasonParsingError::InputIsTooShort(bytes.to_vec()));
         };
 
-        let string_length = U256::from_big_endian(&data[0..32]).as_usize();
+        let string_length = U256::from_big_endian(&data[0..32]);
 
-        if string_length + 32 > data.len() {
+        if string_length > U256::from(data.len() - 32) {
             return Err(VmRevertReasonParsingError::IncorrectStringLength(
                 bytes.to_vec(),
             ));
         };
+        let string_length = string_length.as_usize();
 
         let raw_data = &data[32..32 + string_length];
         Ok(Self::General {
@@ -170,6 +173,7 @@ impl fmt::Display for VmRevertReason {
 #[cfg(test)]
 mod tests {
     use assert_matches::assert_matches;
+    use zksync_types::ethabi;
 
     use super::VmRevertReason;
 
@@ -253,4 +257,46 @@ mod tests {
         let reason = VmRevertReason::try_from_bytes(msg.as_slice());
         assert!(reason.is_err());
     }
+
+    #[test]
+    fn revert_reason_display_roundtrip() {
+        let mut encoded = VmRevertReason::GENERAL_ERROR_SELECTOR.to_vec();
+        encoded.extend(ethabi::encode(&[ethabi::Token::String(
+            "ERC20: transfer amount exceeds balance".to_owned(),
+        )]));
+        let reason = VmRevertReason::from(encoded.as_slice());
+        assert_matches!(reason, VmRevertReason::General { .. });
+        assert_eq!(reason.to_string(), "ERC20: transfer amount exceeds balance");
+        assert_eq!(reason.encoded_data(), encoded);
+    }
+
+    #[test]
+    fn revert_reason_display_for_non_general_variants() {
+        let reason = VmRevertReason::from([0xde, 0xad, 0xbe, 0xef, 0x01].as_slice());
+        assert_eq!(
+            reason.to_string(),
+            "Error function_selector = 0xdeadbeef, data = 0xdeadbeef01"
+        );
+        assert_eq!(reason.to_user_friendly_string(), "");
+
+        assert_eq!(
+            VmRevertReason::InnerTxError.to_string(),
+            "Bootloader-based tx failed"
+        );
+        assert_eq!(VmRevertReason::VmError.to_string(), "VM Error");
+    }
+
+    #[test]
+    fn revert_reason_with_out_of_range_offset_or_length() {
+        let selector = ethabi::short_signature("Error", &[ethabi::ParamType::String]);
+        let msg = [&selector[..], &[0xff; 64]].concat();
+        let reason = VmRevertReason::try_from_bytes(&msg);
+        assert!(reason.is_err());
+
+        let mut msg = [&selector[..], &[0; 64]].concat();
+        msg[4 + 31] = 32; // correct offset
+        msg[4 + 32..].fill(0xff); // string length
+        let reason = VmRevertReason::try_from_bytes(&msg);
+        assert!(reason.is_err());
+    }
 }
@@ -1,8 +1,8 @@
-use std::fmt::Debug;
+use std::{collections::HashSet, fmt::Debug};
 
 use serde::{Deserialize, Serialize};
 use zksync_contracts::BaseSystemContracts;
-use zksync_types::{L2ChainId, ProtocolVersionId};
+use zksync_types::{Address, L2ChainId, ProtocolVersionId};
 
 /// Params related to the execution process, not batch it self
 #[derive(Clone, PartialEq, Serialize, Deserialize)]
@@ -15,6 +15,10 @@ pub struct SystemEnv {
     pub execution_mode: TxExecutionMode,
     pub default_validation_computational_gas_limit: u32,
     pub chain_id: L2ChainId,
+    /// If set, only the specified addresses are allowed to deploy contracts in L2 transactions.
+    /// Transactions deploying contracts from other addresses are rejected by the batch executor.
+    #[serde(default)]
+    pub whitelisted_deployers: Option<HashSet<Address>>,
 }
 
 impl Debug for SystemEnv {
@@ -33,6 +37,7 @@ impl Debug for SystemEnv {
             )
             .field("execution_mode", &self.execution_mode)
             .field("chain_id", &self.chain_id)
+            .field("whitelisted_deployers", &self.whitelisted_deployers)
             .finish()
     }
 }
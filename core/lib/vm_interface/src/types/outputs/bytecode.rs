@@ -3,3 +3,88 @@ pub struct CompressedBytecodeInfo {
     pub original: Vec<u8>,
     pub compressed: Vec<u8>,
 }
+
+/// Aggregated sizes of bytecodes compressed for a transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BytecodeCompressionMetrics {
+    /// Total size of original bytecodes in bytes.
+    pub original_bytes: usize,
+    /// Total size of compressed bytecodes in bytes.
+    pub compressed_bytes: usize,
+}
+
+impl BytecodeCompressionMetrics {
+    pub fn from_bytecodes(bytecodes: &[CompressedBytecodeInfo]) -> Self {
+        Self {
+            original_bytes: bytecodes
+                .iter()
+                .map(|bytecode| bytecode.original.len())
+                .sum(),
+            compressed_bytes: bytecodes
+                .iter()
+                .map(|bytecode| bytecode.compressed.len())
+                .sum(),
+        }
+    }
+
+    /// Creates metrics for bytecodes published without compression, i.e., with the compressed size equal
+    /// to the original one.
+    pub fn uncompressed(bytecodes: &[Vec<u8>]) -> Self {
+        let total_bytes = bytecodes.iter().map(Vec::len).sum();
+        Self {
+            original_bytes: total_bytes,
+            compressed_bytes: total_bytes,
+        }
+    }
+
+    /// Returns the ratio of the original size to the compressed size, i.e., values greater than 1 mean that
+    /// compression has reduced the size. Returns 1 if no bytecodes were compressed.
+    pub fn compression_ratio(&self) -> f64 {
+        if self.compressed_bytes == 0 {
+            return 1.0;
+        }
+        self.original_bytes as f64 / self.compressed_bytes as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computing_compression_metrics() {
+        let bytecodes = [
+            CompressedBytecodeInfo {
+                original: vec![0; 320],
+                compressed: vec![0; 100],
+            },
+            CompressedBytecodeInfo {
+                original: vec![0; 64],
+                compressed: vec![0; 28],
+            },
+        ];
+        let metrics = BytecodeCompressionMetrics::from_bytecodes(&bytecodes);
+        assert_eq!(
+            metrics,
+            BytecodeCompressionMetrics {
+                original_bytes: 384,
+                compressed_bytes: 128,
+            }
+        );
+        assert_eq!(metrics.compression_ratio(), 3.0);
+
+        let empty = BytecodeCompressionMetrics::from_bytecodes(&[]);
+        assert_eq!(empty, BytecodeCompressionMetrics::default());
+        assert_eq!(empty.compression_ratio(), 1.0);
+
+        let uncompressed = BytecodeCompressionMetrics::uncompressed(&[vec![0; 320], vec![0; 64]]);
+        assert_eq!(
+            uncompressed,
+            BytecodeCompressionMetrics {
+                original_bytes: 384,
+                compressed_bytes: 384,
+            }
+        );
+        assert_eq!(uncompressed.compression_ratio(), 1.0);
+    }
+}
@@ -0,0 +1,241 @@
+//! Conversion of call traces to OpenTelemetry spans.
+
+use std::{ops, time::SystemTime};
+
+use opentelemetry::{
+    trace::{Event, SpanContext, SpanKind, Status},
+    InstrumentationLibrary, KeyValue,
+};
+use opentelemetry_sdk::{
+    export::trace::SpanData,
+    trace::{IdGenerator, RandomIdGenerator, SpanEvents, SpanLinks},
+};
+
+use super::Call;
+
+impl Call {
+    /// Converts this call and all its subcalls into OpenTelemetry spans, so that they can be exported together
+    /// with other spans of the node (e.g., via a `SpanExporter`). Spans are returned in the depth-first order,
+    /// starting from the span for this call.
+    ///
+    /// The span for this call is a child of `parent`; the span for each subcall is a child of the span for its parent call.
+    /// Call type and addresses are recorded as span attributes, and gas usage as a span event. Since call traces
+    /// don't record timing, all spans cover the provided `time` range (e.g., the execution time of the transaction).
+    pub fn to_opentelemetry_spans(
+        &self,
+        parent: &SpanContext,
+        time: ops::Range<SystemTime>,
+    ) -> Vec<SpanData> {
+        let mut converter = SpanConverter {
+            id_generator: RandomIdGenerator::default(),
+            instrumentation_lib: InstrumentationLibrary::builder(env!("CARGO_PKG_NAME"))
+                .with_version(env!("CARGO_PKG_VERSION"))
+                .build(),
+            time,
+            spans: vec![],
+        };
+        converter.push_spans(self, parent);
+        converter.spans
+    }
+}
+
+#[derive(Debug)]
+struct SpanConverter {
+    id_generator: RandomIdGenerator,
+    instrumentation_lib: InstrumentationLibrary,
+    time: ops::Range<SystemTime>,
+    spans: Vec<SpanData>,
+}
+
+impl SpanConverter {
+    fn push_spans(&mut self, root: &Call, parent: &SpanContext) {
+        // Traverses calls in the same way as `Call::flatten()`, additionally tracking the parent span context.
+        let mut stack = vec![(root, parent.clone())];
+        while let Some((call, parent)) = stack.pop() {
+            let span_context = self.push_span(call, &parent);
+            stack.extend(
+                call.calls
+                    .iter()
+                    .rev()
+                    .map(|subcall| (subcall, span_context.clone())),
+            );
+        }
+    }
+
+    /// Pushes a span for a single `call` and returns its context.
+    fn push_span(&mut self, call: &Call, parent: &SpanContext) -> SpanContext {
+        let span_context = SpanContext::new(
+            parent.trace_id(),
+            self.id_generator.new_span_id(),
+            parent.trace_flags(),
+            false,
+            parent.trace_state().clone(),
+        );
+
+        let attributes = vec![
+            KeyValue::new("type", call.r#type.to_string()),
+            KeyValue::new("from", format!("{:?}", call.from)),
+            KeyValue::new("to", format!("{:?}", call.to)),
+            KeyValue::new("value", call.value.to_string()),
+        ];
+        let gas_event = Event::new(
+            "gas",
+            self.time.end,
+            vec![
+                KeyValue::new("gas", saturating_i64(call.gas)),
+                KeyValue::new("gas_used", saturating_i64(call.gas_used)),
+            ],
+            0,
+        );
+        let mut events = SpanEvents::default();
+        events.events.push(gas_event);
+        let status = match call.error.as_ref().or(call.revert_reason.as_ref()) {
+            Some(err) => Status::error(err.clone()),
+            None => Status::Unset,
+        };
+
+        self.spans.push(SpanData {
+            span_context: span_context.clone(),
+            parent_span_id: parent.span_id(),
+            span_kind: SpanKind::Internal,
+            name: call.r#type.to_string().into(),
+            start_time: self.time.start,
+            end_time: self.time.end,
+            attributes,
+            dropped_attributes_count: 0,
+            events,
+            links: SpanLinks::default(),
+            status,
+            instrumentation_lib: self.instrumentation_lib.clone(),
+        });
+        span_context
+    }
+}
+
+/// OpenTelemetry only supports signed integer values.
+fn saturating_i64(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use futures::{executor::block_on, future::BoxFuture, FutureExt};
+    use opentelemetry::trace::{Span as _, Tracer as _, TracerProvider as _};
+    use opentelemetry_sdk::{
+        export::trace::{ExportResult, SpanExporter},
+        trace::TracerProvider,
+    };
+    use zksync_types::{zk_evm_types::FarCallOpcode, Address};
+
+    use super::*;
+    use crate::CallType;
+
+    /// Exporter collecting all exported spans in memory.
+    #[derive(Debug, Clone, Default)]
+    struct TestExporter(Arc<Mutex<Vec<SpanData>>>);
+
+    impl SpanExporter for TestExporter {
+        fn export(&mut self, batch: Vec<SpanData>) -> BoxFuture<'static, ExportResult> {
+            self.0.lock().unwrap().extend(batch);
+            async { Ok(()) }.boxed()
+        }
+    }
+
+    #[test]
+    fn exporting_call_spans() {
+        let exporter = TestExporter::default();
+        let provider = TracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let mut tx_span = provider.tracer("test").start("tx");
+
+        let call = Call {
+            r#type: CallType::Call(FarCallOpcode::Normal),
+            from: Address::repeat_byte(1),
+            to: Address::repeat_byte(2),
+            gas: 1_000,
+            gas_used: 600,
+            calls: vec![
+                Call {
+                    r#type: CallType::Create,
+                    gas_used: 200,
+                    revert_reason: Some("oops".to_owned()),
+                    ..Call::default()
+                },
+                Call {
+                    r#type: CallType::NearCall,
+                    gas_used: 100,
+                    ..Call::default()
+                },
+            ],
+            ..Call::default()
+        };
+        let start = SystemTime::now();
+        let spans = call.to_opentelemetry_spans(
+            tx_span.span_context(),
+            start..start + Duration::from_millis(10),
+        );
+        tx_span.end();
+        block_on(exporter.clone().export(spans)).unwrap();
+
+        let exported = exporter.0.lock().unwrap();
+        let span = |name: &str| exported.iter().find(|span| span.name == name).unwrap();
+        assert_eq!(exported.len(), 4);
+        let (tx_span, root_span) = (span("tx"), span("call"));
+        let (create_span, near_call_span) = (span("create"), span("nearcall"));
+
+        let trace_id = tx_span.span_context.trace_id();
+        for call_span in [root_span, create_span, near_call_span] {
+            assert_eq!(call_span.span_context.trace_id(), trace_id);
+            assert_eq!(call_span.start_time, start);
+        }
+        assert_eq!(root_span.parent_span_id, tx_span.span_context.span_id());
+        let root_id = root_span.span_context.span_id();
+        assert_eq!(create_span.parent_span_id, root_id);
+        assert_eq!(near_call_span.parent_span_id, root_id);
+
+        assert!(root_span.attributes.contains(&KeyValue::new(
+            "from",
+            format!("{:?}", Address::repeat_byte(1))
+        )));
+        assert!(root_span
+            .attributes
+            .contains(&KeyValue::new("type", "call")));
+        let gas_event = &root_span.events.events[0];
+        assert_eq!(gas_event.name, "gas");
+        assert!(gas_event
+            .attributes
+            .contains(&KeyValue::new("gas_used", 600_i64)));
+
+        assert_eq!(root_span.status, Status::Unset);
+        assert_eq!(create_span.status, Status::error("oops"));
+    }
+
+    #[test]
+    fn converting_deeply_nested_calls() {
+        let mut call = Call::default();
+        for gas_used in 1..100_000 {
+            call = Call {
+                gas_used,
+                calls: vec![call],
+                ..Call::default()
+            };
+        }
+        let parent = SpanContext::empty_context();
+        let start = SystemTime::now();
+        let spans = call.to_opentelemetry_spans(&parent, start..start + Duration::from_millis(10));
+
+        assert_eq!(spans.len(), 100_000);
+        assert_eq!(spans[0].parent_span_id, parent.span_id());
+        for (parent_span, span) in spans.iter().zip(&spans[1..]) {
+            assert_eq!(span.parent_span_id, parent_span.span_context.span_id());
+        }
+        // Dropping a deeply nested tree is recursive, so we leak it instead.
+        std::mem::forget(call);
+    }
+}
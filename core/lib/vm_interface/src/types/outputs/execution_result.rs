@@ -1,24 +1,72 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt, iter, ops,
+    time::Duration,
+};
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use zksync_system_constants::{
-    BOOTLOADER_ADDRESS, KNOWN_CODES_STORAGE_ADDRESS, L1_MESSENGER_ADDRESS,
-    PUBLISH_BYTECODE_OVERHEAD,
+    ACCOUNT_CODE_STORAGE_ADDRESS, BOOTLOADER_ADDRESS, BOOTLOADER_UTILITIES_ADDRESS,
+    CODE_ORACLE_ADDRESS, COMPLEX_UPGRADER_ADDRESS, COMPRESSOR_ADDRESS, CONTRACT_DEPLOYER_ADDRESS,
+    CONTRACT_FORCE_DEPLOYER_ADDRESS, CREATE2_FACTORY_ADDRESS, EVENT_WRITER_ADDRESS,
+    EVM_GAS_MANAGER_ADDRESS, EVM_HASHES_STORAGE_ADDRESS, EVM_PREDEPLOYS_MANAGER_ADDRESS,
+    IMMUTABLE_SIMULATOR_STORAGE_ADDRESS, KNOWN_CODES_STORAGE_ADDRESS, L1_MESSENGER_ADDRESS,
+    L2_ASSET_ROUTER_ADDRESS, L2_BASE_TOKEN_ADDRESS, L2_BRIDGEHUB_ADDRESS,
+    L2_GENESIS_UPGRADE_ADDRESS, L2_MESSAGE_ROOT_ADDRESS, L2_NATIVE_TOKEN_VAULT_ADDRESS,
+    MSG_VALUE_SIMULATOR_ADDRESS, NONCE_HOLDER_ADDRESS, PUBDATA_CHUNK_PUBLISHER_ADDRESS,
+    PUBLISH_BYTECODE_OVERHEAD, SLOAD_CONTRACT_ADDRESS, SYSTEM_CONTEXT_ADDRESS,
 };
 use zksync_types::{
-    bytecode::BytecodeHash,
-    ethabi,
-    l2_to_l1_log::{SystemL2ToL1Log, UserL2ToL1Log},
+    bytecode::{BytecodeHash, BytecodeMarker},
+    ethabi, h256_to_address, h256_to_u256,
+    l2_to_l1_log::{L2ToL1Log, SystemL2ToL1Log, UserL2ToL1Log},
+    web3::keccak256,
+    writes::compression::compress_with_best_strategy,
     zk_evm_types::FarCallOpcode,
-    Address, L1BatchNumber, StorageLogWithPreviousValue, Transaction, H256, U256,
+    Address, L1BatchNumber, ProtocolVersionId, StorageKey, StorageLogKind,
+    StorageLogWithPreviousValue, Transaction, H256, U256,
 };
 
 use crate::{
-    BytecodeCompressionError, Halt, VmExecutionMetrics, VmExecutionStatistics, VmRevertReason,
+    BytecodeCompressionError, BytecodeCompressionMetrics, DeduplicatedWritesMetrics, Halt,
+    L1MessageEventError, VmExecutionMetrics, VmExecutionStatistics, VmRevertReason,
 };
 
+/// Addresses of system contracts (excluding precompiles, which cannot emit events), including
+/// built-in contracts in the `0x1xxxx` address space.
+const SYSTEM_CONTRACT_ADDRESSES: [Address; 27] = [
+    BOOTLOADER_ADDRESS,
+    ACCOUNT_CODE_STORAGE_ADDRESS,
+    NONCE_HOLDER_ADDRESS,
+    KNOWN_CODES_STORAGE_ADDRESS,
+    IMMUTABLE_SIMULATOR_STORAGE_ADDRESS,
+    CONTRACT_DEPLOYER_ADDRESS,
+    CONTRACT_FORCE_DEPLOYER_ADDRESS,
+    L1_MESSENGER_ADDRESS,
+    MSG_VALUE_SIMULATOR_ADDRESS,
+    L2_BASE_TOKEN_ADDRESS,
+    SYSTEM_CONTEXT_ADDRESS,
+    BOOTLOADER_UTILITIES_ADDRESS,
+    EVENT_WRITER_ADDRESS,
+    COMPRESSOR_ADDRESS,
+    COMPLEX_UPGRADER_ADDRESS,
+    PUBDATA_CHUNK_PUBLISHER_ADDRESS,
+    CODE_ORACLE_ADDRESS,
+    EVM_GAS_MANAGER_ADDRESS,
+    EVM_PREDEPLOYS_MANAGER_ADDRESS,
+    EVM_HASHES_STORAGE_ADDRESS,
+    CREATE2_FACTORY_ADDRESS,
+    L2_GENESIS_UPGRADE_ADDRESS,
+    L2_BRIDGEHUB_ADDRESS,
+    L2_ASSET_ROUTER_ADDRESS,
+    L2_NATIVE_TOKEN_VAULT_ADDRESS,
+    L2_MESSAGE_ROOT_ADDRESS,
+    SLOAD_CONTRACT_ADDRESS,
+];
+
 /// Event generated by the VM.
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VmEvent {
     pub location: (L1BatchNumber, u32),
     pub address: Address,
@@ -47,56 +95,365 @@ impl VmEvent {
         58, 54, 228, 114, 145, 244, 32, 31, 175, 19, 127, 171, 8, 29, 146, 41, 91, 206, 45, 83,
         190, 44, 108, 166, 139, 168, 44, 127, 170, 156, 226, 65,
     ]);
+    /// Long signature of the ERC-20 token transfer event (`Transfer`).
+    pub const ERC20_TRANSFER_EVENT_SIGNATURE: H256 = H256([
+        221, 242, 82, 173, 27, 226, 200, 155, 105, 194, 176, 104, 252, 55, 141, 170, 149, 43, 167,
+        241, 99, 196, 161, 22, 40, 245, 90, 77, 245, 35, 179, 239,
+    ]);
+
+    /// Creates an event with the specified payload and a zero location, for use in tests that don't need
+    /// a real batch context.
+    #[cfg(any(test, feature = "test-support"))]
+    pub fn synthetic(address: Address, indexed_topics: Vec<H256>, value: Vec<u8>) -> Self {
+        Self {
+            location: (L1BatchNumber(0), 0),
+            address,
+            indexed_topics,
+            value,
+        }
+    }
+
+    /// Returns the number of the L1 batch in which this event was emitted.
+    pub fn location_batch(&self) -> L1BatchNumber {
+        self.location.0
+    }
+
+    /// Returns the index of the transaction in the L1 batch that has emitted this event.
+    pub fn location_tx_index(&self) -> u32 {
+        self.location.1
+    }
+
+    /// Returns the indexed topic at the specified `index`, or `None` if the event has fewer topics.
+    pub fn indexed_topic_at(&self, index: usize) -> Option<H256> {
+        self.indexed_topics.get(index).copied()
+    }
+
+    /// Checks whether the first indexed topic of this event (i.e., the event signature for non-anonymous events)
+    /// equals the provided `signature`. This is the preferred way to filter events by their type.
+    pub fn matches_signature(&self, signature: H256) -> bool {
+        self.indexed_topic_at(0) == Some(signature)
+    }
+
+    /// Checks whether this event was emitted by a system contract (e.g., the L1 messenger or the contract deployer)
+    /// as opposed to a user contract.
+    pub fn is_from_system_contract(&self) -> bool {
+        SYSTEM_CONTRACT_ADDRESSES.contains(&self.address)
+    }
+
+    /// Decodes this event as a contract deployment event (`ContractDeployed`) emitted by the contract deployer.
+    /// Returns `None` if the event is not a deployment event.
+    pub fn decode_deploy_event(&self) -> Option<DeployEvent> {
+        if self.address != CONTRACT_DEPLOYER_ADDRESS
+            || self.indexed_topics.len() != 4
+            || !self.matches_signature(Self::DEPLOY_EVENT_SIGNATURE)
+        {
+            return None;
+        }
+        Some(DeployEvent {
+            deployer: h256_to_address(&self.indexed_topic_at(1)?),
+            bytecode_hash: self.indexed_topic_at(2)?,
+            contract_address: h256_to_address(&self.indexed_topic_at(3)?),
+        })
+    }
+
+    /// Checks that all contract deployments among `events` were performed by one of `whitelisted_deployers`.
+    /// Returns [`Halt::UnauthorizedDeployment`] for the first deployment violating this requirement.
+    pub fn validate_deployments(
+        events: &[Self],
+        whitelisted_deployers: &HashSet<Address>,
+    ) -> Result<(), Halt> {
+        let unauthorized_deployment = events
+            .iter()
+            .filter_map(Self::decode_deploy_event)
+            .find(|event| !whitelisted_deployers.contains(&event.deployer));
+        match unauthorized_deployment {
+            Some(event) => Err(Halt::UnauthorizedDeployment(event.deployer)),
+            None => Ok(()),
+        }
+    }
+
+    fn is_l1_message_event(&self) -> bool {
+        // Filter events from the l1 messenger contract that match the expected signature.
+        self.address == L1_MESSENGER_ADDRESS
+            && self.indexed_topics.len() == 3
+            && self.matches_signature(Self::L1_MESSAGE_EVENT_SIGNATURE)
+    }
+
+    fn decode_l1_message(&self) -> Vec<u8> {
+        self.try_decode_bytes()
+            .expect("Failed to decode L1MessageSent message")
+    }
+
+    /// Decodes the non-indexed data of this event as a single ABI-encoded value of type `T`.
+    pub fn try_decode_abi<T: AbiDecode>(&self) -> Result<T, ethabi::Error> {
+        let token = ethabi::decode(&[T::param_type()], &self.value)?
+            .into_iter()
+            .next();
+        token
+            .and_then(T::from_token)
+            .ok_or(ethabi::Error::InvalidData)
+    }
+
+    /// Decodes the non-indexed data of this event as ABI-encoded `bytes`.
+    pub fn try_decode_bytes(&self) -> Result<Vec<u8>, ethabi::Error> {
+        self.try_decode_abi()
+    }
+
+    /// Decodes the non-indexed data of this event as an ABI-encoded `address`.
+    pub fn try_decode_address(&self) -> Result<Address, ethabi::Error> {
+        self.try_decode_abi()
+    }
+
+    /// Decodes the non-indexed data of this event as an ABI-encoded `uint256`.
+    pub fn try_decode_u256(&self) -> Result<U256, ethabi::Error> {
+        self.try_decode_abi()
+    }
 
     /// Extracts all the "long" L2->L1 messages that were submitted by the L1Messenger contract.
     pub fn extract_long_l2_to_l1_messages(events: &[Self]) -> Vec<Vec<u8>> {
+        events
+            .iter()
+            .filter(|event| event.is_l1_message_event())
+            .map(Self::decode_l1_message)
+            .collect()
+    }
+
+    /// Same as [`Self::extract_long_l2_to_l1_messages()`], but only returns messages sent by the specified `sender`.
+    /// Messages from other senders are filtered out before decoding.
+    pub fn extract_long_l2_to_l1_messages_from(events: &[Self], sender: Address) -> Vec<Vec<u8>> {
+        let sender_topic = H256::from(sender);
         events
             .iter()
             .filter(|event| {
-                // Filter events from the l1 messenger contract that match the expected signature.
-                event.address == L1_MESSENGER_ADDRESS
-                    && event.indexed_topics.len() == 3
-                    && event.indexed_topics[0] == Self::L1_MESSAGE_EVENT_SIGNATURE
+                event.is_l1_message_event() && event.indexed_topic_at(1) == Some(sender_topic)
             })
+            .map(Self::decode_l1_message)
+            .collect()
+    }
+
+    /// Same as [`Self::extract_long_l2_to_l1_messages()`], but also returns the sender of each message
+    /// (i.e., the address that has called the L1 messenger), as `(sender, message)` pairs.
+    pub fn extract_l1_messages_with_sender(events: &[Self]) -> Vec<(Address, Vec<u8>)> {
+        events
+            .iter()
+            .filter(|event| event.is_l1_message_event())
             .map(|event| {
-                let decoded_tokens = ethabi::decode(&[ethabi::ParamType::Bytes], &event.value)
-                    .expect("Failed to decode L1MessageSent message");
-                // The `Token` does not implement `Copy` trait, so I had to do it like that:
-                let bytes_token = decoded_tokens.into_iter().next().unwrap();
-                bytes_token.into_bytes().unwrap()
+                // The topic count is checked in `is_l1_message_event()`.
+                let sender = h256_to_address(&event.indexed_topics[1]);
+                (sender, event.decode_l1_message())
             })
             .collect()
     }
 
+    /// Decodes a `MarkedAsKnown` event into the bytecode hash and the flag whether the bytecode should be published onchain.
+    fn decode_marked_as_known(&self) -> Option<(H256, bool)> {
+        // Filter events from the deployer contract that match the expected signature.
+        if self.address != KNOWN_CODES_STORAGE_ADDRESS
+            || self.indexed_topics.len() != 3
+            || !self.matches_signature(Self::PUBLISHED_BYTECODE_SIGNATURE)
+        {
+            return None;
+        }
+        let bytecode_hash = self.indexed_topic_at(1)?;
+        let should_publish = self.indexed_topic_at(2)? != H256::zero();
+        Some((bytecode_hash, should_publish))
+    }
+
     /// Extracts bytecodes that were marked as known on the system contracts and should be published onchain.
     pub fn extract_published_bytecodes(events: &[Self]) -> Vec<H256> {
         events
             .iter()
-            .filter(|event| {
-                // Filter events from the deployer contract that match the expected signature.
-                event.address == KNOWN_CODES_STORAGE_ADDRESS
-                    && event.indexed_topics.len() == 3
-                    && event.indexed_topics[0] == Self::PUBLISHED_BYTECODE_SIGNATURE
-                    && event.indexed_topics[2] != H256::zero()
+            .filter_map(Self::decode_marked_as_known)
+            .filter_map(|(bytecode_hash, should_publish)| should_publish.then_some(bytecode_hash))
+            .collect()
+    }
+
+    /// Same as [`Self::extract_published_bytecodes()`], but also returns the length of each bytecode in bytes
+    /// (as encoded in its hash).
+    ///
+    /// # Panics
+    ///
+    /// Panics if a published bytecode hash cannot be parsed.
+    pub fn extract_published_bytecodes_with_size(events: &[Self]) -> Vec<(H256, usize)> {
+        Self::extract_published_bytecodes(events)
+            .into_iter()
+            .map(|bytecode_hash| {
+                let len_in_bytes = BytecodeHash::try_from(bytecode_hash)
+                    .expect("published unparseable bytecode hash")
+                    .len_in_bytes();
+                (bytecode_hash, len_in_bytes)
             })
-            .map(|event| event.indexed_topics[1])
             .collect()
     }
 
+    /// Extracts the first Solidity revert reason (i.e., ABI-encoded `Error(string)`) found in the event payloads.
+    /// The revert data may be emitted either as is, or wrapped in ABI-encoded `bytes`. This is useful to debug
+    /// contracts (e.g., proxies) that catch reverts of their callees and re-emit them.
+    pub fn extract_revert_reason_from_events(events: &[Self]) -> Option<String> {
+        events.iter().find_map(Self::decode_revert_reason)
+    }
+
+    fn decode_revert_reason(&self) -> Option<String> {
+        let is_revert_data = |data: &[u8]| data.starts_with(VmRevertReason::GENERAL_ERROR_SELECTOR);
+        let revert_data = if is_revert_data(&self.value) {
+            self.value.clone()
+        } else {
+            let data = self.try_decode_bytes().ok()?;
+            if !is_revert_data(&data) {
+                return None;
+            }
+            data
+        };
+        match VmRevertReason::from(revert_data.as_slice()) {
+            VmRevertReason::General { msg, .. } => Some(msg),
+            _ => None,
+        }
+    }
+
     /// Extracts all bytecodes marked as known on the system contracts.
     pub fn extract_bytecodes_marked_as_known(events: &[Self]) -> impl Iterator<Item = H256> + '_ {
         events
             .iter()
-            .filter(|event| {
-                // Filter events from the deployer contract that match the expected signature.
-                event.address == KNOWN_CODES_STORAGE_ADDRESS
-                    && event.indexed_topics.len() == 3
-                    && event.indexed_topics[0] == Self::PUBLISHED_BYTECODE_SIGNATURE
-            })
-            .map(|event| event.indexed_topics[1])
+            .filter_map(Self::decode_marked_as_known)
+            .map(|(bytecode_hash, _)| bytecode_hash)
+    }
+
+    /// Decodes an ERC-20 `Transfer` event into `(token, from, to, amount)`.
+    fn decode_erc20_transfer(&self) -> Option<(Address, Address, Address, U256)> {
+        // ERC-721 `Transfer` events have the same signature, but the token ID is indexed and the data is empty,
+        // so they are filtered out by the topic count and the failing decoding.
+        if self.indexed_topics.len() != 3
+            || !self.matches_signature(Self::ERC20_TRANSFER_EVENT_SIGNATURE)
+        {
+            return None;
+        }
+        let from = h256_to_address(&self.indexed_topic_at(1)?);
+        let to = h256_to_address(&self.indexed_topic_at(2)?);
+        let amount = self.try_decode_u256().ok()?;
+        Some((self.address, from, to, amount))
+    }
+
+    /// Extracts all ERC-20 token transfers as `(token, from, to, amount)` tuples, where `token` is the address
+    /// of the token contract that has emitted the `Transfer` event.
+    pub fn extract_erc20_transfers(events: &[Self]) -> Vec<(Address, Address, Address, U256)> {
+        events
+            .iter()
+            .filter_map(Self::decode_erc20_transfer)
+            .collect()
+    }
+}
+
+/// Value that can be decoded from ABI encoding with a statically known type, e.g. using
+/// [`VmEvent::try_decode_abi()`].
+pub trait AbiDecode: Sized {
+    /// Returns the ABI type of values.
+    fn param_type() -> ethabi::ParamType;
+    /// Converts a token decoded according to [`Self::param_type()`]. Returns `None` if the token has
+    /// an unexpected type.
+    fn from_token(token: ethabi::Token) -> Option<Self>;
+}
+
+impl AbiDecode for Vec<u8> {
+    fn param_type() -> ethabi::ParamType {
+        ethabi::ParamType::Bytes
+    }
+
+    fn from_token(token: ethabi::Token) -> Option<Self> {
+        token.into_bytes()
+    }
+}
+
+impl AbiDecode for Address {
+    fn param_type() -> ethabi::ParamType {
+        ethabi::ParamType::Address
+    }
+
+    fn from_token(token: ethabi::Token) -> Option<Self> {
+        token.into_address()
+    }
+}
+
+impl AbiDecode for U256 {
+    fn param_type() -> ethabi::ParamType {
+        ethabi::ParamType::Uint(256)
+    }
+
+    fn from_token(token: ethabi::Token) -> Option<Self> {
+        token.into_uint()
+    }
+}
+
+impl AbiDecode for H256 {
+    fn param_type() -> ethabi::ParamType {
+        ethabi::ParamType::FixedBytes(32)
+    }
+
+    fn from_token(token: ethabi::Token) -> Option<Self> {
+        let bytes = token.into_fixed_bytes()?;
+        (bytes.len() == 32).then(|| H256::from_slice(&bytes))
+    }
+}
+
+impl AbiDecode for bool {
+    fn param_type() -> ethabi::ParamType {
+        ethabi::ParamType::Bool
+    }
+
+    fn from_token(token: ethabi::Token) -> Option<Self> {
+        token.into_bool()
+    }
+}
+
+/// Converts an `L1MessageSent` event into the user L2-to-L1 log emitted by the L1 messenger alongside it.
+impl TryFrom<&VmEvent> for UserL2ToL1Log {
+    type Error = L1MessageEventError;
+
+    fn try_from(event: &VmEvent) -> Result<Self, Self::Error> {
+        if !event.is_l1_message_event() {
+            return Err(L1MessageEventError::NotAL1MessageEvent);
+        }
+        let message = event
+            .try_decode_bytes()
+            .map_err(L1MessageEventError::MalformedPayload)?;
+        let message_hash = event.indexed_topics[2];
+        if H256(keccak256(&message)) != message_hash {
+            return Err(L1MessageEventError::HashMismatch);
+        }
+        let tx_number_in_block = u16::try_from(event.location_tx_index())
+            .map_err(|_| L1MessageEventError::TxNumberOverflow(event.location_tx_index()))?;
+
+        Ok(Self(L2ToL1Log {
+            shard_id: 0,
+            is_service: true,
+            tx_number_in_block,
+            sender: L1_MESSENGER_ADDRESS,
+            // The sender of the message is stored as the key, padded to 32 bytes.
+            key: event.indexed_topics[1],
+            value: message_hash,
+        }))
     }
 }
 
+/// Decoded contract deployment event (`ContractDeployed`) emitted by the contract deployer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeployEvent {
+    /// Address of the account that has deployed the contract.
+    pub deployer: Address,
+    /// Hash of the deployed bytecode.
+    pub bytecode_hash: H256,
+    /// Address of the deployed contract.
+    pub contract_address: Address,
+}
+
+/// Kind of an upgradeable proxy contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProxyKind {
+    /// Transparent proxy (EIP-1967) with upgrade logic in the proxy itself, controlled by an admin.
+    TransparentProxy,
+    /// Universal upgradeable proxy (UUPS, EIP-1822) with upgrade logic in the implementation contract.
+    Uups,
+}
+
 /// Refunds produced for the user.
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct Refunds {
@@ -104,8 +461,43 @@ pub struct Refunds {
     pub operator_suggested_refund: u64,
 }
 
+impl Refunds {
+    /// Returns the larger of the refund computed by the bootloader and the refund suggested by the operator.
+    pub fn total(&self) -> u64 {
+        self.gas_refunded.max(self.operator_suggested_refund)
+    }
+
+    /// Checks whether the refund suggested by the operator differs from the refund computed by the bootloader
+    /// by more than `threshold_percent` percent. The difference is relative to the larger of the two refunds,
+    /// so the check is symmetric; if both refunds are zero, they are considered equal.
+    pub fn is_meaningful(&self, threshold_percent: u8) -> bool {
+        let difference = self.gas_refunded.abs_diff(self.operator_suggested_refund);
+        u128::from(difference) * 100 > u128::from(threshold_percent) * u128::from(self.total())
+    }
+}
+
+impl ops::Add for Refunds {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            gas_refunded: self.gas_refunded + other.gas_refunded,
+            operator_suggested_refund: self.operator_suggested_refund
+                + other.operator_suggested_refund,
+        }
+    }
+}
+
+impl ops::AddAssign for Refunds {
+    fn add_assign(&mut self, other: Self) {
+        self.gas_refunded += other.gas_refunded;
+        self.operator_suggested_refund += other.operator_suggested_refund;
+    }
+}
+
 /// Events/storage logs/l2->l1 logs created within transaction execution.
 #[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct VmExecutionLogs {
     pub storage_logs: Vec<StorageLogWithPreviousValue>,
     pub events: Vec<VmEvent>,
@@ -121,6 +513,239 @@ impl VmExecutionLogs {
     pub fn total_l2_to_l1_logs_count(&self) -> usize {
         self.user_l2_to_l1_logs.len() + self.system_l2_to_l1_logs.len()
     }
+
+    /// Groups events by the emitting contract address. Events for each address retain their relative order.
+    pub fn events_by_address(&self) -> HashMap<Address, Vec<&VmEvent>> {
+        let mut grouped = HashMap::<_, Vec<_>>::new();
+        for event in &self.events {
+            grouped.entry(event.address).or_default().push(event);
+        }
+        grouped
+    }
+
+    /// Returns events emitted by user contracts, i.e. ones not
+    /// [emitted by system contracts](VmEvent::is_from_system_contract()).
+    pub fn user_events(&self) -> impl Iterator<Item = &VmEvent> + '_ {
+        self.events
+            .iter()
+            .filter(|event| !event.is_from_system_contract())
+    }
+
+    /// Returns events [emitted by system contracts](VmEvent::is_from_system_contract()).
+    pub fn system_events(&self) -> impl Iterator<Item = &VmEvent> + '_ {
+        self.events
+            .iter()
+            .filter(|event| event.is_from_system_contract())
+    }
+
+    /// Returns the number of [user events](Self::user_events()).
+    pub fn user_event_count(&self) -> usize {
+        self.user_events().count()
+    }
+
+    /// Returns the number of [system events](Self::system_events()).
+    pub fn system_event_count(&self) -> usize {
+        self.system_events().count()
+    }
+
+    /// Counts events by their signature (i.e., the first topic). Events without topics are counted under `H256::zero()`.
+    pub fn count_events_by_signature(&self) -> HashMap<H256, usize> {
+        let mut counts = HashMap::new();
+        for event in &self.events {
+            let signature = event.indexed_topics.first().copied().unwrap_or_default();
+            *counts.entry(signature).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Groups storage logs (both reads and writes) by the accessed slot, i.e. by `(contract_address, storage_key)`.
+    /// Logs for each slot retain their relative order.
+    pub fn storage_logs_by_slot(
+        &self,
+    ) -> HashMap<(Address, H256), Vec<&StorageLogWithPreviousValue>> {
+        let mut grouped = HashMap::<_, Vec<_>>::new();
+        for log in &self.storage_logs {
+            let slot = (*log.log.key.address(), *log.log.key.key());
+            grouped.entry(slot).or_default().push(log);
+        }
+        grouped
+    }
+
+    /// Returns the last value written to the specified slot, or `None` if the slot wasn't written to.
+    pub fn last_value_for_slot(&self, address: Address, key: H256) -> Option<H256> {
+        self.storage_logs
+            .iter()
+            .rev()
+            .find(|log| {
+                log.log.is_write() && *log.log.key.address() == address && *log.log.key.key() == key
+            })
+            .map(|log| log.log.value)
+    }
+
+    /// Returns the net effect of storage writes, i.e. the final value for each written slot `(contract_address, storage_key)`.
+    /// Slots for which the final value equals the value before the first write (i.e., writes have no net effect)
+    /// are omitted, as are slots that were only read.
+    pub fn net_storage_writes(&self) -> HashMap<(Address, H256), H256> {
+        collapse_storage_writes(&self.storage_logs)
+            .into_iter()
+            .filter(|(_, write)| write.previous_value != write.value)
+            .map(|(key, write)| ((*key.address(), *key.key()), write.value))
+            .collect()
+    }
+
+    /// Removes duplicate storage writes, so that each written slot has a single write log reflecting the net effect
+    /// of all writes to the slot. The retained log is the last write to the slot (i.e., it has the final value),
+    /// but its previous value and kind are taken from the first write. Read logs are left intact.
+    pub fn deduplicate_storage_logs(&mut self) {
+        let writes = collapse_storage_writes(&self.storage_logs);
+        let mut i = 0;
+        self.storage_logs.retain_mut(|log| {
+            let index = i;
+            i += 1;
+            if !log.log.is_write() {
+                return true;
+            }
+            let write = &writes[&log.log.key];
+            if index != write.last_index {
+                return false;
+            }
+            log.log.kind = write.kind;
+            log.previous_value = write.previous_value;
+            true
+        });
+    }
+
+    /// Appends logs from `other` to these logs. Logs from `other` are placed after the existing logs.
+    pub fn merge_in_place(&mut self, other: Self) {
+        self.storage_logs.extend(other.storage_logs);
+        self.events.extend(other.events);
+        self.user_l2_to_l1_logs.extend(other.user_l2_to_l1_logs);
+        self.system_l2_to_l1_logs.extend(other.system_l2_to_l1_logs);
+        self.total_log_queries_count += other.total_log_queries_count;
+    }
+
+    /// Combines these logs with `other` logs in the append order.
+    pub fn merge(mut self, other: Self) -> Self {
+        self.merge_in_place(other);
+        self
+    }
+
+    /// Computes a commitment to storage logs, events and L2-to-L1 logs, i.e. a keccak256 hash of their serialization.
+    /// The commitment depends on the order of logs, but not on the log query count (which is a statistic).
+    pub fn commitment(&self) -> H256 {
+        let mut buffer = vec![];
+        // Each list is prefixed with its length so that the serialization is unambiguous.
+        let push_len = |buffer: &mut Vec<u8>, len: usize| {
+            buffer.extend_from_slice(&(len as u64).to_be_bytes());
+        };
+
+        push_len(&mut buffer, self.storage_logs.len());
+        for log in &self.storage_logs {
+            buffer.push(match log.log.kind {
+                StorageLogKind::Read => 0,
+                StorageLogKind::InitialWrite => 1,
+                StorageLogKind::RepeatedWrite => 2,
+            });
+            buffer.extend_from_slice(log.log.key.address().as_bytes());
+            buffer.extend_from_slice(log.log.key.key().as_bytes());
+            buffer.extend_from_slice(log.log.value.as_bytes());
+            buffer.extend_from_slice(log.previous_value.as_bytes());
+        }
+
+        push_len(&mut buffer, self.events.len());
+        for event in &self.events {
+            buffer.extend_from_slice(&event.location.0 .0.to_be_bytes());
+            buffer.extend_from_slice(&event.location.1.to_be_bytes());
+            buffer.extend_from_slice(event.address.as_bytes());
+            push_len(&mut buffer, event.indexed_topics.len());
+            for topic in &event.indexed_topics {
+                buffer.extend_from_slice(topic.as_bytes());
+            }
+            push_len(&mut buffer, event.value.len());
+            buffer.extend_from_slice(&event.value);
+        }
+
+        push_len(&mut buffer, self.user_l2_to_l1_logs.len());
+        for log in &self.user_l2_to_l1_logs {
+            buffer.extend_from_slice(&log.0.to_bytes());
+        }
+        push_len(&mut buffer, self.system_l2_to_l1_logs.len());
+        for log in &self.system_l2_to_l1_logs {
+            buffer.extend_from_slice(&log.0.to_bytes());
+        }
+        H256(keccak256(&buffer))
+    }
+
+    /// Splits events and L2-to-L1 logs into ones emitted by transactions with index (in the batch) less than `tx_index`,
+    /// and ones emitted by the transaction with `tx_index` and subsequent transactions.
+    ///
+    /// Events and L2-to-L1 logs must be ordered by the transaction index, as they are in a batch execution result;
+    /// otherwise, the split is unspecified. Storage logs don't record the transaction index, so they are kept
+    /// in the first part as a whole (as is the log query count); the second part never contains storage logs.
+    pub fn split_at_tx(self, tx_index: u32) -> (Self, Self) {
+        let mut events = self.events;
+        let events_tail =
+            events.split_off(events.partition_point(|event| event.location.1 < tx_index));
+        let is_before = |log: &L2ToL1Log| u32::from(log.tx_number_in_block) < tx_index;
+        let mut user_logs = self.user_l2_to_l1_logs;
+        let user_logs_tail =
+            user_logs.split_off(user_logs.partition_point(|log| is_before(&log.0)));
+        let mut system_logs = self.system_l2_to_l1_logs;
+        let system_logs_tail =
+            system_logs.split_off(system_logs.partition_point(|log| is_before(&log.0)));
+
+        let head = Self {
+            storage_logs: self.storage_logs,
+            events,
+            user_l2_to_l1_logs: user_logs,
+            system_l2_to_l1_logs: system_logs,
+            total_log_queries_count: self.total_log_queries_count,
+        };
+        let tail = Self {
+            events: events_tail,
+            user_l2_to_l1_logs: user_logs_tail,
+            system_l2_to_l1_logs: system_logs_tail,
+            ..Self::default()
+        };
+        (head, tail)
+    }
+}
+
+/// Net effect of all writes to a single storage slot.
+#[derive(Debug, Clone, Copy)]
+struct CollapsedWrite {
+    /// Index of the last write to the slot in the collapsed `storage_logs`.
+    last_index: usize,
+    /// Kind of the first write to the slot.
+    kind: StorageLogKind,
+    /// Value before the first write to the slot.
+    previous_value: H256,
+    /// Value after the last write to the slot.
+    value: H256,
+}
+
+/// Collapses all writes among `storage_logs` for each written slot. Read logs are ignored.
+fn collapse_storage_writes(
+    storage_logs: &[StorageLogWithPreviousValue],
+) -> HashMap<StorageKey, CollapsedWrite> {
+    let mut writes = HashMap::<StorageKey, CollapsedWrite>::new();
+    for (i, log) in storage_logs.iter().enumerate() {
+        if log.log.is_write() {
+            writes
+                .entry(log.log.key)
+                .and_modify(|write| {
+                    write.last_index = i;
+                    write.value = log.log.value;
+                })
+                .or_insert(CollapsedWrite {
+                    last_index: i,
+                    kind: log.log.kind,
+                    previous_value: log.previous_value,
+                    value: log.log.value,
+                });
+        }
+    }
+    writes
 }
 
 /// Result and logs of the VM execution.
@@ -136,6 +761,43 @@ pub struct VmExecutionResultAndLogs {
     pub dynamic_factory_deps: HashMap<H256, Vec<u8>>,
 }
 
+/// Compares results ignoring statistics that can legitimately differ between VM implementations, namely `cycles_used`
+/// and `circuit_statistic`. Use [`VmExecutionResultAndLogs::eq_strict()`] to compare all fields.
+impl PartialEq for VmExecutionResultAndLogs {
+    fn eq(&self, other: &Self) -> bool {
+        // Destructuring ensures that new fields are explicitly either compared or ignored.
+        let Self {
+            result,
+            logs,
+            statistics,
+            refunds,
+            dynamic_factory_deps,
+        } = self;
+        let VmExecutionStatistics {
+            contracts_used,
+            cycles_used: _,
+            gas_used,
+            gas_remaining,
+            computational_gas_used,
+            total_log_queries,
+            pubdata_published,
+            circuit_statistic: _,
+        } = statistics;
+        let other_stats = &other.statistics;
+
+        *result == other.result
+            && *logs == other.logs
+            && *refunds == other.refunds
+            && *dynamic_factory_deps == other.dynamic_factory_deps
+            && *contracts_used == other_stats.contracts_used
+            && *gas_used == other_stats.gas_used
+            && *gas_remaining == other_stats.gas_remaining
+            && *computational_gas_used == other_stats.computational_gas_used
+            && *total_log_queries == other_stats.total_log_queries
+            && *pubdata_published == other_stats.pubdata_published
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ExecutionResult {
     /// Returned successfully
@@ -151,6 +813,27 @@ impl ExecutionResult {
     pub fn is_failed(&self) -> bool {
         matches!(self, Self::Revert { .. } | Self::Halt { .. })
     }
+
+    /// Returns `true` if the execution was successful.
+    pub fn is_success(&self) -> bool {
+        matches!(self, Self::Success { .. })
+    }
+
+    /// Returns the output of a successful execution, or `None` if the execution has failed.
+    pub fn output(&self) -> Option<&[u8]> {
+        match self {
+            Self::Success { output } => Some(output),
+            Self::Revert { .. } | Self::Halt { .. } => None,
+        }
+    }
+
+    /// Returns the revert reason if the execution was reverted by a contract.
+    pub fn revert_reason(&self) -> Option<&VmRevertReason> {
+        match self {
+            Self::Revert { output } => Some(output),
+            Self::Success { .. } | Self::Halt { .. } => None,
+        }
+    }
 }
 
 impl VmExecutionResultAndLogs {
@@ -170,6 +853,192 @@ impl VmExecutionResultAndLogs {
         Self::mock(ExecutionResult::Success { output: vec![] })
     }
 
+    /// Returns the execution status of the transaction. Both reverted and halted transactions are considered failed.
+    pub fn execution_status(&self) -> TxExecutionStatus {
+        TxExecutionStatus::from_has_failed(self.result.is_failed())
+    }
+
+    /// Asserts that the execution has succeeded.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the revert or halt reason if the execution has failed.
+    #[cfg(any(test, feature = "test-support"))]
+    #[track_caller]
+    pub fn assert_success(&self) {
+        match &self.result {
+            ExecutionResult::Success { .. } => { /* OK */ }
+            ExecutionResult::Revert { output } => {
+                panic!("expected execution to succeed, but it was reverted: {output}");
+            }
+            ExecutionResult::Halt { reason } => {
+                panic!("expected execution to succeed, but it was halted: {reason}");
+            }
+        }
+    }
+
+    /// Asserts that the execution was halted with the `expected_reason`.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the actual execution outcome if it differs from the expected one.
+    #[cfg(any(test, feature = "test-support"))]
+    #[track_caller]
+    pub fn assert_halted(&self, expected_reason: &Halt) {
+        match &self.result {
+            ExecutionResult::Halt { reason } if reason == expected_reason => { /* OK */ }
+            ExecutionResult::Halt { reason } => {
+                panic!("expected execution to be halted with `{expected_reason}`, but it was halted with `{reason}`");
+            }
+            ExecutionResult::Revert { output } => {
+                panic!("expected execution to be halted with `{expected_reason}`, but it was reverted: {output}");
+            }
+            ExecutionResult::Success { .. } => {
+                panic!(
+                    "expected execution to be halted with `{expected_reason}`, but it succeeded"
+                );
+            }
+        }
+    }
+
+    /// Adds a dynamic factory dependency to this result. Intended to be used together with [`Self::mock()`] in tests.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hash` is not a valid bytecode hash for `bytecode`. For EVM bytecodes, `bytecode` must be padded
+    /// in the same way as during decommitment.
+    pub fn with_dynamic_factory_dep(mut self, hash: H256, bytecode: Vec<u8>) -> Self {
+        let parsed_hash = BytecodeHash::try_from(hash).expect("invalid bytecode hash");
+        let expected_hash = match parsed_hash.marker() {
+            BytecodeMarker::EraVm => BytecodeHash::for_bytecode(&bytecode),
+            BytecodeMarker::Evm => {
+                BytecodeHash::for_evm_bytecode(parsed_hash.len_in_bytes(), &bytecode)
+            }
+        };
+        assert_eq!(
+            expected_hash, parsed_hash,
+            "bytecode hash does not match the bytecode"
+        );
+        self.dynamic_factory_deps.insert(hash, bytecode);
+        self
+    }
+
+    /// Returns hashes of [dynamic factory deps](Self::dynamic_factory_deps) sorted in ascending order.
+    pub fn dynamic_factory_dep_hashes(&self) -> Vec<H256> {
+        let mut hashes: Vec<_> = self.dynamic_factory_deps.keys().copied().collect();
+        hashes.sort_unstable();
+        hashes
+    }
+
+    /// Returns byte sizes of [dynamic factory deps](Self::dynamic_factory_deps) keyed by the bytecode hash.
+    pub fn dynamic_factory_dep_sizes(&self) -> HashMap<H256, usize> {
+        self.dynamic_factory_deps
+            .iter()
+            .map(|(&hash, bytecode)| (hash, bytecode.len()))
+            .collect()
+    }
+
+    /// Compares all fields of this result with `other`, unlike the [`PartialEq`] implementation, which ignores
+    /// some statistics.
+    pub fn eq_strict(&self, other: &Self) -> bool {
+        self == other
+            && self.statistics.cycles_used == other.statistics.cycles_used
+            && self.statistics.circuit_statistic == other.statistics.circuit_statistic
+    }
+
+    /// Clones this result, leaving out storage logs. Storage logs can be large, so this is cheaper than cloning
+    /// the full result if only events and L2-to-L1 logs are needed.
+    pub fn clone_without_storage_logs(&self) -> Self {
+        Self {
+            result: self.result.clone(),
+            logs: VmExecutionLogs {
+                storage_logs: vec![],
+                events: self.logs.events.clone(),
+                user_l2_to_l1_logs: self.logs.user_l2_to_l1_logs.clone(),
+                system_l2_to_l1_logs: self.logs.system_l2_to_l1_logs.clone(),
+                total_log_queries_count: self.logs.total_log_queries_count,
+            },
+            statistics: self.statistics.clone(),
+            refunds: self.refunds.clone(),
+            dynamic_factory_deps: self.dynamic_factory_deps.clone(),
+        }
+    }
+
+    /// Returns logs visible to users, i.e. without events emitted by system contracts
+    /// (see [`VmEvent::is_from_system_contract()`]) and without system L2-to-L1 logs. Storage logs
+    /// and user L2-to-L1 logs are retained as is.
+    pub fn filter_user_logs(&self) -> VmExecutionLogs {
+        VmExecutionLogs {
+            storage_logs: self.logs.storage_logs.clone(),
+            events: self
+                .logs
+                .events
+                .iter()
+                .filter(|event| !event.is_from_system_contract())
+                .cloned()
+                .collect(),
+            user_l2_to_l1_logs: self.logs.user_l2_to_l1_logs.clone(),
+            system_l2_to_l1_logs: vec![],
+            total_log_queries_count: self.logs.total_log_queries_count,
+        }
+    }
+
+    /// Sets refunds for this result and deducts the [total refunded gas](Refunds::total()) from `statistics.gas_used`,
+    /// so that the statistics reflect gas used after the refund. This method should be called at most once, with the final refunds
+    /// for the transaction; otherwise, the refunded gas will be deducted several times.
+    pub fn apply_refunds(&mut self, refunds: Refunds) {
+        self.statistics.gas_used = self.statistics.gas_used.saturating_sub(refunds.total());
+        self.refunds = refunds;
+    }
+
+    /// Returns gas remaining from the provided `gas_limit` after execution. Returns 0 if more gas was used
+    /// than the limit.
+    pub fn gas_remaining(&self, gas_limit: u64) -> u64 {
+        gas_limit.saturating_sub(self.statistics.gas_used)
+    }
+
+    /// Returns the fraction of the provided `gas_limit` used during execution. Returns 0 if `gas_limit` is 0.
+    pub fn gas_used_fraction(&self, gas_limit: u64) -> f64 {
+        if gas_limit == 0 {
+            0.0
+        } else {
+            self.statistics.gas_used as f64 / gas_limit as f64
+        }
+    }
+
+    /// Returns `(contract_address, bytecode_hash)` pairs for all contracts deployed during execution,
+    /// in the order of deployment.
+    pub fn deployed_contracts(&self) -> Vec<(Address, H256)> {
+        self.logs
+            .events
+            .iter()
+            .filter_map(VmEvent::decode_deploy_event)
+            .map(|event| (event.contract_address, event.bytecode_hash))
+            .collect()
+    }
+
+    /// Checks whether any contract deployed during execution is an upgradeable proxy, based on its bytecode hash
+    /// from the `ContractDeployed` event. `known_proxies` maps bytecode hashes of known proxy implementations
+    /// to their kind. If several proxies were deployed, returns the kind of the first one.
+    ///
+    /// This is a heuristic: proxies with bytecodes not present in `known_proxies` are not detected.
+    pub fn is_upgradeable_deployment(
+        &self,
+        known_proxies: &HashMap<H256, ProxyKind>,
+    ) -> Option<ProxyKind> {
+        self.logs
+            .events
+            .iter()
+            .filter_map(VmEvent::decode_deploy_event)
+            .find_map(|event| known_proxies.get(&event.bytecode_hash).copied())
+    }
+
+    /// Returns the cost of pubdata published by the transaction for the given price per pubdata byte
+    /// (in wei). Saturates to `U256::MAX` on overflow.
+    pub fn total_pubdata_cost(&self, pubdata_price: U256) -> U256 {
+        pubdata_price.saturating_mul(self.statistics.pubdata_published.into())
+    }
+
     pub fn get_execution_metrics(&self) -> VmExecutionMetrics {
         // We published the data as ABI-encoded `bytes`, so the total length is:
         // - message length in bytes, rounded up to a multiple of 32
@@ -180,15 +1049,11 @@ impl VmExecutionResultAndLogs {
             .map(|event| (event.len() + 31) / 32 * 32 + 64)
             .sum();
 
-        let published_bytecode_bytes = VmEvent::extract_published_bytecodes(&self.logs.events)
-            .iter()
-            .map(|&bytecode_hash| {
-                let len_in_bytes = BytecodeHash::try_from(bytecode_hash)
-                    .expect("published unparseable bytecode hash")
-                    .len_in_bytes();
-                len_in_bytes + PUBLISH_BYTECODE_OVERHEAD as usize
-            })
-            .sum();
+        let published_bytecode_bytes =
+            VmEvent::extract_published_bytecodes_with_size(&self.logs.events)
+                .iter()
+                .map(|&(_, len_in_bytes)| len_in_bytes + PUBLISH_BYTECODE_OVERHEAD as usize)
+                .sum();
 
         VmExecutionMetrics {
             gas_used: self.statistics.gas_used as usize,
@@ -206,6 +1071,63 @@ impl VmExecutionResultAndLogs {
             circuit_statistic: self.statistics.circuit_statistic,
         }
     }
+
+    /// Estimates the number of bytes this execution adds to the L1 batch pubdata, i.e. the sum of sizes of L2-to-L1 logs,
+    /// long L2-to-L1 messages, published bytecodes (with overhead) and storage diffs. Storage writes are deduplicated
+    /// within this execution only, so the estimate may be larger than the actual contribution to a batch if other
+    /// transactions in the batch write to the same slots.
+    pub fn estimate_l1_batch_encoding_size(&self, protocol_version: ProtocolVersionId) -> usize {
+        self.get_execution_metrics().size()
+            + self.deduplicated_writes_metrics().size(protocol_version)
+    }
+
+    fn deduplicated_writes_metrics(&self) -> DeduplicatedWritesMetrics {
+        let writes = collapse_storage_writes(&self.logs.storage_logs);
+        let mut metrics = DeduplicatedWritesMetrics::default();
+        for write in writes.into_values() {
+            // Writes that restore the original value are not published.
+            if write.previous_value == write.value {
+                continue;
+            }
+            if write.kind == StorageLogKind::InitialWrite {
+                metrics.initial_storage_writes += 1;
+            } else {
+                metrics.repeated_storage_writes += 1;
+            }
+            metrics.total_updated_values_size += compress_with_best_strategy(
+                h256_to_u256(write.previous_value),
+                h256_to_u256(write.value),
+            )
+            .len();
+        }
+        metrics
+    }
+}
+
+/// Flattened summary of [`VmExecutionResultAndLogs`] consisting only of plain values, e.g. for reporting metrics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VmExecutionSummary {
+    pub gas_used: u64,
+    pub pubdata_published: u32,
+    pub storage_logs_count: usize,
+    pub events_count: usize,
+    pub l2_to_l1_logs_count: usize,
+    pub reverted: bool,
+    pub halted: bool,
+}
+
+impl From<&VmExecutionResultAndLogs> for VmExecutionSummary {
+    fn from(result: &VmExecutionResultAndLogs) -> Self {
+        Self {
+            gas_used: result.statistics.gas_used,
+            pubdata_published: result.statistics.pubdata_published,
+            storage_logs_count: result.logs.storage_logs.len(),
+            events_count: result.logs.events.len(),
+            l2_to_l1_logs_count: result.logs.total_l2_to_l1_logs_count(),
+            reverted: matches!(result.result, ExecutionResult::Revert { .. }),
+            halted: matches!(result.result, ExecutionResult::Halt { .. }),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -327,28 +1249,451 @@ impl Call {
             calls,
         }
     }
-}
 
-/// Mid-level transaction execution output returned by a [batch executor](crate::executor::BatchExecutor).
-#[derive(Debug)]
+    /// Iterates over this call and all its subcalls (recursively) in the pre-order DFS order, i.e.,
+    /// each call is yielded before its subcalls.
+    pub fn flatten(&self) -> impl Iterator<Item = &Self> + '_ {
+        // Use an explicit stack rather than recursion to not overflow the stack on pathologically deep call trees.
+        let mut stack = vec![self];
+        iter::from_fn(move || {
+            let call = stack.pop()?;
+            stack.extend(call.calls.iter().rev());
+            Some(call)
+        })
+    }
+
+    /// Iterates over the subtree rooted at this call (including the call itself) in the breadth-first (level) order:
+    /// all calls at a certain depth are visited before going deeper. Calls at the same depth are visited
+    /// in the execution order. See [`Self::flatten()`] for the depth-first order.
+    pub fn bfs(&self) -> CallIter<'_> {
+        CallIter {
+            queue: VecDeque::from([self]),
+        }
+    }
+
+    /// Mutable counterpart of [`Self::flatten()`]. Since a mutable reference to a call covers its subcalls,
+    /// calls are provided to the `visitor` closure one by one rather than via an iterator. The subcalls of a call
+    /// are visited after the visitor returns for it, so the visitor may modify subcalls as well.
+    pub fn flatten_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
+        let mut stack = vec![self];
+        while let Some(call) = stack.pop() {
+            visitor(call);
+            stack.extend(call.calls.iter_mut().rev());
+        }
+    }
+
+    /// Returns all calls in the subtree rooted at this call (including the call itself) made from the specified address.
+    /// Calls are returned in the same order as in [`Self::flatten()`].
+    pub fn from_address(&self, address: Address) -> Vec<&Self> {
+        self.flatten().filter(|call| call.from == address).collect()
+    }
+
+    /// Checks whether the subtree rooted at this call (including the call itself) contains a contract deployment.
+    pub fn contains_create(&self) -> bool {
+        self.flatten().any(|call| call.r#type == CallType::Create)
+    }
+
+    /// Returns all contract deployments in the subtree rooted at this call (including the call itself).
+    /// Calls are returned in the same order as in [`Self::flatten()`].
+    pub fn all_creates(&self) -> Vec<&Self> {
+        self.flatten()
+            .filter(|call| call.r#type == CallType::Create)
+            .collect()
+    }
+
+    /// Returns the 0-based index (in the [`Self::flatten()`] order) of the first call in this subtree that has
+    /// a revert reason or an error, or `None` if all calls have succeeded.
+    pub fn revert_depth(&self) -> Option<usize> {
+        self.flatten()
+            .position(|call| call.revert_reason.is_some() || call.error.is_some())
+    }
+
+    /// Returns the number of direct subcalls of this call.
+    pub fn subcall_count(&self) -> usize {
+        self.calls.len()
+    }
+
+    /// Returns the number of all subcalls of this call (recursively), excluding the call itself.
+    pub fn total_call_count(&self) -> usize {
+        self.flatten().count() - 1
+    }
+
+    /// Returns gas used by this call itself, i.e. excluding gas used by its direct subcalls (which is included
+    /// into `gas_used` of the call). Saturates to zero if subcalls report more gas than the call.
+    pub fn self_gas_used(&self) -> u64 {
+        let subcalls_gas_used = self
+            .calls
+            .iter()
+            .fold(0_u64, |total, call| total.saturating_add(call.gas_used));
+        self.gas_used.saturating_sub(subcalls_gas_used)
+    }
+
+    /// Returns the total gas used in the subtree rooted at this call, computed as the sum of [`Self::self_gas_used()`]
+    /// across all calls in the subtree, so that gas used by subcalls isn't counted multiple times. For well-formed traces,
+    /// this is equal to `gas_used` of the call.
+    pub fn total_gas_used(&self) -> u64 {
+        self.flatten().fold(0_u64, |total, call| {
+            total.saturating_add(call.self_gas_used())
+        })
+    }
+
+    /// Returns the sum of values transferred in the subtree rooted at this call (including the call itself),
+    /// saturating to `U256::MAX` on overflow. See [`Self::value_transfers()`] for which calls are considered transfers.
+    pub fn total_value_transferred(&self) -> U256 {
+        self.value_transfers()
+            .fold(U256::zero(), |total, value| total.saturating_add(value))
+    }
+
+    /// Returns the largest value transferred by a single call in the subtree rooted at this call
+    /// (including the call itself). See [`Self::value_transfers()`] for which calls are considered transfers.
+    pub fn max_single_transfer(&self) -> U256 {
+        self.value_transfers().max().unwrap_or_default()
+    }
+
+    /// Iterates over values transferred in the subtree rooted at this call, counting each transfer once.
+    ///
+    /// Call tracers record the `value` of the execution context, so it is repeated in places where no value is transferred:
+    ///
+    /// - Delegate calls and near calls inherit the value of the calling context.
+    /// - A call to `MsgValueSimulator` is followed by the call forwarded by the simulator to the recipient
+    ///   (possibly via near calls inside the simulator), which carries the same value.
+    fn value_transfers(&self) -> impl Iterator<Item = U256> + '_ {
+        // For each call, we track the value of the enclosing `MsgValueSimulator` call (if any) to skip the forwarded call.
+        let mut stack = vec![(self, None::<U256>)];
+        iter::from_fn(move || loop {
+            let (call, simulator_value) = stack.pop()?;
+            let inherits_value = matches!(
+                call.r#type,
+                CallType::Call(FarCallOpcode::Delegate) | CallType::NearCall
+            );
+            let subcall_simulator_value = if call.r#type == CallType::NearCall {
+                simulator_value
+            } else if !inherits_value && call.to == MSG_VALUE_SIMULATOR_ADDRESS {
+                Some(call.value)
+            } else {
+                None
+            };
+            stack.extend(
+                call.calls
+                    .iter()
+                    .rev()
+                    .map(|subcall| (subcall, subcall_simulator_value)),
+            );
+
+            let is_forwarded = simulator_value == Some(call.value);
+            if !inherits_value && !is_forwarded && !call.value.is_zero() {
+                break Some(call.value);
+            }
+        })
+    }
+
+    /// Returns the share of the provided gas used by this call, as a percentage. Returns 0 if no gas was provided.
+    pub fn gas_efficiency(&self) -> f64 {
+        Self::efficiency(self.gas_used, self.gas)
+    }
+
+    /// Returns the [gas efficiency](Self::gas_efficiency()) of this call and all its subcalls (recursively),
+    /// with each call weighted by the gas provided to it. Returns 0 if no gas was provided to any call.
+    pub fn subtree_gas_efficiency(&self) -> f64 {
+        let (gas_used, gas) = self
+            .flatten()
+            .fold((0_u128, 0_u128), |(gas_used, gas), call| {
+                (
+                    gas_used + u128::from(call.gas_used),
+                    gas + u128::from(call.gas),
+                )
+            });
+        Self::efficiency(gas_used, gas)
+    }
+
+    fn efficiency(gas_used: impl Into<u128>, gas: impl Into<u128>) -> f64 {
+        let gas = gas.into();
+        if gas == 0 {
+            0.0
+        } else {
+            gas_used.into() as f64 / gas as f64 * 100.0
+        }
+    }
+
+    /// Returns the maximum depth of the call tree rooted at this call. A call without subcalls has depth 1.
+    pub fn max_call_depth(&self) -> usize {
+        let mut max_depth = 0;
+        let mut stack = vec![(self, 1)];
+        while let Some((call, depth)) = stack.pop() {
+            max_depth = max_depth.max(depth);
+            stack.extend(call.calls.iter().map(|subcall| (subcall, depth + 1)));
+        }
+        max_depth
+    }
+
+    /// Classifies the error of this call. If the call has no error but has a revert reason,
+    /// returns [`CallErrorKind::Reverted`]. Returns `None` if the call has succeeded.
+    pub fn error_kind(&self) -> Option<CallErrorKind> {
+        match (&self.error, &self.revert_reason) {
+            (Some(error), _) => Some(CallErrorKind::classify(error)),
+            (None, Some(_)) => Some(CallErrorKind::Reverted),
+            (None, None) => None,
+        }
+    }
+
+    /// Flattens the call tree rooted at this call into records, e.g. for bulk insertion into a database.
+    /// Records are returned in the [`Self::flatten()`] order; their IDs are sequential starting from 0,
+    /// so the root call has ID 0 and no parent.
+    pub fn to_records(&self, tx_hash: H256) -> Vec<CallRecord> {
+        let mut records = vec![];
+        let mut stack = vec![(self, None, 0)];
+        while let Some((call, parent_id, depth)) = stack.pop() {
+            let id = records.len() as u64;
+            records.push(CallRecord {
+                id,
+                parent_id,
+                tx_hash,
+                depth,
+                r#type: call.r#type,
+                from: call.from,
+                to: call.to,
+                parent_gas: call.parent_gas,
+                gas: call.gas,
+                gas_used: call.gas_used,
+                value: call.value,
+                input: call.input.clone(),
+                output: call.output.clone(),
+                error: call.error.clone(),
+                revert_reason: call.revert_reason.clone(),
+            });
+            stack.extend(
+                call.calls
+                    .iter()
+                    .rev()
+                    .map(|subcall| (subcall, Some(id), depth + 1)),
+            );
+        }
+        records
+    }
+}
+
+/// Kind of a [`Call`] error returned by [`Call::error_kind()`].
+///
+/// Call tracers don't record the cause of a panic: running out of gas, an invalid opcode etc. are all recorded
+/// as the same `"Panic"` error (and a panicking call always burns all gas provided to it, so the cause cannot be
+/// inferred from gas usage either). Thus, only reverts and panics can be distinguished.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallErrorKind {
+    /// Call has reverted, i.e., it has a revert reason and no error.
+    Reverted,
+    /// Call has panicked.
+    Panicked,
+    /// Error not produced by call tracers (e.g., in traces from an external source), with the original error message.
+    Other(String),
+}
+
+impl CallErrorKind {
+    /// Error recorded by call tracers for panicking calls.
+    const PANIC_ERROR: &'static str = "Panic";
+
+    fn classify(error: &str) -> Self {
+        if error == Self::PANIC_ERROR {
+            Self::Panicked
+        } else {
+            Self::Other(error.to_owned())
+        }
+    }
+}
+
+/// Breadth-first iterator over a call tree returned by [`Call::bfs()`].
+#[derive(Debug, Clone)]
+pub struct CallIter<'a> {
+    queue: VecDeque<&'a Call>,
+}
+
+impl<'a> Iterator for CallIter<'a> {
+    type Item = &'a Call;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let call = self.queue.pop_front()?;
+        self.queue.extend(&call.calls);
+        Some(call)
+    }
+}
+
+/// Flat (i.e., non-recursive) representation of a single [`Call`] produced by [`Call::to_records()`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallRecord {
+    /// ID of the call, unique within the call tree of a transaction.
+    pub id: u64,
+    /// ID of the parent call, or `None` for the root call.
+    pub parent_id: Option<u64>,
+    /// Hash of the transaction that produced the call.
+    pub tx_hash: H256,
+    /// Depth of the call in the call tree; the root call has depth 0.
+    pub depth: u32,
+    /// Type of the call.
+    pub r#type: CallType,
+    /// Address of the caller.
+    pub from: Address,
+    /// Address of the callee.
+    pub to: Address,
+    /// Gas from the parent call.
+    pub parent_gas: u64,
+    /// Gas provided for the call.
+    pub gas: u64,
+    /// Gas used by the call.
+    pub gas_used: u64,
+    /// Value transferred.
+    pub value: U256,
+    /// Input data.
+    pub input: Vec<u8>,
+    /// Output data.
+    pub output: Vec<u8>,
+    /// Error message provided by vm or some unexpected errors.
+    pub error: Option<String>,
+    /// Revert reason.
+    pub revert_reason: Option<String>,
+}
+
+impl fmt::Display for CallType {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(match self {
+            Self::Call(FarCallOpcode::Normal) => "call",
+            Self::Call(FarCallOpcode::Delegate) => "delegatecall",
+            Self::Call(FarCallOpcode::Mimic) => "mimiccall",
+            Self::Create => "create",
+            Self::NearCall => "nearcall",
+        })
+    }
+}
+
+/// Outputs the call tree rooted at this call, one call per line, with subcalls indented by 2 spaces
+/// relative to their parent.
+impl fmt::Display for Call {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Traverses calls in the same way as `Call::flatten()`, additionally tracking call depth.
+        let mut stack = vec![(self, 0)];
+        let mut is_first = true;
+        while let Some((call, depth)) = stack.pop() {
+            if !is_first {
+                writeln!(formatter)?;
+            }
+            is_first = false;
+
+            write!(
+                formatter,
+                "{:indent$}{} {:?} -> {:?}, gas: {}/{}, value: {}",
+                "",
+                call.r#type,
+                call.from,
+                call.to,
+                call.gas_used,
+                call.gas,
+                call.value,
+                indent = depth * 2
+            )?;
+            if let Some(reason) = &call.revert_reason {
+                write!(formatter, ", reverted: {reason}")?;
+            }
+            if let Some(error) = &call.error {
+                write!(formatter, ", error: {error}")?;
+            }
+            stack.extend(call.calls.iter().rev().map(|subcall| (subcall, depth + 1)));
+        }
+        Ok(())
+    }
+}
+
+/// Mid-level transaction execution output returned by a [batch executor](crate::executor::BatchExecutor).
+#[derive(Debug)]
 pub struct BatchTransactionExecutionResult {
     /// VM result.
     pub tx_result: Box<VmExecutionResultAndLogs>,
-    /// Compressed bytecodes used by the transaction.
-    pub compression_result: Result<(), BytecodeCompressionError>,
+    /// Result of compressing bytecodes used by the transaction.
+    pub compression_result: Result<BytecodeCompressionMetrics, BytecodeCompressionError>,
     /// Call traces (if requested; otherwise, empty).
     pub call_traces: Vec<Call>,
+    /// Wall-clock time spent executing the transaction in the VM, as measured by the executor.
+    /// If the transaction was re-executed (e.g., without bytecode compression), includes all executions.
+    pub execution_time: Duration,
 }
 
 impl BatchTransactionExecutionResult {
+    /// Creates a mock result based on the provided VM result, with successful bytecode compression and no call traces.
+    pub fn mock(tx_result: VmExecutionResultAndLogs) -> Self {
+        Self {
+            tx_result: Box::new(tx_result),
+            compression_result: Ok(BytecodeCompressionMetrics::default()),
+            call_traces: vec![],
+            execution_time: Duration::ZERO,
+        }
+    }
+
+    /// Replaces call traces in this result. Intended to be used together with [`Self::mock()`] in tests.
+    pub fn with_call_traces(mut self, call_traces: Vec<Call>) -> Self {
+        self.call_traces = call_traces;
+        self
+    }
+
+    /// Replaces the bytecode compression result. Intended to be used together with [`Self::mock()`] in tests.
+    pub fn with_compression_result(
+        mut self,
+        compression_result: Result<BytecodeCompressionMetrics, BytecodeCompressionError>,
+    ) -> Self {
+        self.compression_result = compression_result;
+        self
+    }
+
+    /// Returns the execution time in whole milliseconds (saturating on overflow).
+    pub fn execution_time_ms(&self) -> u64 {
+        u64::try_from(self.execution_time.as_millis()).unwrap_or(u64::MAX)
+    }
+
     pub fn was_halted(&self) -> bool {
         matches!(self.tx_result.result, ExecutionResult::Halt { .. })
     }
+
+    /// Checks whether compressing bytecodes used by the transaction has failed.
+    pub fn is_compression_failed(&self) -> bool {
+        self.compression_result.is_err()
+    }
+
+    /// Returns the bytecode compression error, or `None` if compression has succeeded.
+    pub fn compression_error(&self) -> Option<&BytecodeCompressionError> {
+        self.compression_result.as_ref().err()
+    }
+
+    /// Returns the execution status of the transaction. Both reverted and halted transactions are considered failed.
+    pub fn execution_status(&self) -> TxExecutionStatus {
+        self.tx_result.execution_status()
+    }
+
+    /// Destructures this result into the VM result, bytecode compression result and call traces.
+    pub fn into_parts(
+        self,
+    ) -> (
+        VmExecutionResultAndLogs,
+        Result<BytecodeCompressionMetrics, BytecodeCompressionError>,
+        Vec<Call>,
+    ) {
+        (*self.tx_result, self.compression_result, self.call_traces)
+    }
 }
 
 /// Mid-level transaction execution output returned by a [oneshot executor](crate::executor::OneshotExecutor).
 pub type OneshotTransactionExecutionResult = BatchTransactionExecutionResult;
 
+/// Creates a result with successful bytecode compression (with zero-sized metrics) and zero execution time
+/// from a VM result and call traces.
+/// Since [`OneshotTransactionExecutionResult`] is an alias, this conversion applies to
+/// [`BatchTransactionExecutionResult`] as well.
+impl From<(VmExecutionResultAndLogs, Vec<Call>)> for OneshotTransactionExecutionResult {
+    fn from((tx_result, call_traces): (VmExecutionResultAndLogs, Vec<Call>)) -> Self {
+        Self {
+            tx_result: Box::new(tx_result),
+            compression_result: Ok(BytecodeCompressionMetrics::default()),
+            call_traces,
+            execution_time: Duration::ZERO,
+        }
+    }
+}
+
 /// High-level transaction execution result used by the state keeper etc.
 #[derive(Debug, Clone, PartialEq)]
 pub struct TransactionExecutionResult {
@@ -362,13 +1707,22 @@ pub struct TransactionExecutionResult {
 }
 
 impl TransactionExecutionResult {
+    /// Returns gas actually paid for by the transaction, i.e. its gas limit minus the refunded gas.
+    /// Clamped to zero if the refund exceeds the gas limit.
+    pub fn net_gas_cost(&self) -> u64 {
+        self.transaction
+            .gas_limit()
+            .as_u64()
+            .saturating_sub(self.refunded_gas)
+    }
+
     pub fn call_trace(&self) -> Option<Call> {
         if self.call_traces.is_empty() {
             None
         } else {
             Some(Call::new_high_level(
                 self.transaction.gas_limit().as_u64(),
-                self.transaction.gas_limit().as_u64() - self.refunded_gas,
+                self.net_gas_cost(),
                 self.transaction.execute.value,
                 self.transaction.execute.calldata.clone(),
                 vec![],
@@ -379,11 +1733,51 @@ impl TransactionExecutionResult {
     }
 }
 
+impl VmExecutionMetrics {
+    /// Aggregates execution metrics of the provided transactions (e.g., all transactions in an L1 batch).
+    /// Returns zero metrics if `results` is empty.
+    pub fn aggregate(results: &[TransactionExecutionResult]) -> Self {
+        results
+            .iter()
+            .fold(Self::default(), |acc, result| acc + result.execution_info)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use zksync_types::ethabi;
+    use assert_matches::assert_matches;
+    use zksync_types::{
+        bytecode::pad_evm_bytecode, ethabi, fee::Fee, AccountTreeId, Execute,
+        ExecuteTransactionCommon, L2TxCommonData, StorageLog,
+    };
 
     use super::*;
+    use crate::CircuitStatistic;
+
+    #[test]
+    fn creating_synthetic_event() {
+        let address = Address::repeat_byte(1);
+        let event = VmEvent::synthetic(address, vec![H256::repeat_byte(2)], vec![3]);
+        assert_eq!(
+            event,
+            VmEvent {
+                location: (L1BatchNumber(0), 0),
+                address,
+                indexed_topics: vec![H256::repeat_byte(2)],
+                value: vec![3],
+            }
+        );
+    }
+
+    #[test]
+    fn accessing_event_location() {
+        let event = VmEvent {
+            location: (L1BatchNumber(3), 5),
+            ..mock_event(1)
+        };
+        assert_eq!(event.location_batch(), L1BatchNumber(3));
+        assert_eq!(event.location_tx_index(), 5);
+    }
 
     #[test]
     fn deploy_event_signature_matches() {
@@ -423,6 +1817,19 @@ mod tests {
         assert_eq!(VmEvent::L1_MESSAGE_EVENT_SIGNATURE, expected_signature);
     }
 
+    #[test]
+    fn erc20_transfer_event_signature_matches() {
+        let expected_signature = ethabi::long_signature(
+            "Transfer",
+            &[
+                ethabi::ParamType::Address,
+                ethabi::ParamType::Address,
+                ethabi::ParamType::Uint(256),
+            ],
+        );
+        assert_eq!(VmEvent::ERC20_TRANSFER_EVENT_SIGNATURE, expected_signature);
+    }
+
     #[test]
     fn published_bytecode_event_signature_matches() {
         let expected_signature = ethabi::long_signature(
@@ -431,4 +1838,1945 @@ mod tests {
         );
         assert_eq!(VmEvent::PUBLISHED_BYTECODE_SIGNATURE, expected_signature);
     }
+
+    #[test]
+    fn execution_result_accessors() {
+        let success = ExecutionResult::Success {
+            output: vec![1, 2, 3],
+        };
+        assert!(success.is_success());
+        assert!(!success.is_failed());
+        assert_eq!(success.output(), Some([1, 2, 3].as_slice()));
+        assert_eq!(success.revert_reason(), None);
+
+        let revert_reason = VmRevertReason::General {
+            msg: "oops".to_owned(),
+            data: vec![],
+        };
+        let revert = ExecutionResult::Revert {
+            output: revert_reason.clone(),
+        };
+        assert!(!revert.is_success());
+        assert!(revert.is_failed());
+        assert_eq!(revert.output(), None);
+        assert_eq!(revert.revert_reason(), Some(&revert_reason));
+
+        let halt = ExecutionResult::Halt {
+            reason: Halt::BootloaderOutOfGas,
+        };
+        assert!(!halt.is_success());
+        assert!(halt.is_failed());
+        assert_eq!(halt.output(), None);
+        assert_eq!(halt.revert_reason(), None);
+    }
+
+    /// Creates a chain of nested calls, each of which uses `self_gas_used` gas itself (i.e., excluding subcalls).
+    fn nested_call(depth: usize, self_gas_used: u64) -> Call {
+        let mut call = Call {
+            gas_used: self_gas_used,
+            ..Call::default()
+        };
+        for _ in 1..depth {
+            call = Call {
+                gas_used: call.gas_used + self_gas_used,
+                calls: vec![call],
+                ..Call::default()
+            };
+        }
+        call
+    }
+
+    #[test]
+    fn call_tree_gas_and_depth() {
+        let leaf = Call {
+            gas_used: 7,
+            ..Call::default()
+        };
+        let mut root = nested_call(6, 10);
+        root.gas_used += leaf.gas_used;
+        root.calls.push(leaf.clone());
+        assert_eq!(root.gas_used, 6 * 10 + 7);
+        assert_eq!(root.self_gas_used(), 10);
+        assert_eq!(root.total_gas_used(), 6 * 10 + 7);
+        assert_eq!(root.max_call_depth(), 6);
+
+        assert_eq!(leaf.self_gas_used(), 7);
+        assert_eq!(leaf.total_gas_used(), 7);
+        assert_eq!(leaf.max_call_depth(), 1);
+    }
+
+    #[test]
+    fn call_tree_gas_with_inconsistent_subcalls() {
+        // Subcalls report more gas than the parent call; this must not underflow.
+        let root = Call {
+            gas_used: 5,
+            calls: vec![
+                Call {
+                    gas_used: u64::MAX,
+                    ..Call::default()
+                },
+                Call {
+                    gas_used: 3,
+                    ..Call::default()
+                },
+            ],
+            ..Call::default()
+        };
+        assert_eq!(root.self_gas_used(), 0);
+        assert_eq!(root.total_gas_used(), u64::MAX);
+    }
+
+    #[test]
+    fn computing_transferred_value() {
+        let with_value = |value: u64, calls| Call {
+            value: U256::from(value),
+            calls,
+            ..Call::default()
+        };
+        let root = with_value(
+            10,
+            vec![
+                with_value(0, vec![with_value(25, vec![])]),
+                with_value(5, vec![]),
+            ],
+        );
+        assert_eq!(root.total_value_transferred(), U256::from(40));
+        assert_eq!(root.max_single_transfer(), U256::from(25));
+
+        let leaf = with_value(7, vec![]);
+        assert_eq!(leaf.total_value_transferred(), U256::from(7));
+        assert_eq!(leaf.max_single_transfer(), U256::from(7));
+        assert_eq!(Call::default().total_value_transferred(), U256::zero());
+        assert_eq!(Call::default().max_single_transfer(), U256::zero());
+
+        let huge_call = Call {
+            value: U256::MAX,
+            calls: vec![with_value(1, vec![])],
+            ..Call::default()
+        };
+        assert_eq!(huge_call.total_value_transferred(), U256::MAX);
+        assert_eq!(huge_call.max_single_transfer(), U256::MAX);
+    }
+
+    #[test]
+    fn value_inherited_by_calls_is_not_transferred() {
+        let call = |r#type, to, value: u64, calls| Call {
+            r#type,
+            to,
+            value: U256::from(value),
+            calls,
+            ..Call::default()
+        };
+        let recipient = Address::repeat_byte(1);
+        let library = Address::repeat_byte(2);
+        let normal_call = CallType::Call(FarCallOpcode::Normal);
+        let mimic_call = CallType::Call(FarCallOpcode::Mimic);
+        let delegate_call = CallType::Call(FarCallOpcode::Delegate);
+
+        // A value-bearing call routed via `MsgValueSimulator`, with the recipient delegate-calling a library
+        // and the library making a near call.
+        let root = call(
+            normal_call,
+            MSG_VALUE_SIMULATOR_ADDRESS,
+            100,
+            vec![call(
+                CallType::NearCall,
+                MSG_VALUE_SIMULATOR_ADDRESS,
+                100,
+                vec![call(
+                    mimic_call,
+                    recipient,
+                    100,
+                    vec![call(
+                        delegate_call,
+                        library,
+                        100,
+                        vec![call(CallType::NearCall, library, 100, vec![])],
+                    )],
+                )],
+            )],
+        );
+        assert_eq!(root.total_value_transferred(), U256::from(100));
+        assert_eq!(root.max_single_transfer(), U256::from(100));
+
+        // A delegate call under a value-bearing call.
+        let root = call(
+            normal_call,
+            recipient,
+            50,
+            vec![call(delegate_call, library, 50, vec![])],
+        );
+        assert_eq!(root.total_value_transferred(), U256::from(50));
+
+        // A call with a different value made by the recipient of a simulated transfer is counted.
+        let root = call(
+            normal_call,
+            MSG_VALUE_SIMULATOR_ADDRESS,
+            100,
+            vec![call(
+                mimic_call,
+                recipient,
+                100,
+                vec![call(normal_call, library, 30, vec![])],
+            )],
+        );
+        assert_eq!(root.total_value_transferred(), U256::from(130));
+        assert_eq!(root.max_single_transfer(), U256::from(100));
+    }
+
+    #[test]
+    fn call_tree_traversal_does_not_overflow_stack() {
+        let call = nested_call(100_000, 1);
+        assert_eq!(call.total_gas_used(), 100_000);
+        assert_eq!(call.max_call_depth(), 100_000);
+        // Dropping a deeply nested tree is recursive, so we leak it instead.
+        std::mem::forget(call);
+    }
+
+    fn mock_event(address: u64) -> VmEvent {
+        VmEvent::synthetic(Address::from_low_u64_be(address), vec![], vec![])
+    }
+
+    #[test]
+    fn merging_logs() {
+        let first = VmExecutionLogs {
+            events: vec![mock_event(1), mock_event(2)],
+            user_l2_to_l1_logs: vec![UserL2ToL1Log::default()],
+            total_log_queries_count: 3,
+            ..VmExecutionLogs::default()
+        };
+        let second = VmExecutionLogs {
+            events: vec![mock_event(3)],
+            system_l2_to_l1_logs: vec![SystemL2ToL1Log::default()],
+            total_log_queries_count: 2,
+            ..VmExecutionLogs::default()
+        };
+
+        let merged = first.clone().merge(second.clone());
+        assert_eq!(merged.events, [mock_event(1), mock_event(2), mock_event(3)]);
+        assert_eq!(merged.user_l2_to_l1_logs.len(), 1);
+        assert_eq!(merged.system_l2_to_l1_logs.len(), 1);
+        assert_eq!(merged.total_l2_to_l1_logs_count(), 2);
+        assert_eq!(merged.total_log_queries_count, 5);
+
+        let mut merged_in_place = first;
+        merged_in_place.merge_in_place(second);
+        assert_eq!(merged_in_place, merged);
+    }
+
+    #[test]
+    fn computing_logs_commitment() {
+        let logs = VmExecutionLogs {
+            storage_logs: vec![StorageLogWithPreviousValue {
+                log: StorageLog::new_write_log(
+                    StorageKey::new(AccountTreeId::new(Address::repeat_byte(1)), H256::zero()),
+                    H256::repeat_byte(2),
+                ),
+                previous_value: H256::zero(),
+            }],
+            events: vec![
+                VmEvent::synthetic(Address::repeat_byte(1), vec![H256::repeat_byte(3)], vec![4]),
+                mock_event(2),
+            ],
+            user_l2_to_l1_logs: vec![UserL2ToL1Log::default()],
+            system_l2_to_l1_logs: vec![],
+            total_log_queries_count: 5,
+        };
+        let commitment = logs.commitment();
+        assert_ne!(commitment, H256::zero());
+        assert_eq!(logs.clone().commitment(), commitment);
+        assert_ne!(VmExecutionLogs::default().commitment(), commitment);
+
+        let mut other_logs = logs.clone();
+        other_logs.total_log_queries_count = 0;
+        assert_eq!(other_logs.commitment(), commitment);
+
+        other_logs.events.swap(0, 1);
+        assert_ne!(other_logs.commitment(), commitment);
+
+        let mut other_logs = logs.clone();
+        other_logs.storage_logs[0].previous_value = H256::repeat_byte(1);
+        assert_ne!(other_logs.commitment(), commitment);
+
+        let mut other_logs = logs.clone();
+        other_logs.events[0].value.clear();
+        assert_ne!(other_logs.commitment(), commitment);
+
+        // The same log must be committed to differently depending on whether it's a user or a system one.
+        let mut other_logs = logs.clone();
+        let user_log = other_logs.user_l2_to_l1_logs.pop().unwrap();
+        other_logs
+            .system_l2_to_l1_logs
+            .push(SystemL2ToL1Log(user_log.0));
+        assert_ne!(other_logs.commitment(), commitment);
+    }
+
+    #[test]
+    fn splitting_logs_at_tx() {
+        let event = |tx_index| VmEvent {
+            location: (L1BatchNumber(1), tx_index),
+            ..mock_event(u64::from(tx_index))
+        };
+        let l2_to_l1_log = |tx_number_in_block| L2ToL1Log {
+            tx_number_in_block,
+            ..L2ToL1Log::default()
+        };
+        let logs = VmExecutionLogs {
+            storage_logs: vec![StorageLogWithPreviousValue {
+                log: StorageLog::new_read_log(
+                    StorageKey::new(AccountTreeId::new(Address::repeat_byte(1)), H256::zero()),
+                    H256::zero(),
+                ),
+                previous_value: H256::zero(),
+            }],
+            events: vec![event(0), event(1), event(1), event(3)],
+            user_l2_to_l1_logs: vec![UserL2ToL1Log(l2_to_l1_log(1))],
+            system_l2_to_l1_logs: vec![
+                SystemL2ToL1Log(l2_to_l1_log(0)),
+                SystemL2ToL1Log(l2_to_l1_log(2)),
+            ],
+            total_log_queries_count: 5,
+        };
+
+        let (head, tail) = logs.clone().split_at_tx(1);
+        assert_eq!(head.events, [event(0)]);
+        assert!(head.user_l2_to_l1_logs.is_empty());
+        assert_eq!(
+            head.system_l2_to_l1_logs,
+            [SystemL2ToL1Log(l2_to_l1_log(0))]
+        );
+        assert_eq!(head.storage_logs, logs.storage_logs);
+        assert_eq!(head.total_log_queries_count, 5);
+        assert_eq!(tail.events, [event(1), event(1), event(3)]);
+        assert_eq!(tail.user_l2_to_l1_logs, logs.user_l2_to_l1_logs);
+        assert_eq!(
+            tail.system_l2_to_l1_logs,
+            [SystemL2ToL1Log(l2_to_l1_log(2))]
+        );
+        assert!(tail.storage_logs.is_empty());
+        assert_eq!(tail.total_log_queries_count, 0);
+
+        let (head, tail) = logs.clone().split_at_tx(2);
+        assert_eq!(head.events, [event(0), event(1), event(1)]);
+        assert_eq!(head.user_l2_to_l1_logs, logs.user_l2_to_l1_logs);
+        assert_eq!(tail.events, [event(3)]);
+        assert!(tail.user_l2_to_l1_logs.is_empty());
+
+        let (head, tail) = logs.clone().split_at_tx(0);
+        assert_eq!(
+            head,
+            VmExecutionLogs {
+                storage_logs: logs.storage_logs.clone(),
+                total_log_queries_count: 5,
+                ..VmExecutionLogs::default()
+            }
+        );
+        assert_eq!(tail.events, logs.events);
+        let (head, tail) = logs.clone().split_at_tx(u32::MAX);
+        assert_eq!(head, logs);
+        assert_eq!(tail, VmExecutionLogs::default());
+
+        // No logs should be lost regardless of the split point.
+        for tx_index in 0..=4 {
+            let (mut head, tail) = logs.clone().split_at_tx(tx_index);
+            head.storage_logs.extend(tail.storage_logs);
+            head.events.extend(tail.events);
+            head.user_l2_to_l1_logs.extend(tail.user_l2_to_l1_logs);
+            head.system_l2_to_l1_logs.extend(tail.system_l2_to_l1_logs);
+            head.total_log_queries_count += tail.total_log_queries_count;
+            assert_eq!(head, logs, "tx_index={tx_index}");
+        }
+    }
+
+    #[test]
+    fn grouping_events_by_address() {
+        let logs = VmExecutionLogs {
+            events: vec![
+                VmEvent {
+                    value: vec![1],
+                    ..mock_event(1)
+                },
+                mock_event(2),
+                VmEvent {
+                    value: vec![2],
+                    ..mock_event(1)
+                },
+            ],
+            ..VmExecutionLogs::default()
+        };
+
+        let grouped = logs.events_by_address();
+        assert_eq!(grouped.len(), 2);
+        let values: Vec<_> = grouped[&Address::from_low_u64_be(1)]
+            .iter()
+            .map(|event| event.value.as_slice())
+            .collect();
+        assert_eq!(values, [[1], [2]]);
+        assert_eq!(grouped[&Address::from_low_u64_be(2)], [&mock_event(2)]);
+        assert!(!grouped.contains_key(&Address::from_low_u64_be(3)));
+        assert!(VmExecutionLogs::default().events_by_address().is_empty());
+    }
+
+    #[test]
+    fn separating_user_and_system_events() {
+        let system_event = VmEvent::synthetic(L1_MESSENGER_ADDRESS, vec![], vec![1]);
+        let logs = VmExecutionLogs {
+            events: vec![
+                mock_event(1),
+                system_event.clone(),
+                mock_event(2),
+                VmEvent::synthetic(CONTRACT_DEPLOYER_ADDRESS, vec![], vec![]),
+                VmEvent::synthetic(L2_NATIVE_TOKEN_VAULT_ADDRESS, vec![], vec![]),
+            ],
+            ..VmExecutionLogs::default()
+        };
+
+        assert_eq!(logs.user_event_count(), 2);
+        assert_eq!(logs.system_event_count(), 3);
+        let user_events: Vec<_> = logs.user_events().collect();
+        assert_eq!(user_events, [&mock_event(1), &mock_event(2)]);
+        assert_eq!(logs.system_events().next(), Some(&system_event));
+
+        let empty_logs = VmExecutionLogs::default();
+        assert_eq!(empty_logs.user_event_count(), 0);
+        assert_eq!(empty_logs.system_event_count(), 0);
+    }
+
+    #[test]
+    fn counting_events_by_signature() {
+        let logs = VmExecutionLogs {
+            events: vec![
+                VmEvent {
+                    indexed_topics: vec![VmEvent::DEPLOY_EVENT_SIGNATURE, H256::repeat_byte(1)],
+                    ..mock_event(1)
+                },
+                mock_event(2),
+                VmEvent {
+                    indexed_topics: vec![VmEvent::DEPLOY_EVENT_SIGNATURE],
+                    ..mock_event(3)
+                },
+                VmEvent {
+                    indexed_topics: vec![VmEvent::L1_MESSAGE_EVENT_SIGNATURE],
+                    ..mock_event(1)
+                },
+            ],
+            ..VmExecutionLogs::default()
+        };
+
+        let counts = logs.count_events_by_signature();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&VmEvent::DEPLOY_EVENT_SIGNATURE], 2);
+        assert_eq!(counts[&VmEvent::L1_MESSAGE_EVENT_SIGNATURE], 1);
+        assert_eq!(counts[&H256::zero()], 1);
+        assert!(VmExecutionLogs::default()
+            .count_events_by_signature()
+            .is_empty());
+    }
+
+    #[test]
+    fn deduplicating_storage_logs() {
+        let key = |byte| {
+            StorageKey::new(
+                AccountTreeId::new(Address::repeat_byte(1)),
+                H256::repeat_byte(byte),
+            )
+        };
+        let write = |key, kind, previous_value: u64, value: u64| StorageLogWithPreviousValue {
+            log: StorageLog {
+                kind,
+                key,
+                value: H256::from_low_u64_be(value),
+            },
+            previous_value: H256::from_low_u64_be(previous_value),
+        };
+        let read = |key, value: u64| StorageLogWithPreviousValue {
+            log: StorageLog::new_read_log(key, H256::from_low_u64_be(value)),
+            previous_value: H256::from_low_u64_be(value),
+        };
+
+        let mut logs = VmExecutionLogs {
+            storage_logs: vec![
+                write(key(1), StorageLogKind::InitialWrite, 0, 1),
+                read(key(1), 1),
+                write(key(2), StorageLogKind::RepeatedWrite, 5, 6),
+                write(key(1), StorageLogKind::RepeatedWrite, 1, 2),
+                read(key(3), 7),
+                write(key(1), StorageLogKind::RepeatedWrite, 2, 3),
+            ],
+            ..VmExecutionLogs::default()
+        };
+        logs.deduplicate_storage_logs();
+        assert_eq!(
+            logs.storage_logs,
+            [
+                read(key(1), 1),
+                write(key(2), StorageLogKind::RepeatedWrite, 5, 6),
+                read(key(3), 7),
+                write(key(1), StorageLogKind::InitialWrite, 0, 3),
+            ]
+        );
+
+        // Deduplication is idempotent.
+        let deduplicated_logs = logs.storage_logs.clone();
+        logs.deduplicate_storage_logs();
+        assert_eq!(logs.storage_logs, deduplicated_logs);
+    }
+
+    #[test]
+    fn computing_net_storage_writes() {
+        let address = Address::repeat_byte(1);
+        let key = |byte| StorageKey::new(AccountTreeId::new(address), H256::repeat_byte(byte));
+        let write = |key, previous_value: u64, value: u64| StorageLogWithPreviousValue {
+            log: StorageLog::new_write_log(key, H256::from_low_u64_be(value)),
+            previous_value: H256::from_low_u64_be(previous_value),
+        };
+        let read = |key, value: u64| StorageLogWithPreviousValue {
+            log: StorageLog::new_read_log(key, H256::from_low_u64_be(value)),
+            previous_value: H256::from_low_u64_be(value),
+        };
+
+        let logs = VmExecutionLogs {
+            storage_logs: vec![
+                // Written multiple times; only the final value matters.
+                write(key(1), 0, 1),
+                write(key(1), 1, 2),
+                read(key(1), 2),
+                write(key(1), 2, 3),
+                // Written and then restored to the original value.
+                write(key(2), 5, 6),
+                write(key(2), 6, 5),
+                // Only read.
+                read(key(3), 7),
+                write(key(4), 8, 9),
+            ],
+            ..VmExecutionLogs::default()
+        };
+
+        assert_eq!(
+            logs.net_storage_writes(),
+            HashMap::from([
+                ((address, H256::repeat_byte(1)), H256::from_low_u64_be(3)),
+                ((address, H256::repeat_byte(4)), H256::from_low_u64_be(9)),
+            ])
+        );
+        assert!(VmExecutionLogs::default().net_storage_writes().is_empty());
+    }
+
+    #[test]
+    fn grouping_storage_logs_by_slot() {
+        let address = Address::repeat_byte(1);
+        let key = |byte| StorageKey::new(AccountTreeId::new(address), H256::repeat_byte(byte));
+        let write = |key, value: u64| StorageLogWithPreviousValue {
+            log: StorageLog::new_write_log(key, H256::from_low_u64_be(value)),
+            previous_value: H256::zero(),
+        };
+        let read = |key, value: u64| StorageLogWithPreviousValue {
+            log: StorageLog::new_read_log(key, H256::from_low_u64_be(value)),
+            previous_value: H256::from_low_u64_be(value),
+        };
+
+        let logs = VmExecutionLogs {
+            storage_logs: vec![
+                write(key(1), 1),
+                read(key(2), 5),
+                write(key(1), 2),
+                read(key(1), 2),
+            ],
+            ..VmExecutionLogs::default()
+        };
+
+        let grouped = logs.storage_logs_by_slot();
+        assert_eq!(grouped.len(), 2);
+        let slot_logs = &grouped[&(address, H256::repeat_byte(1))];
+        assert_eq!(
+            slot_logs.iter().copied().cloned().collect::<Vec<_>>(),
+            [write(key(1), 1), write(key(1), 2), read(key(1), 2)]
+        );
+        assert_eq!(grouped[&(address, H256::repeat_byte(2))].len(), 1);
+
+        assert_eq!(
+            logs.last_value_for_slot(address, H256::repeat_byte(1)),
+            Some(H256::from_low_u64_be(2))
+        );
+        // The slot was only read.
+        assert_eq!(
+            logs.last_value_for_slot(address, H256::repeat_byte(2)),
+            None
+        );
+        assert_eq!(
+            logs.last_value_for_slot(Address::repeat_byte(2), H256::repeat_byte(1)),
+            None
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    fn gen_logs(rng: &mut impl rand::Rng) -> VmExecutionLogs {
+        let storage_logs = (0..rng.gen_range(0..5))
+            .map(|_| {
+                let key = StorageKey::new(AccountTreeId::new(Address(rng.gen())), H256(rng.gen()));
+                let mut log = StorageLog::new_write_log(key, H256(rng.gen()));
+                log.kind = [
+                    StorageLogKind::Read,
+                    StorageLogKind::InitialWrite,
+                    StorageLogKind::RepeatedWrite,
+                ][rng.gen_range(0..3)];
+                StorageLogWithPreviousValue {
+                    log,
+                    previous_value: H256(rng.gen()),
+                }
+            })
+            .collect();
+        let events = (0..rng.gen_range(0..5))
+            .map(|_| VmEvent {
+                location: (L1BatchNumber(rng.gen()), rng.gen()),
+                address: Address(rng.gen()),
+                indexed_topics: (0..rng.gen_range(0..=4)).map(|_| H256(rng.gen())).collect(),
+                value: (0..rng.gen_range(0..100)).map(|_| rng.gen()).collect(),
+            })
+            .collect();
+        let mut gen_l2_to_l1_log = || L2ToL1Log {
+            shard_id: rng.gen(),
+            is_service: rng.gen(),
+            tx_number_in_block: rng.gen(),
+            sender: Address(rng.gen()),
+            key: H256(rng.gen()),
+            value: H256(rng.gen()),
+        };
+        let user_l2_to_l1_logs = (0..2).map(|_| UserL2ToL1Log(gen_l2_to_l1_log())).collect();
+        let system_l2_to_l1_logs = (0..2)
+            .map(|_| SystemL2ToL1Log(gen_l2_to_l1_log()))
+            .collect();
+
+        VmExecutionLogs {
+            storage_logs,
+            events,
+            user_l2_to_l1_logs,
+            system_l2_to_l1_logs,
+            total_log_queries_count: rng.gen_range(0..1_000),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn logs_serde_roundtrip() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(123);
+        for _ in 0..100 {
+            let logs = gen_logs(&mut rng);
+            let serialized = serde_json::to_string(&logs).unwrap();
+            let deserialized: VmExecutionLogs = serde_json::from_str(&serialized).unwrap();
+            assert_eq!(deserialized, logs);
+        }
+    }
+
+    fn mock_deploy_event(
+        deployer: Address,
+        bytecode_hash: H256,
+        contract_address: Address,
+    ) -> VmEvent {
+        VmEvent::synthetic(
+            CONTRACT_DEPLOYER_ADDRESS,
+            vec![
+                VmEvent::DEPLOY_EVENT_SIGNATURE,
+                H256::from(deployer),
+                bytecode_hash,
+                H256::from(contract_address),
+            ],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn distinguishing_system_contract_events() {
+        for address in [
+            L1_MESSENGER_ADDRESS,
+            KNOWN_CODES_STORAGE_ADDRESS,
+            CONTRACT_DEPLOYER_ADDRESS,
+            L2_BASE_TOKEN_ADDRESS,
+            L2_ASSET_ROUTER_ADDRESS,
+            L2_NATIVE_TOKEN_VAULT_ADDRESS,
+        ] {
+            let event = VmEvent {
+                address,
+                ..VmEvent::default()
+            };
+            assert!(event.is_from_system_contract(), "{address:?}");
+        }
+
+        assert!(!mock_event(1).is_from_system_contract());
+        let user_event = VmEvent {
+            address: Address::repeat_byte(0x80),
+            ..VmEvent::default()
+        };
+        assert!(!user_event.is_from_system_contract());
+    }
+
+    #[test]
+    fn decoding_deploy_event() {
+        let deployer = Address::repeat_byte(1);
+        let bytecode_hash = H256::repeat_byte(2);
+        let contract_address = Address::repeat_byte(3);
+        let mut event = mock_deploy_event(deployer, bytecode_hash, contract_address);
+        assert_eq!(
+            event.decode_deploy_event(),
+            Some(DeployEvent {
+                deployer,
+                bytecode_hash,
+                contract_address,
+            })
+        );
+
+        event.indexed_topics[0] = VmEvent::L1_MESSAGE_EVENT_SIGNATURE;
+        assert_eq!(event.decode_deploy_event(), None);
+        event.indexed_topics[0] = VmEvent::DEPLOY_EVENT_SIGNATURE;
+        event.address = Address::repeat_byte(0xff);
+        assert_eq!(event.decode_deploy_event(), None);
+    }
+
+    #[test]
+    fn validating_deployments() {
+        let allowed = Address::repeat_byte(1);
+        let forbidden = Address::repeat_byte(0xff);
+        let whitelist = HashSet::from([allowed]);
+        let mut events = vec![
+            mock_event(1),
+            mock_deploy_event(allowed, H256::repeat_byte(2), Address::repeat_byte(3)),
+        ];
+        VmEvent::validate_deployments(&events, &whitelist).unwrap();
+        VmEvent::validate_deployments(&[], &HashSet::new()).unwrap();
+
+        events.push(mock_deploy_event(
+            forbidden,
+            H256::repeat_byte(2),
+            Address::repeat_byte(4),
+        ));
+        assert_eq!(
+            VmEvent::validate_deployments(&events, &whitelist),
+            Err(Halt::UnauthorizedDeployment(forbidden))
+        );
+        assert_eq!(
+            VmEvent::validate_deployments(&events, &HashSet::new()),
+            Err(Halt::UnauthorizedDeployment(allowed))
+        );
+    }
+
+    fn mock_transfer_event(token: Address, from: Address, to: Address, amount: U256) -> VmEvent {
+        VmEvent::synthetic(
+            token,
+            vec![
+                VmEvent::ERC20_TRANSFER_EVENT_SIGNATURE,
+                H256::from(from),
+                H256::from(to),
+            ],
+            ethabi::encode(&[ethabi::Token::Uint(amount)]),
+        )
+    }
+
+    #[test]
+    fn extracting_erc20_transfers() {
+        let token = Address::repeat_byte(0x10);
+        let (alice, bob) = (Address::repeat_byte(1), Address::repeat_byte(2));
+        let mut nft_transfer = mock_transfer_event(token, alice, bob, U256::zero());
+        nft_transfer.indexed_topics.push(H256::from_low_u64_be(42));
+        nft_transfer.value.clear();
+        let mut truncated_transfer = mock_transfer_event(token, alice, bob, U256::one());
+        truncated_transfer.indexed_topics.truncate(1);
+        let mut malformed_transfer = mock_transfer_event(token, alice, bob, U256::one());
+        malformed_transfer.value.truncate(16);
+
+        let events = [
+            mock_transfer_event(token, alice, bob, U256::from(100)),
+            mock_event(1),
+            nft_transfer,
+            truncated_transfer,
+            malformed_transfer,
+            mock_transfer_event(Address::repeat_byte(0x20), bob, alice, U256::MAX),
+        ];
+        assert_eq!(
+            VmEvent::extract_erc20_transfers(&events),
+            [
+                (token, alice, bob, U256::from(100)),
+                (Address::repeat_byte(0x20), bob, alice, U256::MAX),
+            ]
+        );
+        assert!(VmEvent::extract_erc20_transfers(&[]).is_empty());
+    }
+
+    #[test]
+    fn listing_deployed_contracts() {
+        let deployer = Address::repeat_byte(1);
+        let mut result = VmExecutionResultAndLogs::mock_success();
+        result.logs.events = vec![
+            mock_deploy_event(deployer, H256::repeat_byte(2), Address::repeat_byte(3)),
+            mock_event(1),
+            mock_deploy_event(deployer, H256::repeat_byte(4), Address::repeat_byte(5)),
+        ];
+
+        assert_eq!(
+            result.deployed_contracts(),
+            [
+                (Address::repeat_byte(3), H256::repeat_byte(2)),
+                (Address::repeat_byte(5), H256::repeat_byte(4)),
+            ]
+        );
+        assert!(VmExecutionResultAndLogs::mock_success()
+            .deployed_contracts()
+            .is_empty());
+    }
+
+    #[test]
+    fn detecting_upgradeable_deployments() {
+        let deployer = Address::repeat_byte(1);
+        let known_proxies = HashMap::from([
+            (H256::repeat_byte(0xaa), ProxyKind::TransparentProxy),
+            (H256::repeat_byte(0xbb), ProxyKind::Uups),
+        ]);
+
+        let mut result = VmExecutionResultAndLogs::mock_success();
+        result.logs.events = vec![mock_deploy_event(
+            deployer,
+            H256::repeat_byte(2),
+            Address::repeat_byte(3),
+        )];
+        assert_eq!(result.is_upgradeable_deployment(&known_proxies), None);
+
+        result.logs.events.push(mock_deploy_event(
+            deployer,
+            H256::repeat_byte(0xbb),
+            Address::repeat_byte(4),
+        ));
+        result.logs.events.push(mock_deploy_event(
+            deployer,
+            H256::repeat_byte(0xaa),
+            Address::repeat_byte(5),
+        ));
+        assert_eq!(
+            result.is_upgradeable_deployment(&known_proxies),
+            Some(ProxyKind::Uups)
+        );
+        assert_eq!(result.is_upgradeable_deployment(&HashMap::new()), None);
+    }
+
+    #[test]
+    fn estimating_l1_batch_encoding_size() {
+        let key = |byte| {
+            StorageKey::new(
+                AccountTreeId::new(Address::repeat_byte(1)),
+                H256::repeat_byte(byte),
+            )
+        };
+        let write = |key, kind, previous_value: u64, value: u64| StorageLogWithPreviousValue {
+            log: StorageLog {
+                kind,
+                key,
+                value: H256::from_low_u64_be(value),
+            },
+            previous_value: H256::from_low_u64_be(previous_value),
+        };
+
+        let mut result = VmExecutionResultAndLogs::mock_success();
+        assert_eq!(
+            result.estimate_l1_batch_encoding_size(ProtocolVersionId::latest()),
+            0
+        );
+
+        result.logs.storage_logs = vec![
+            write(key(1), StorageLogKind::InitialWrite, 0, 1),
+            write(key(1), StorageLogKind::RepeatedWrite, 1, 2),
+            // Restores the original value, so it's not published.
+            write(key(2), StorageLogKind::RepeatedWrite, 5, 6),
+            write(key(2), StorageLogKind::RepeatedWrite, 6, 5),
+            write(key(3), StorageLogKind::RepeatedWrite, 0, 1),
+            StorageLogWithPreviousValue {
+                log: StorageLog::new_read_log(key(4), H256::zero()),
+                previous_value: H256::zero(),
+            },
+        ];
+        result.logs.user_l2_to_l1_logs = vec![UserL2ToL1Log::default()];
+
+        // Each storage diff has a 2-byte compressed value (the metadata byte + 1-byte diff).
+        let expected_writes_size = (32 + 2) + (4 + 2);
+        let expected_logs_size = 88 + 4;
+        assert_eq!(
+            result.estimate_l1_batch_encoding_size(ProtocolVersionId::latest()),
+            expected_writes_size + expected_logs_size
+        );
+    }
+
+    #[test]
+    fn comparing_results() {
+        let mut result = VmExecutionResultAndLogs::mock_success();
+        result.logs.events = vec![mock_event(1)];
+        result.statistics.gas_used = 100;
+        result.statistics.cycles_used = 1_000;
+
+        let mut other = result.clone();
+        assert_eq!(result, other);
+        assert!(result.eq_strict(&other));
+
+        other.statistics.cycles_used = 2_000;
+        other.statistics.circuit_statistic.main_vm = 0.5;
+        assert_eq!(result, other);
+        assert!(!result.eq_strict(&other));
+
+        let mut other = result.clone();
+        other.statistics.gas_used = 200;
+        assert_ne!(result, other);
+        assert!(!result.eq_strict(&other));
+
+        let mut other = result.clone();
+        other.logs.events.push(mock_event(2));
+        assert_ne!(result, other);
+        let mut other = result.clone();
+        other.refunds.gas_refunded = 10;
+        assert_ne!(result, other);
+        let other = VmExecutionResultAndLogs {
+            result: ExecutionResult::Revert {
+                output: VmRevertReason::VmError,
+            },
+            ..result.clone()
+        };
+        assert_ne!(result, other);
+    }
+
+    #[test]
+    fn cloning_result_without_storage_logs() {
+        let mut result = VmExecutionResultAndLogs::mock(ExecutionResult::Revert {
+            output: VmRevertReason::General {
+                msg: "oops".to_owned(),
+                data: vec![],
+            },
+        });
+        result.logs.storage_logs = vec![StorageLogWithPreviousValue {
+            log: StorageLog::new_write_log(
+                StorageKey::new(
+                    AccountTreeId::new(Address::repeat_byte(1)),
+                    H256::repeat_byte(2),
+                ),
+                H256::repeat_byte(3),
+            ),
+            previous_value: H256::zero(),
+        }];
+        result.logs.events = vec![mock_event(1), mock_event(2)];
+        result.logs.user_l2_to_l1_logs = vec![UserL2ToL1Log::default()];
+        result.logs.total_log_queries_count = 5;
+        result.statistics.gas_used = 100;
+        result.refunds.gas_refunded = 10;
+
+        let cloned = result.clone_without_storage_logs();
+        assert!(cloned.logs.storage_logs.is_empty());
+        assert_eq!(cloned.result, result.result);
+        assert_eq!(cloned.logs.events, result.logs.events);
+        assert_eq!(
+            cloned.logs.user_l2_to_l1_logs,
+            result.logs.user_l2_to_l1_logs
+        );
+        assert_eq!(cloned.logs.total_log_queries_count, 5);
+        assert_eq!(cloned.statistics.gas_used, 100);
+        assert_eq!(cloned.refunds, result.refunds);
+        // The original result is unchanged.
+        assert_eq!(result.logs.storage_logs.len(), 1);
+    }
+
+    #[test]
+    fn filtering_user_logs() {
+        let mut result = VmExecutionResultAndLogs::mock_success();
+        let system_event = VmEvent {
+            address: L1_MESSENGER_ADDRESS,
+            ..VmEvent::default()
+        };
+        result.logs.events = vec![mock_event(1), system_event, mock_event(2)];
+        result.logs.user_l2_to_l1_logs = vec![UserL2ToL1Log::default()];
+        result.logs.system_l2_to_l1_logs = vec![SystemL2ToL1Log::default()];
+        result.logs.total_log_queries_count = 5;
+
+        let user_logs = result.filter_user_logs();
+        assert_eq!(user_logs.events, [mock_event(1), mock_event(2)]);
+        assert_eq!(user_logs.user_l2_to_l1_logs.len(), 1);
+        assert!(user_logs.system_l2_to_l1_logs.is_empty());
+        assert_eq!(user_logs.total_log_queries_count, 5);
+        // The original result is unchanged.
+        assert_eq!(result.logs.events.len(), 3);
+        assert_eq!(result.logs.system_l2_to_l1_logs.len(), 1);
+    }
+
+    #[test]
+    fn computing_remaining_gas() {
+        let mut result = VmExecutionResultAndLogs::mock_success();
+        result.statistics.gas_used = 250;
+        assert_eq!(result.gas_remaining(1_000), 750);
+        assert_eq!(result.gas_remaining(250), 0);
+        assert_eq!(result.gas_remaining(100), 0);
+        assert_eq!(result.gas_used_fraction(1_000), 0.25);
+        assert_eq!(result.gas_used_fraction(250), 1.0);
+        assert_eq!(result.gas_used_fraction(0), 0.0);
+    }
+
+    #[test]
+    fn computing_pubdata_cost() {
+        let mut result = VmExecutionResultAndLogs::mock_success();
+        result.statistics.pubdata_published = 1_000;
+        assert_eq!(result.total_pubdata_cost(U256::from(3)), U256::from(3_000));
+        assert_eq!(result.total_pubdata_cost(U256::zero()), U256::zero());
+        assert_eq!(result.total_pubdata_cost(U256::MAX / 2), U256::MAX);
+
+        result.statistics.pubdata_published = 0;
+        assert_eq!(result.total_pubdata_cost(U256::MAX), U256::zero());
+    }
+
+    #[test]
+    fn batch_tx_execution_status() {
+        let results = [
+            (
+                ExecutionResult::Success { output: vec![] },
+                TxExecutionStatus::Success,
+            ),
+            (
+                ExecutionResult::Revert {
+                    output: VmRevertReason::VmError,
+                },
+                TxExecutionStatus::Failure,
+            ),
+            (
+                ExecutionResult::Halt {
+                    reason: Halt::VMPanic,
+                },
+                TxExecutionStatus::Failure,
+            ),
+        ];
+        for (result, expected_status) in results {
+            let batch_result =
+                BatchTransactionExecutionResult::mock(VmExecutionResultAndLogs::mock(result));
+            assert_eq!(batch_result.tx_result.execution_status(), expected_status);
+            assert_eq!(batch_result.execution_status(), expected_status);
+            assert!(!batch_result.is_compression_failed());
+            assert_matches!(batch_result.compression_error(), None);
+        }
+    }
+
+    #[test]
+    fn batch_tx_execution_time() {
+        let mut batch_result =
+            BatchTransactionExecutionResult::mock(VmExecutionResultAndLogs::mock_success());
+        assert_eq!(batch_result.execution_time, Duration::ZERO);
+        assert_eq!(batch_result.execution_time_ms(), 0);
+
+        batch_result.execution_time = Duration::from_micros(12_345);
+        assert_eq!(batch_result.execution_time_ms(), 12);
+        batch_result.execution_time = Duration::MAX;
+        assert_eq!(batch_result.execution_time_ms(), u64::MAX);
+    }
+
+    #[test]
+    fn destructuring_batch_tx_result() {
+        let batch_result =
+            BatchTransactionExecutionResult::mock(VmExecutionResultAndLogs::mock_success())
+                .with_compression_result(Err(BytecodeCompressionError::BytecodeCompressionFailed))
+                .with_call_traces(vec![mock_call(CallType::Create, 1, vec![])]);
+        assert!(batch_result.is_compression_failed());
+        assert_matches!(
+            batch_result.compression_error(),
+            Some(BytecodeCompressionError::BytecodeCompressionFailed)
+        );
+        let (tx_result, compression_result, call_traces) = batch_result.into_parts();
+        assert!(tx_result.result.is_success());
+        assert_matches!(
+            compression_result,
+            Err(BytecodeCompressionError::BytecodeCompressionFailed)
+        );
+        assert_eq!(call_traces, [mock_call(CallType::Create, 1, vec![])]);
+    }
+
+    #[test]
+    fn converting_to_oneshot_tx_result() {
+        let call_traces = vec![mock_call(CallType::Create, 1, vec![])];
+        let result = OneshotTransactionExecutionResult::from((
+            VmExecutionResultAndLogs::mock_success(),
+            call_traces.clone(),
+        ));
+        assert!(result.tx_result.result.is_success());
+        assert_matches!(
+            result.compression_result,
+            Ok(metrics) if metrics == BytecodeCompressionMetrics::default()
+        );
+        assert_eq!(result.call_traces, call_traces);
+    }
+
+    #[test]
+    fn detecting_meaningful_refund_difference() {
+        let refunds = |gas_refunded, operator_suggested_refund| Refunds {
+            gas_refunded,
+            operator_suggested_refund,
+        };
+
+        assert!(!refunds(0, 0).is_meaningful(0));
+        assert!(!refunds(100, 100).is_meaningful(0));
+        assert!(refunds(100, 99).is_meaningful(0));
+        assert!(refunds(0, 1).is_meaningful(99));
+
+        // The difference is exactly 10% of the larger refund, which doesn't exceed the threshold.
+        assert!(!refunds(100, 90).is_meaningful(10));
+        assert!(!refunds(90, 100).is_meaningful(10));
+        assert!(refunds(100, 89).is_meaningful(10));
+        assert!(refunds(89, 100).is_meaningful(10));
+
+        assert!(!refunds(u64::MAX, 0).is_meaningful(100));
+        assert!(refunds(u64::MAX, 0).is_meaningful(99));
+        assert!(!refunds(u64::MAX, u64::MAX - 1).is_meaningful(1));
+    }
+
+    #[test]
+    fn accumulating_refunds() {
+        let first = Refunds {
+            gas_refunded: 100,
+            operator_suggested_refund: 150,
+        };
+        let second = Refunds {
+            gas_refunded: 50,
+            operator_suggested_refund: 10,
+        };
+        assert_eq!(first.total(), 150);
+        assert_eq!(second.total(), 50);
+
+        let sum = first.clone() + second.clone();
+        assert_eq!(
+            sum,
+            Refunds {
+                gas_refunded: 150,
+                operator_suggested_refund: 160,
+            }
+        );
+        assert_eq!(sum.total(), 160);
+
+        let mut acc = Refunds::default();
+        acc += first;
+        acc += second;
+        assert_eq!(acc, sum);
+    }
+
+    fn mock_l1_message_event(sender: Address, message: &[u8]) -> VmEvent {
+        VmEvent::synthetic(
+            L1_MESSENGER_ADDRESS,
+            vec![
+                VmEvent::L1_MESSAGE_EVENT_SIGNATURE,
+                H256::from(sender),
+                H256(keccak256(message)),
+            ],
+            ethabi::encode(&[ethabi::Token::Bytes(message.to_vec())]),
+        )
+    }
+
+    #[test]
+    fn converting_l1_message_event_to_user_log() {
+        let sender = Address::repeat_byte(1);
+        let mut event = mock_l1_message_event(sender, b"message");
+        event.location = (L1BatchNumber(1), 5);
+        let log = UserL2ToL1Log::try_from(&event).unwrap();
+        assert_eq!(
+            log.0,
+            L2ToL1Log {
+                shard_id: 0,
+                is_service: true,
+                tx_number_in_block: 5,
+                sender: L1_MESSENGER_ADDRESS,
+                key: H256::from(sender),
+                value: H256(keccak256(b"message")),
+            }
+        );
+
+        let mut overflowing_event = event.clone();
+        overflowing_event.location.1 = u32::from(u16::MAX) + 1;
+        assert_matches!(
+            UserL2ToL1Log::try_from(&overflowing_event),
+            Err(L1MessageEventError::TxNumberOverflow(_))
+        );
+        let mut mismatched_event = event.clone();
+        mismatched_event.indexed_topics[2] = H256::zero();
+        assert_matches!(
+            UserL2ToL1Log::try_from(&mismatched_event),
+            Err(L1MessageEventError::HashMismatch)
+        );
+        let mut malformed_event = event.clone();
+        malformed_event.value = vec![1, 2, 3];
+        assert_matches!(
+            UserL2ToL1Log::try_from(&malformed_event),
+            Err(L1MessageEventError::MalformedPayload(_))
+        );
+        assert_matches!(
+            UserL2ToL1Log::try_from(&mock_event(1)),
+            Err(L1MessageEventError::NotAL1MessageEvent)
+        );
+    }
+
+    #[test]
+    fn extracting_l2_to_l1_messages_by_sender() {
+        let sender = Address::repeat_byte(1);
+        let other_sender = Address::repeat_byte(2);
+        let events = [
+            mock_l1_message_event(sender, b"first"),
+            mock_l1_message_event(other_sender, b"second"),
+            mock_event(3),
+            mock_l1_message_event(sender, b"third"),
+        ];
+
+        let all_messages = VmEvent::extract_long_l2_to_l1_messages(&events);
+        assert_eq!(
+            all_messages,
+            [b"first".to_vec(), b"second".to_vec(), b"third".to_vec()]
+        );
+        let messages = VmEvent::extract_long_l2_to_l1_messages_from(&events, sender);
+        assert_eq!(messages, [b"first".to_vec(), b"third".to_vec()]);
+        let messages = VmEvent::extract_long_l2_to_l1_messages_from(&events, Address::zero());
+        assert!(messages.is_empty());
+
+        let messages = VmEvent::extract_l1_messages_with_sender(&events);
+        assert_eq!(
+            messages,
+            [
+                (sender, b"first".to_vec()),
+                (other_sender, b"second".to_vec()),
+                (sender, b"third".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn summarizing_execution_result() {
+        let mut result = VmExecutionResultAndLogs::mock(ExecutionResult::Revert {
+            output: VmRevertReason::VmError,
+        });
+        result.statistics.gas_used = 1_000;
+        result.statistics.pubdata_published = 100;
+        result.logs.events = vec![mock_event(1), mock_event(2)];
+        result.logs.user_l2_to_l1_logs = vec![UserL2ToL1Log::default()];
+        result.logs.system_l2_to_l1_logs = vec![SystemL2ToL1Log::default()];
+
+        let summary = VmExecutionSummary::from(&result);
+        assert_eq!(
+            summary,
+            VmExecutionSummary {
+                gas_used: 1_000,
+                pubdata_published: 100,
+                storage_logs_count: 0,
+                events_count: 2,
+                l2_to_l1_logs_count: 2,
+                reverted: true,
+                halted: false,
+            }
+        );
+    }
+
+    fn mock_call(r#type: CallType, gas_used: u64, calls: Vec<Call>) -> Call {
+        Call {
+            r#type,
+            gas_used,
+            calls,
+            ..Call::default()
+        }
+    }
+
+    #[test]
+    fn flattening_call_tree() {
+        let mut root = mock_call(
+            CallType::Call(FarCallOpcode::Normal),
+            0,
+            vec![
+                mock_call(
+                    CallType::NearCall,
+                    1,
+                    vec![mock_call(CallType::Create, 2, vec![])],
+                ),
+                mock_call(
+                    CallType::Call(FarCallOpcode::Delegate),
+                    3,
+                    vec![
+                        mock_call(CallType::Call(FarCallOpcode::Mimic), 4, vec![]),
+                        mock_call(CallType::NearCall, 5, vec![]),
+                    ],
+                ),
+            ],
+        );
+
+        let visited_gas: Vec<_> = root.flatten().map(|call| call.gas_used).collect();
+        assert_eq!(visited_gas, [0, 1, 2, 3, 4, 5]);
+        let visited_types: Vec<_> = root.flatten().map(|call| call.r#type).collect();
+        assert_eq!(
+            visited_types,
+            [
+                CallType::Call(FarCallOpcode::Normal),
+                CallType::NearCall,
+                CallType::Create,
+                CallType::Call(FarCallOpcode::Delegate),
+                CallType::Call(FarCallOpcode::Mimic),
+                CallType::NearCall,
+            ]
+        );
+
+        let mut visited_gas = vec![];
+        root.flatten_mut(|call| {
+            visited_gas.push(call.gas_used);
+            call.gas_used *= 10;
+        });
+        assert_eq!(visited_gas, [0, 1, 2, 3, 4, 5]);
+        let visited_gas: Vec<_> = root.flatten().map(|call| call.gas_used).collect();
+        assert_eq!(visited_gas, [0, 10, 20, 30, 40, 50]);
+
+        let leaf = mock_call(CallType::Create, 7, vec![]);
+        assert_eq!(leaf.flatten().count(), 1);
+    }
+
+    #[test]
+    fn traversing_call_tree_in_bfs_order() {
+        let root = mock_call(
+            CallType::Call(FarCallOpcode::Normal),
+            0,
+            vec![
+                mock_call(
+                    CallType::NearCall,
+                    1,
+                    vec![mock_call(
+                        CallType::Create,
+                        3,
+                        vec![mock_call(CallType::NearCall, 6, vec![])],
+                    )],
+                ),
+                mock_call(
+                    CallType::Call(FarCallOpcode::Delegate),
+                    2,
+                    vec![
+                        mock_call(CallType::Call(FarCallOpcode::Mimic), 4, vec![]),
+                        mock_call(CallType::NearCall, 5, vec![]),
+                    ],
+                ),
+            ],
+        );
+
+        let visited_gas: Vec<_> = root.bfs().map(|call| call.gas_used).collect();
+        assert_eq!(visited_gas, [0, 1, 2, 3, 4, 5, 6]);
+        let visited_gas: Vec<_> = root.flatten().map(|call| call.gas_used).collect();
+        assert_eq!(visited_gas, [0, 1, 3, 6, 2, 4, 5]);
+
+        let leaf = mock_call(CallType::Create, 7, vec![]);
+        let visited: Vec<_> = leaf.bfs().collect();
+        assert_eq!(visited, [&leaf]);
+    }
+
+    #[test]
+    fn classifying_call_errors() {
+        let mut call = mock_call(CallType::Call(FarCallOpcode::Normal), 0, vec![]);
+        assert_eq!(call.error_kind(), None);
+        call.revert_reason = Some("Insufficient balance".to_owned());
+        assert_eq!(call.error_kind(), Some(CallErrorKind::Reverted));
+
+        let expected_kinds = [
+            ("Panic", CallErrorKind::Panicked),
+            ("out of gas", CallErrorKind::Other("out of gas".to_owned())),
+        ];
+        for (error, expected_kind) in expected_kinds {
+            call.error = Some(error.to_owned());
+            assert_eq!(call.error_kind(), Some(expected_kind), "{error}");
+        }
+    }
+
+    #[test]
+    fn converting_call_tree_to_records() {
+        let tx_hash = H256::repeat_byte(0xaa);
+        let mut root = mock_call(
+            CallType::Call(FarCallOpcode::Normal),
+            0,
+            vec![
+                mock_call(
+                    CallType::NearCall,
+                    1,
+                    vec![mock_call(CallType::Create, 2, vec![])],
+                ),
+                mock_call(CallType::Call(FarCallOpcode::Delegate), 3, vec![]),
+            ],
+        );
+        root.revert_reason = Some("oops".to_owned());
+
+        let records = root.to_records(tx_hash);
+        let tree: Vec<_> = records
+            .iter()
+            .map(|record| (record.id, record.parent_id, record.depth, record.gas_used))
+            .collect();
+        assert_eq!(
+            tree,
+            [
+                (0, None, 0, 0),
+                (1, Some(0), 1, 1),
+                (2, Some(1), 2, 2),
+                (3, Some(0), 1, 3),
+            ]
+        );
+        assert!(records.iter().all(|record| record.tx_hash == tx_hash));
+        assert_eq!(records[0].revert_reason.as_deref(), Some("oops"));
+        assert_eq!(records[2].r#type, CallType::Create);
+
+        let leaf_records = mock_call(CallType::Create, 7, vec![]).to_records(tx_hash);
+        assert_eq!(leaf_records.len(), 1);
+        assert_eq!(leaf_records[0].parent_id, None);
+    }
+
+    #[test]
+    fn displaying_call_tree() {
+        let root = Call {
+            from: Address::repeat_byte(1),
+            to: Address::repeat_byte(2),
+            gas: 100_000,
+            gas_used: 50_000,
+            value: U256::from(1_000),
+            calls: vec![
+                Call {
+                    r#type: CallType::Call(FarCallOpcode::Delegate),
+                    from: Address::repeat_byte(2),
+                    to: Address::repeat_byte(3),
+                    gas: 60_000,
+                    gas_used: 20_000,
+                    revert_reason: Some("Ownable: caller is not the owner".to_owned()),
+                    calls: vec![Call {
+                        r#type: CallType::NearCall,
+                        from: Address::repeat_byte(3),
+                        to: Address::repeat_byte(3),
+                        gas: 10_000,
+                        gas_used: 10_000,
+                        error: Some("out of gas".to_owned()),
+                        ..Call::default()
+                    }],
+                    ..Call::default()
+                },
+                Call {
+                    r#type: CallType::Create,
+                    from: Address::repeat_byte(2),
+                    to: Address::repeat_byte(4),
+                    gas: 30_000,
+                    gas_used: 25_000,
+                    ..Call::default()
+                },
+            ],
+            ..Call::default()
+        };
+
+        let expected = include_str!("testdata/call_tree.txt");
+        assert_eq!(root.to_string(), expected.trim_end());
+    }
+
+    #[test]
+    fn computing_gas_efficiency() {
+        let leaf = Call {
+            gas: 1_000,
+            gas_used: 1_000,
+            ..Call::default()
+        };
+        let root = Call {
+            gas: 3_000,
+            gas_used: 500,
+            calls: vec![leaf.clone(), Call::default()],
+            ..Call::default()
+        };
+        assert_eq!(leaf.gas_efficiency(), 100.0);
+        assert_eq!(root.gas_efficiency(), 500.0 / 3_000.0 * 100.0);
+        assert_eq!(Call::default().gas_efficiency(), 0.0);
+
+        // Weighted by gas: (500 + 1_000 + 0) / (3_000 + 1_000 + 0)
+        assert_eq!(root.subtree_gas_efficiency(), 1_500.0 / 4_000.0 * 100.0);
+        assert_eq!(leaf.subtree_gas_efficiency(), 100.0);
+        assert_eq!(Call::default().subtree_gas_efficiency(), 0.0);
+
+        let huge_call = Call {
+            gas: u64::MAX,
+            gas_used: u64::MAX,
+            calls: vec![Call {
+                gas: u64::MAX,
+                gas_used: 0,
+                ..Call::default()
+            }],
+            ..Call::default()
+        };
+        assert_eq!(huge_call.subtree_gas_efficiency(), 50.0);
+    }
+
+    #[test]
+    fn filtering_calls_by_sender() {
+        let alice = Address::repeat_byte(1);
+        let bob = Address::repeat_byte(2);
+        let call_from = |from: Address, gas_used: u64, calls: Vec<Call>| Call {
+            from,
+            ..mock_call(CallType::Call(FarCallOpcode::Normal), gas_used, calls)
+        };
+        let root = call_from(
+            alice,
+            0,
+            vec![
+                call_from(bob, 1, vec![call_from(alice, 2, vec![])]),
+                call_from(alice, 3, vec![call_from(bob, 4, vec![])]),
+            ],
+        );
+
+        let alice_gas: Vec<_> = root
+            .from_address(alice)
+            .into_iter()
+            .map(|call| call.gas_used)
+            .collect();
+        assert_eq!(alice_gas, [0, 2, 3]);
+        let bob_gas: Vec<_> = root
+            .from_address(bob)
+            .into_iter()
+            .map(|call| call.gas_used)
+            .collect();
+        assert_eq!(bob_gas, [1, 4]);
+        assert!(root.from_address(Address::repeat_byte(3)).is_empty());
+    }
+
+    #[test]
+    fn counting_subcalls() {
+        let leaf = || mock_call(CallType::NearCall, 0, vec![]);
+        let flat = mock_call(CallType::NearCall, 0, (0..100).map(|_| leaf()).collect());
+        assert_eq!(flat.subcall_count(), 100);
+        assert_eq!(flat.total_call_count(), 100);
+        assert_eq!(leaf().subcall_count(), 0);
+        assert_eq!(leaf().total_call_count(), 0);
+
+        // Full binary tree of depth 7, i.e. with 127 calls.
+        let mut tree = leaf();
+        for _ in 1..7 {
+            tree = mock_call(CallType::NearCall, 0, vec![tree.clone(), tree]);
+        }
+        assert_eq!(tree.max_call_depth(), 7);
+        assert_eq!(tree.subcall_count(), 2);
+        // The root itself is not counted.
+        assert_eq!(tree.total_call_count(), 126);
+        let root = mock_call(CallType::Call(FarCallOpcode::Normal), 0, vec![tree]);
+        assert_eq!(root.subcall_count(), 1);
+        assert_eq!(root.total_call_count(), 127);
+    }
+
+    #[test]
+    fn finding_contract_deployments() {
+        let near_call = || mock_call(CallType::NearCall, 0, vec![]);
+        let mut root = mock_call(
+            CallType::Call(FarCallOpcode::Normal),
+            0,
+            vec![
+                near_call(),
+                mock_call(CallType::Call(FarCallOpcode::Mimic), 1, vec![near_call()]),
+            ],
+        );
+        assert!(!root.contains_create());
+        assert!(root.all_creates().is_empty());
+
+        // Direct deployment from the transaction.
+        root.calls
+            .push(mock_call(CallType::Create, 2, vec![near_call()]));
+        // Nested deployment by a factory contract, which itself is being deployed.
+        root.calls[2]
+            .calls
+            .push(mock_call(CallType::Create, 3, vec![]));
+        // Deployment by a factory called from the transaction.
+        root.calls[1]
+            .calls
+            .push(mock_call(CallType::Create, 4, vec![]));
+        assert!(root.contains_create());
+        assert!(root.calls[2].contains_create());
+        assert!(!root.calls[0].contains_create());
+
+        let gas_used: Vec<_> = root
+            .all_creates()
+            .iter()
+            .map(|call| call.gas_used)
+            .collect();
+        assert_eq!(gas_used, [4, 2, 3]);
+
+        let root = mock_call(CallType::Create, 5, vec![]);
+        assert_eq!(root.all_creates(), [&root]);
+    }
+
+    #[test]
+    fn finding_first_reverted_call() {
+        let leaf = |gas_used| mock_call(CallType::NearCall, gas_used, vec![]);
+        let mut root = mock_call(
+            CallType::Call(FarCallOpcode::Normal),
+            0,
+            vec![
+                mock_call(CallType::NearCall, 1, vec![leaf(2)]),
+                mock_call(CallType::NearCall, 3, vec![leaf(4), leaf(5)]),
+            ],
+        );
+        assert_eq!(root.revert_depth(), None);
+
+        root.calls[1].calls[1].error = Some("out of gas".to_owned());
+        assert_eq!(root.revert_depth(), Some(5));
+        root.calls[1].revert_reason = Some("reverted".to_owned());
+        assert_eq!(root.revert_depth(), Some(3));
+        root.calls[0].calls[0].revert_reason = Some("reverted".to_owned());
+        assert_eq!(root.revert_depth(), Some(2));
+        root.error = Some("reverted".to_owned());
+        assert_eq!(root.revert_depth(), Some(0));
+    }
+
+    #[test]
+    fn accessing_indexed_topics() {
+        let event = VmEvent {
+            indexed_topics: vec![H256::repeat_byte(1), H256::repeat_byte(2)],
+            ..VmEvent::default()
+        };
+        assert_eq!(event.indexed_topic_at(0), Some(H256::repeat_byte(1)));
+        assert_eq!(event.indexed_topic_at(1), Some(H256::repeat_byte(2)));
+        assert_eq!(event.indexed_topic_at(2), None);
+    }
+
+    #[test]
+    fn decoding_event_values() {
+        let event_with = |token: ethabi::Token| VmEvent {
+            value: ethabi::encode(&[token]),
+            ..VmEvent::default()
+        };
+
+        let event = event_with(ethabi::Token::Bytes(b"hello".to_vec()));
+        assert_eq!(event.try_decode_bytes().unwrap(), b"hello");
+        let event = event_with(ethabi::Token::Address(Address::repeat_byte(0x11)));
+        assert_eq!(
+            event.try_decode_address().unwrap(),
+            Address::repeat_byte(0x11)
+        );
+        let event = event_with(ethabi::Token::Uint(U256::from(123)));
+        assert_eq!(event.try_decode_u256().unwrap(), U256::from(123));
+        let event = event_with(ethabi::Token::FixedBytes(vec![0x22; 32]));
+        assert_eq!(
+            event.try_decode_abi::<H256>().unwrap(),
+            H256::repeat_byte(0x22)
+        );
+        let event = event_with(ethabi::Token::Bool(true));
+        assert!(event.try_decode_abi::<bool>().unwrap());
+
+        // Data is too short for the `bytes` type.
+        let event = VmEvent {
+            value: vec![1, 2, 3],
+            ..VmEvent::default()
+        };
+        event.try_decode_bytes().unwrap_err();
+    }
+
+    #[test]
+    fn matching_event_signature() {
+        let event = VmEvent {
+            indexed_topics: vec![VmEvent::DEPLOY_EVENT_SIGNATURE, H256::repeat_byte(1)],
+            ..VmEvent::default()
+        };
+        assert!(event.matches_signature(VmEvent::DEPLOY_EVENT_SIGNATURE));
+        assert!(!event.matches_signature(VmEvent::L1_MESSAGE_EVENT_SIGNATURE));
+        // Only the first topic is checked.
+        assert!(!event.matches_signature(H256::repeat_byte(1)));
+        // Anonymous events without topics don't match any signature.
+        assert!(!VmEvent::default().matches_signature(H256::zero()));
+    }
+
+    #[test]
+    fn extracting_bytecodes_marked_as_known() {
+        let marked_as_known = |hash: H256, publish: bool| VmEvent {
+            address: KNOWN_CODES_STORAGE_ADDRESS,
+            indexed_topics: vec![
+                VmEvent::PUBLISHED_BYTECODE_SIGNATURE,
+                hash,
+                H256::from_low_u64_be(publish.into()),
+            ],
+            ..VmEvent::default()
+        };
+        let malformed_event = VmEvent {
+            address: KNOWN_CODES_STORAGE_ADDRESS,
+            indexed_topics: vec![VmEvent::PUBLISHED_BYTECODE_SIGNATURE],
+            ..VmEvent::default()
+        };
+        let events = [
+            marked_as_known(H256::repeat_byte(1), true),
+            malformed_event,
+            marked_as_known(H256::repeat_byte(2), false),
+            mock_event(3),
+        ];
+
+        let published = VmEvent::extract_published_bytecodes(&events);
+        assert_eq!(published, [H256::repeat_byte(1)]);
+        let marked: Vec<_> = VmEvent::extract_bytecodes_marked_as_known(&events).collect();
+        assert_eq!(marked, [H256::repeat_byte(1), H256::repeat_byte(2)]);
+    }
+
+    #[test]
+    fn extracting_revert_reason_from_events() {
+        let revert_data = |msg: &str| {
+            let selector = ethabi::short_signature("Error", &[ethabi::ParamType::String]);
+            let encoded_msg = ethabi::encode(&[ethabi::Token::String(msg.to_owned())]);
+            [&selector[..], &encoded_msg].concat()
+        };
+        let wrapped_revert_data =
+            |msg: &str| ethabi::encode(&[ethabi::Token::Bytes(revert_data(msg))]);
+        let event = |value| VmEvent::synthetic(Address::repeat_byte(1), vec![], value);
+
+        assert_eq!(VmEvent::extract_revert_reason_from_events(&[]), None);
+        let events = [
+            mock_event(1),
+            // Truncated revert data
+            event(revert_data("truncated")[..40].to_vec()),
+            // Revert data with an out-of-range string offset
+            event([&revert_data("")[..4], &[0xff; 64]].concat()),
+            // Custom error
+            event(ethabi::encode(&[ethabi::Token::Bytes(vec![1, 2, 3, 4, 5])])),
+            event(ethabi::encode(&[ethabi::Token::String(
+                "not an error".to_owned(),
+            )])),
+        ];
+        assert_eq!(VmEvent::extract_revert_reason_from_events(&events), None);
+
+        let mut events = events.to_vec();
+        events.push(event(wrapped_revert_data("wrapped")));
+        events.push(event(revert_data("raw")));
+        assert_eq!(
+            VmEvent::extract_revert_reason_from_events(&events).as_deref(),
+            Some("wrapped")
+        );
+        events.swap(5, 6);
+        assert_eq!(
+            VmEvent::extract_revert_reason_from_events(&events).as_deref(),
+            Some("raw")
+        );
+    }
+
+    #[test]
+    fn extracting_published_bytecodes_with_size() {
+        let era_hash = BytecodeHash::for_bytecode(&[0; 96]).value();
+        let evm_hash = BytecodeHash::for_raw_evm_bytecode(&[0xfe; 40]).value();
+        let marked_as_known = |hash: H256, publish: bool| VmEvent {
+            address: KNOWN_CODES_STORAGE_ADDRESS,
+            indexed_topics: vec![
+                VmEvent::PUBLISHED_BYTECODE_SIGNATURE,
+                hash,
+                H256::from_low_u64_be(publish.into()),
+            ],
+            ..VmEvent::default()
+        };
+        let events = [
+            marked_as_known(era_hash, true),
+            marked_as_known(H256::repeat_byte(1), false),
+            marked_as_known(evm_hash, true),
+        ];
+
+        let published = VmEvent::extract_published_bytecodes_with_size(&events);
+        assert_eq!(published, [(era_hash, 96), (evm_hash, 40)]);
+
+        let mut result = VmExecutionResultAndLogs::mock_success();
+        result.logs.events = events.to_vec();
+        assert_eq!(
+            result.get_execution_metrics().published_bytecode_bytes,
+            96 + 40 + 2 * PUBLISH_BYTECODE_OVERHEAD as usize
+        );
+    }
+
+    fn mock_tx_execution_result(gas_limit: u64, refunded_gas: u64) -> TransactionExecutionResult {
+        let transaction = Transaction {
+            common_data: ExecuteTransactionCommon::L2(L2TxCommonData {
+                fee: Fee {
+                    gas_limit: gas_limit.into(),
+                    ..Fee::default()
+                },
+                ..L2TxCommonData::default()
+            }),
+            execute: Execute::default(),
+            received_timestamp_ms: 0,
+            raw_bytes: None,
+        };
+        TransactionExecutionResult {
+            transaction,
+            hash: H256::zero(),
+            execution_info: VmExecutionMetrics::default(),
+            execution_status: TxExecutionStatus::Success,
+            refunded_gas,
+            call_traces: vec![],
+            revert_reason: None,
+        }
+    }
+
+    #[test]
+    fn net_gas_cost_of_transaction() {
+        assert_eq!(mock_tx_execution_result(1_000, 300).net_gas_cost(), 700);
+        assert_eq!(mock_tx_execution_result(1_000, 0).net_gas_cost(), 1_000);
+        assert_eq!(mock_tx_execution_result(1_000, 1_500).net_gas_cost(), 0);
+    }
+
+    #[test]
+    fn aggregating_execution_metrics() {
+        assert_eq!(
+            VmExecutionMetrics::aggregate(&[]),
+            VmExecutionMetrics::default()
+        );
+
+        let metrics = |gas_used, vm_events, main_vm| VmExecutionMetrics {
+            gas_used,
+            vm_events,
+            circuit_statistic: CircuitStatistic {
+                main_vm,
+                ..CircuitStatistic::default()
+            },
+            ..VmExecutionMetrics::default()
+        };
+        let results: Vec<_> = [
+            metrics(100, 1, 0.5),
+            metrics(200, 0, 0.25),
+            metrics(50, 3, 0.0),
+        ]
+        .into_iter()
+        .map(|execution_info| TransactionExecutionResult {
+            execution_info,
+            ..mock_tx_execution_result(1_000, 0)
+        })
+        .collect();
+
+        assert_eq!(
+            VmExecutionMetrics::aggregate(&results[..1]),
+            results[0].execution_info
+        );
+        assert_eq!(
+            VmExecutionMetrics::aggregate(&results),
+            metrics(350, 4, 0.75)
+        );
+    }
+
+    #[test]
+    fn deduplicating_events() {
+        let event = VmEvent {
+            location: (L1BatchNumber(1), 2),
+            address: KNOWN_CODES_STORAGE_ADDRESS,
+            indexed_topics: vec![VmEvent::PUBLISHED_BYTECODE_SIGNATURE, H256::repeat_byte(1)],
+            value: vec![1, 2, 3],
+        };
+        let other_event = VmEvent {
+            value: vec![1, 2],
+            ..event.clone()
+        };
+        let events: HashSet<_> = [event.clone(), other_event.clone(), event.clone()]
+            .into_iter()
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert!(events.contains(&event));
+        assert!(events.contains(&other_event));
+    }
+
+    #[test]
+    fn adding_dynamic_factory_deps() {
+        let evm_bytecode = vec![0xfe; 40];
+        let evm_hash = BytecodeHash::for_raw_evm_bytecode(&evm_bytecode).value();
+        let padded_evm_bytecode = pad_evm_bytecode(&evm_bytecode);
+        let era_bytecode = vec![0; 32];
+        let era_hash = BytecodeHash::for_bytecode(&era_bytecode).value();
+
+        let result = VmExecutionResultAndLogs::mock_success()
+            .with_dynamic_factory_dep(evm_hash, padded_evm_bytecode.clone())
+            .with_dynamic_factory_dep(era_hash, era_bytecode.clone());
+        assert_eq!(
+            result.dynamic_factory_deps,
+            HashMap::from([
+                (evm_hash, padded_evm_bytecode.clone()),
+                (era_hash, era_bytecode.clone())
+            ])
+        );
+
+        let mut expected_hashes = vec![evm_hash, era_hash];
+        expected_hashes.sort_unstable();
+        assert_eq!(result.dynamic_factory_dep_hashes(), expected_hashes);
+        assert_eq!(
+            result.dynamic_factory_dep_sizes(),
+            HashMap::from([
+                (evm_hash, padded_evm_bytecode.len()),
+                (era_hash, era_bytecode.len())
+            ])
+        );
+        assert!(VmExecutionResultAndLogs::mock_success()
+            .dynamic_factory_dep_hashes()
+            .is_empty());
+    }
+
+    fn mock_revert() -> VmExecutionResultAndLogs {
+        VmExecutionResultAndLogs::mock(ExecutionResult::Revert {
+            output: VmRevertReason::General {
+                msg: "oops".to_owned(),
+                data: vec![],
+            },
+        })
+    }
+
+    #[test]
+    fn asserting_execution_outcome() {
+        VmExecutionResultAndLogs::mock_success().assert_success();
+        VmExecutionResultAndLogs::mock(ExecutionResult::Halt {
+            reason: Halt::FromIsNotAnAccount,
+        })
+        .assert_halted(&Halt::FromIsNotAnAccount);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected execution to succeed, but it was reverted: oops")]
+    fn asserting_success_for_reverted_execution() {
+        mock_revert().assert_success();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "expected execution to succeed, but it was halted: Sender is not an account"
+    )]
+    fn asserting_success_for_halted_execution() {
+        VmExecutionResultAndLogs::mock(ExecutionResult::Halt {
+            reason: Halt::FromIsNotAnAccount,
+        })
+        .assert_success();
+    }
+
+    #[test]
+    #[should_panic(expected = "but it was halted with `Bootloader-based tx failed`")]
+    fn asserting_halt_with_different_reason() {
+        VmExecutionResultAndLogs::mock(ExecutionResult::Halt {
+            reason: Halt::InnerTxError,
+        })
+        .assert_halted(&Halt::FromIsNotAnAccount);
+    }
+
+    #[test]
+    #[should_panic(expected = "but it succeeded")]
+    fn asserting_halt_for_successful_execution() {
+        VmExecutionResultAndLogs::mock_success().assert_halted(&Halt::FromIsNotAnAccount);
+    }
+
+    #[test]
+    #[should_panic(expected = "bytecode hash does not match the bytecode")]
+    fn adding_dynamic_factory_dep_with_mismatched_hash() {
+        let hash = BytecodeHash::for_raw_evm_bytecode(&[0xfe; 40]).value();
+        let other_bytecode = pad_evm_bytecode(&[0xfd; 40]);
+        VmExecutionResultAndLogs::mock_success().with_dynamic_factory_dep(hash, other_bytecode);
+    }
+
+    #[test]
+    fn applying_refunds() {
+        let mut result = VmExecutionResultAndLogs::mock_success();
+        result.statistics.gas_used = 1_000;
+        let refunds = Refunds {
+            gas_refunded: 300,
+            operator_suggested_refund: 250,
+        };
+        result.apply_refunds(refunds.clone());
+        assert_eq!(result.statistics.gas_used, 700);
+        assert_eq!(result.refunds, refunds);
+
+        // The operator-suggested refund is used if it's larger than the bootloader one.
+        let mut result = VmExecutionResultAndLogs::mock_success();
+        result.statistics.gas_used = 1_000;
+        result.apply_refunds(Refunds {
+            gas_refunded: 100,
+            operator_suggested_refund: 400,
+        });
+        assert_eq!(result.statistics.gas_used, 600);
+
+        result.apply_refunds(Refunds {
+            gas_refunded: 1_000,
+            operator_suggested_refund: 0,
+        });
+        assert_eq!(result.statistics.gas_used, 0);
+    }
 }
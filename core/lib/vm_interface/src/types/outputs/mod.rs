@@ -1,22 +1,26 @@
 use std::borrow::Cow;
 
 pub use self::{
-    bytecode::CompressedBytecodeInfo,
+    bytecode::{BytecodeCompressionMetrics, CompressedBytecodeInfo},
     execution_result::{
-        BatchTransactionExecutionResult, Call, CallType, ExecutionResult,
-        OneshotTransactionExecutionResult, Refunds, TransactionExecutionResult, TxExecutionStatus,
-        VmEvent, VmExecutionLogs, VmExecutionResultAndLogs,
+        AbiDecode, BatchTransactionExecutionResult, Call, CallErrorKind, CallIter, CallRecord,
+        CallType, DeployEvent, ExecutionResult, OneshotTransactionExecutionResult, ProxyKind,
+        Refunds, TransactionExecutionResult, TxExecutionStatus, VmEvent, VmExecutionLogs,
+        VmExecutionResultAndLogs, VmExecutionSummary,
     },
     execution_state::{BootloaderMemory, CurrentExecutionState},
     finished_l1batch::FinishedL1Batch,
     l2_block::L2Block,
     statistic::{
-        CircuitStatistic, DeduplicatedWritesMetrics, TransactionExecutionMetrics,
-        VmExecutionMetrics, VmExecutionStatistics, VmMemoryMetrics,
+        BatchLimits, CircuitStatistic, CircuitType, CostComponent, CostWeights,
+        DeduplicatedWritesMetrics, TransactionExecutionMetrics, VmExecutionMetrics,
+        VmExecutionStatistics, VmMemoryMetrics,
     },
 };
 
 mod bytecode;
+#[cfg(feature = "opentelemetry")]
+mod call_spans;
 mod execution_result;
 mod execution_state;
 mod finished_l1batch;
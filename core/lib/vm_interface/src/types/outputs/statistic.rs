@@ -1,4 +1,4 @@
-use std::ops;
+use std::{cmp::Ordering, ops};
 
 use serde::{Deserialize, Serialize};
 use zksync_types::{
@@ -8,9 +8,31 @@ use zksync_types::{
         InitialStorageWrite, RepeatedStorageWrite, BYTES_PER_DERIVED_KEY,
         BYTES_PER_ENUMERATION_INDEX,
     },
-    ProtocolVersionId,
+    ProtocolVersionId, U256,
 };
 
+/// Circuit type, corresponding to a field in [`CircuitStatistic`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CircuitType {
+    MainVm,
+    RamPermutation,
+    StorageApplication,
+    StorageSorter,
+    CodeDecommitter,
+    CodeDecommitterSorter,
+    LogDemuxer,
+    EventsSorter,
+    Keccak256,
+    Ecrecover,
+    Sha256,
+    Secp256k1Verify,
+    TransientStorageChecker,
+    Modexp,
+    Ecadd,
+    Ecmul,
+    Ecpairing,
+}
+
 /// Holds information about number of circuits used per circuit type.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 pub struct CircuitStatistic {
@@ -61,6 +83,71 @@ impl CircuitStatistic {
             + self.ecpairing as usize
     }
 
+    /// Returns circuit counts together with their types, in the declaration order of [`CircuitType`] variants.
+    fn counts_by_type(&self) -> [(CircuitType, f32); 17] {
+        [
+            (CircuitType::MainVm, self.main_vm),
+            (CircuitType::RamPermutation, self.ram_permutation),
+            (CircuitType::StorageApplication, self.storage_application),
+            (CircuitType::StorageSorter, self.storage_sorter),
+            (CircuitType::CodeDecommitter, self.code_decommitter),
+            (
+                CircuitType::CodeDecommitterSorter,
+                self.code_decommitter_sorter,
+            ),
+            (CircuitType::LogDemuxer, self.log_demuxer),
+            (CircuitType::EventsSorter, self.events_sorter),
+            (CircuitType::Keccak256, self.keccak256),
+            (CircuitType::Ecrecover, self.ecrecover),
+            (CircuitType::Sha256, self.sha256),
+            (CircuitType::Secp256k1Verify, self.secp256k1_verify),
+            (
+                CircuitType::TransientStorageChecker,
+                self.transient_storage_checker,
+            ),
+            (CircuitType::Modexp, self.modexp),
+            (CircuitType::Ecadd, self.ecadd),
+            (CircuitType::Ecmul, self.ecmul),
+            (CircuitType::Ecpairing, self.ecpairing),
+        ]
+    }
+
+    /// Returns the total number of circuits across all circuit types, rounding up the count for each type.
+    /// Unlike [`Self::total()`], which truncates counts for the `modexp`, `ecadd`, `ecmul` and `ecpairing` precompiles,
+    /// counts for all circuit types are rounded up.
+    pub fn total_circuits(&self) -> u64 {
+        self.counts_by_type()
+            .into_iter()
+            .map(|(_, count)| count.ceil() as u64)
+            .sum()
+    }
+
+    /// Returns the circuit type with the largest number of circuits, or `None` if all counts are zero.
+    /// If several types have the same count, returns the first one in the declaration order of [`CircuitType`]
+    /// variants.
+    pub fn dominant_type(&self) -> Option<CircuitType> {
+        let mut dominant = None;
+        let mut max_count = 0.0;
+        for (circuit_type, count) in self.counts_by_type() {
+            if count > max_count {
+                dominant = Some(circuit_type);
+                max_count = count;
+            }
+        }
+        dominant
+    }
+
+    /// Compares this statistic with `other` by circuit complexity, i.e. by [`Self::total_circuits()`], breaking ties
+    /// by the exact (non-rounded) total.
+    ///
+    /// This isn't an [`Ord`] implementation because the statistic consists of floats, and ordering by complexity
+    /// would be inconsistent with [`PartialEq`]: statistics with equal complexity are not necessarily equal.
+    pub fn cmp_by_complexity(&self, other: &Self) -> Ordering {
+        self.total_circuits()
+            .cmp(&other.total_circuits())
+            .then_with(|| self.total_f32().total_cmp(&other.total_f32()))
+    }
+
     /// Adds numbers.
     pub fn total_f32(&self) -> f32 {
         self.main_vm
@@ -129,6 +216,34 @@ pub struct VmExecutionStatistics {
     pub circuit_statistic: CircuitStatistic,
 }
 
+impl VmExecutionStatistics {
+    /// Returns the circuit type consuming the most circuits during execution, or `None` if no circuits were used.
+    /// See [`CircuitStatistic::dominant_type()`] for details.
+    pub fn circuit_dominant_type(&self) -> Option<CircuitType> {
+        self.circuit_statistic.dominant_type()
+    }
+
+    /// Returns the ratio of gas used to published pubdata bytes, or `None` if no pubdata was published.
+    pub fn gas_per_pubdata_byte(&self) -> Option<f64> {
+        (self.pubdata_published > 0)
+            .then(|| self.gas_used as f64 / f64::from(self.pubdata_published))
+    }
+
+    /// Returns the cost of computational gas used by the transaction in USD cents (rounded down), given the gas price
+    /// in wei and the ETH price in USD cents. Saturates to `U256::MAX` on overflow.
+    pub fn computational_cost(&self, gas_price_wei: U256, eth_price_usd_cents: u64) -> U256 {
+        const WEI_PER_ETHER: U256 = U256([1_000_000_000_000_000_000, 0, 0, 0]);
+
+        let cost_wei = gas_price_wei.saturating_mul(self.computational_gas_used.into());
+        let eth_price_usd_cents = U256::from(eth_price_usd_cents);
+        match cost_wei.checked_mul(eth_price_usd_cents) {
+            Some(cost) => cost / WEI_PER_ETHER,
+            // Divide first to lose some precision instead of overflowing.
+            None => (cost_wei / WEI_PER_ETHER).saturating_mul(eth_price_usd_cents),
+        }
+    }
+}
+
 /// Oracle metrics reported by legacy VMs.
 #[derive(Debug, Default)]
 pub struct VmMemoryMetrics {
@@ -200,7 +315,54 @@ impl Default for TransactionExecutionMetrics {
     }
 }
 
+/// Limits on resources consumed by an L1 batch, used by [`VmExecutionMetrics::dominant_cost()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchLimits {
+    pub gas: usize,
+    pub pubdata_bytes: usize,
+    pub vm_events: usize,
+    pub storage_logs: usize,
+    pub user_l2_to_l1_logs: usize,
+    pub circuits: usize,
+}
+
+/// Resource consumed by VM execution, as returned by [`VmExecutionMetrics::dominant_cost()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CostComponent {
+    Gas,
+    PubdataBytes,
+    VmEvents,
+    StorageLogs,
+    UserL2ToL1Logs,
+    Circuits,
+}
+
+/// Weights used to compute the cost of VM execution in [`VmExecutionMetrics::cost_weight()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostWeights {
+    /// Weight of a single unit of gas used.
+    pub gas: u64,
+    /// Weight of a single byte of published pubdata.
+    pub pubdata_byte: u64,
+    /// Weight of a single log (storage log, VM event or L2->L1 log).
+    pub log: u64,
+}
+
+impl Default for CostWeights {
+    fn default() -> Self {
+        // Roughly corresponds to gas costs on L1: 16 gas per calldata byte, 375 gas per emitted log.
+        Self {
+            gas: 1,
+            pubdata_byte: 16,
+            log: 375,
+        }
+    }
+}
+
 /// Metrics for a (part of) VM execution.
+///
+/// Serialized as a JSON object keyed by field names (e.g., with `serde_json::to_value()`), which is suitable for structured logs.
+/// This representation is persisted in Postgres as transaction execution info, so fields must not be renamed.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
 pub struct VmExecutionMetrics {
     pub gas_used: usize,
@@ -220,6 +382,11 @@ pub struct VmExecutionMetrics {
 }
 
 impl VmExecutionMetrics {
+    /// Checks whether all metrics are zero, i.e., these metrics are equal to [`Self::default()`].
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
     pub fn size(&self) -> usize {
         self.l2_to_l1_logs * L2ToL1Log::SERIALIZED_SIZE
             + self.l2_l1_long_messages
@@ -230,6 +397,121 @@ impl VmExecutionMetrics {
             // user L2->L1 log.
             + self.l2_to_l1_logs * 4
     }
+
+    /// Same as the [`Add`](ops::Add) implementation, but uses saturating arithmetic for all integer fields
+    /// instead of panicking (or wrapping) on overflow. Circuit statistics are added as is since they are floats.
+    #[must_use]
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self {
+            published_bytecode_bytes: self
+                .published_bytecode_bytes
+                .saturating_add(other.published_bytecode_bytes),
+            contracts_used: self.contracts_used.saturating_add(other.contracts_used),
+            l2_l1_long_messages: self
+                .l2_l1_long_messages
+                .saturating_add(other.l2_l1_long_messages),
+            l2_to_l1_logs: self.l2_to_l1_logs.saturating_add(other.l2_to_l1_logs),
+            user_l2_to_l1_logs: self
+                .user_l2_to_l1_logs
+                .saturating_add(other.user_l2_to_l1_logs),
+            gas_used: self.gas_used.saturating_add(other.gas_used),
+            vm_events: self.vm_events.saturating_add(other.vm_events),
+            storage_logs: self.storage_logs.saturating_add(other.storage_logs),
+            total_log_queries: self
+                .total_log_queries
+                .saturating_add(other.total_log_queries),
+            cycles_used: self.cycles_used.saturating_add(other.cycles_used),
+            computational_gas_used: self
+                .computational_gas_used
+                .saturating_add(other.computational_gas_used),
+            pubdata_published: self
+                .pubdata_published
+                .saturating_add(other.pubdata_published),
+            circuit_statistic: self.circuit_statistic + other.circuit_statistic,
+        }
+    }
+
+    /// Returns published pubdata bytes per storage log, or `None` if there are no storage logs.
+    pub fn pubdata_per_storage_log(&self) -> Option<f64> {
+        self.pubdata_per(self.storage_logs)
+    }
+
+    /// Returns published pubdata bytes per VM event, or `None` if there are no events.
+    pub fn pubdata_per_event(&self) -> Option<f64> {
+        self.pubdata_per(self.vm_events)
+    }
+
+    /// Returns published pubdata bytes per L2-to-L1 log, or `None` if there are no L2-to-L1 logs.
+    pub fn pubdata_per_l2_to_l1_log(&self) -> Option<f64> {
+        self.pubdata_per(self.l2_to_l1_logs)
+    }
+
+    fn pubdata_per(&self, count: usize) -> Option<f64> {
+        (count > 0).then(|| f64::from(self.pubdata_published) / count as f64)
+    }
+
+    /// Computes the weighted cost of the execution, combining gas, published pubdata and log counts.
+    pub fn cost_weight(&self, weights: &CostWeights) -> u64 {
+        let logs_count = self.storage_logs + self.vm_events + self.l2_to_l1_logs;
+        (self.gas_used as u64)
+            .saturating_mul(weights.gas)
+            .saturating_add(u64::from(self.pubdata_published).saturating_mul(weights.pubdata_byte))
+            .saturating_add((logs_count as u64).saturating_mul(weights.log))
+    }
+
+    /// Compares these metrics with `other` by their [weighted cost](Self::cost_weight()), e.g. to sort transactions
+    /// by resource consumption.
+    ///
+    /// This isn't an [`Ord`] implementation because ordering by cost would be inconsistent with [`PartialEq`]:
+    /// metrics with equal cost are not necessarily equal.
+    pub fn cmp_by_cost(&self, other: &Self, weights: &CostWeights) -> Ordering {
+        self.cost_weight(weights).cmp(&other.cost_weight(weights))
+    }
+
+    /// Returns the resource with the highest utilization relative to the provided `limits`, i.e. the one closest
+    /// to its limit. If several resources have the same utilization, returns the first one in the declaration order
+    /// of [`CostComponent`] variants. A zero limit is considered to be fully utilized by any non-zero value.
+    pub fn dominant_cost(&self, limits: &BatchLimits) -> CostComponent {
+        let components = [
+            (self.gas_used, limits.gas, CostComponent::Gas),
+            (
+                self.pubdata_published as usize,
+                limits.pubdata_bytes,
+                CostComponent::PubdataBytes,
+            ),
+            (self.vm_events, limits.vm_events, CostComponent::VmEvents),
+            (
+                self.storage_logs,
+                limits.storage_logs,
+                CostComponent::StorageLogs,
+            ),
+            (
+                self.user_l2_to_l1_logs,
+                limits.user_l2_to_l1_logs,
+                CostComponent::UserL2ToL1Logs,
+            ),
+            (
+                self.circuit_statistic.total(),
+                limits.circuits,
+                CostComponent::Circuits,
+            ),
+        ];
+
+        let mut dominant = CostComponent::Gas;
+        let mut max_utilization = f64::NEG_INFINITY;
+        for (value, limit, component) in components {
+            let utilization = match (value, limit) {
+                (0, _) => 0.0,
+                (_, 0) => f64::INFINITY,
+                _ => value as f64 / limit as f64,
+            };
+            if utilization > max_utilization {
+                dominant = component;
+                max_utilization = utilization;
+            }
+        }
+        dominant
+    }
 }
 
 impl ops::Add for VmExecutionMetrics {
@@ -260,3 +542,405 @@ impl ops::AddAssign for VmExecutionMetrics {
         *self = *self + other;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finding_dominant_circuit_type() {
+        let base_statistic = CircuitStatistic {
+            main_vm: 1.0,
+            ..CircuitStatistic::default()
+        };
+        let base_counts = base_statistic.counts_by_type();
+        for (i, (circuit_type, _)) in base_counts.into_iter().enumerate() {
+            let mut counts = base_counts.map(|(_, count)| count);
+            counts[i] += 1.5;
+            let statistic = statistic_from_counts(counts);
+            assert_eq!(statistic.total_f32(), 2.5);
+            assert_eq!(statistic.dominant_type(), Some(circuit_type));
+
+            let execution_statistic = VmExecutionStatistics {
+                circuit_statistic: statistic,
+                ..VmExecutionStatistics::default()
+            };
+            assert_eq!(
+                execution_statistic.circuit_dominant_type(),
+                Some(circuit_type)
+            );
+        }
+
+        assert_eq!(CircuitStatistic::default().dominant_type(), None);
+        assert_eq!(
+            VmExecutionStatistics::default().circuit_dominant_type(),
+            None
+        );
+        // Ties are resolved in favor of the first type.
+        let statistic = CircuitStatistic {
+            keccak256: 2.0,
+            sha256: 2.0,
+            ..base_statistic
+        };
+        assert_eq!(statistic.dominant_type(), Some(CircuitType::Keccak256));
+    }
+
+    fn statistic_from_counts(counts: [f32; 17]) -> CircuitStatistic {
+        CircuitStatistic {
+            main_vm: counts[0],
+            ram_permutation: counts[1],
+            storage_application: counts[2],
+            storage_sorter: counts[3],
+            code_decommitter: counts[4],
+            code_decommitter_sorter: counts[5],
+            log_demuxer: counts[6],
+            events_sorter: counts[7],
+            keccak256: counts[8],
+            ecrecover: counts[9],
+            sha256: counts[10],
+            secp256k1_verify: counts[11],
+            transient_storage_checker: counts[12],
+            modexp: counts[13],
+            ecadd: counts[14],
+            ecmul: counts[15],
+            ecpairing: counts[16],
+        }
+    }
+
+    #[test]
+    fn comparing_circuit_statistics_by_complexity() {
+        let simple = CircuitStatistic {
+            main_vm: 0.5,
+            ram_permutation: 0.2,
+            ..CircuitStatistic::default()
+        };
+        let with_precompile = CircuitStatistic {
+            main_vm: 0.5,
+            ecpairing: 0.1,
+            ..CircuitStatistic::default()
+        };
+        assert_eq!(simple.total_circuits(), 2);
+        assert_eq!(with_precompile.total_circuits(), 2);
+        assert_eq!(CircuitStatistic::default().total_circuits(), 0);
+
+        assert_eq!(
+            simple.cmp_by_complexity(&with_precompile),
+            Ordering::Greater
+        );
+        assert_eq!(simple.cmp_by_complexity(&simple), Ordering::Equal);
+        let complex = CircuitStatistic {
+            main_vm: 2.1,
+            ..CircuitStatistic::default()
+        };
+        assert_eq!(simple.cmp_by_complexity(&complex), Ordering::Less);
+
+        let mut stats = vec![complex, simple, CircuitStatistic::default()];
+        stats.sort_by(CircuitStatistic::cmp_by_complexity);
+        assert_eq!(stats, [CircuitStatistic::default(), simple, complex]);
+    }
+
+    #[test]
+    fn computing_gas_per_pubdata_byte() {
+        let mut stats = VmExecutionStatistics {
+            gas_used: 1_000,
+            ..VmExecutionStatistics::default()
+        };
+        assert_eq!(stats.gas_per_pubdata_byte(), None);
+
+        stats.pubdata_published = 400;
+        assert_eq!(stats.gas_per_pubdata_byte(), Some(2.5));
+        stats.gas_used = 0;
+        assert_eq!(stats.gas_per_pubdata_byte(), Some(0.0));
+    }
+
+    #[test]
+    fn computing_computational_cost() {
+        let mut stats = VmExecutionStatistics {
+            computational_gas_used: 1_000_000,
+            ..VmExecutionStatistics::default()
+        };
+        // 1M gas at 0.25 gwei is 0.00025 ETH, i.e. 75 cents at $3,000 per ETH.
+        let gas_price = U256::from(250_000_000);
+        assert_eq!(stats.computational_cost(gas_price, 300_000), U256::from(75));
+        // Costs are rounded down.
+        assert_eq!(stats.computational_cost(gas_price, 3_999), U256::zero());
+        assert_eq!(
+            stats.computational_cost(U256::zero(), 300_000),
+            U256::zero()
+        );
+        assert_eq!(stats.computational_cost(gas_price, 0), U256::zero());
+
+        stats.computational_gas_used = u32::MAX;
+        assert_eq!(stats.computational_cost(U256::MAX, u64::MAX), U256::MAX);
+        assert_eq!(
+            stats.computational_cost(U256::MAX, 1),
+            U256::MAX / U256::exp10(18)
+        );
+        assert_eq!(stats.computational_cost(U256::MAX, 0), U256::zero());
+
+        stats.computational_gas_used = 0;
+        assert_eq!(stats.computational_cost(U256::MAX, u64::MAX), U256::zero());
+    }
+
+    fn mock_metrics(seed: usize) -> VmExecutionMetrics {
+        VmExecutionMetrics {
+            gas_used: seed * 1_000,
+            published_bytecode_bytes: seed * 2,
+            l2_l1_long_messages: seed * 3,
+            l2_to_l1_logs: seed,
+            user_l2_to_l1_logs: seed,
+            contracts_used: seed,
+            vm_events: seed * 4,
+            storage_logs: seed * 5,
+            total_log_queries: seed * 6,
+            cycles_used: seed as u32 * 7,
+            computational_gas_used: seed as u32 * 8,
+            pubdata_published: seed as u32 * 9,
+            circuit_statistic: CircuitStatistic {
+                main_vm: seed as f32 * 0.5,
+                keccak256: seed as f32 * 0.25,
+                ..CircuitStatistic::default()
+            },
+        }
+    }
+
+    #[test]
+    fn serializing_metrics_to_json() {
+        let json = serde_json::to_value(mock_metrics(1)).unwrap();
+        let fields = json.as_object().unwrap();
+        let mut field_names: Vec<_> = fields.keys().map(String::as_str).collect();
+        field_names.sort_unstable();
+        let mut expected_names = vec![
+            "gas_used",
+            "published_bytecode_bytes",
+            "l2_l1_long_messages",
+            "l2_to_l1_logs",
+            "user_l2_to_l1_logs",
+            "contracts_used",
+            "vm_events",
+            "storage_logs",
+            "total_log_queries",
+            "cycles_used",
+            "computational_gas_used",
+            "pubdata_published",
+            "circuit_statistic",
+        ];
+        expected_names.sort_unstable();
+        assert_eq!(field_names, expected_names);
+
+        assert_eq!(fields["gas_used"], 1_000);
+        assert_eq!(fields["pubdata_published"], 9);
+        assert_eq!(fields["circuit_statistic"]["main_vm"], 0.5);
+        assert_eq!(fields["circuit_statistic"]["ecpairing"], 0.0);
+    }
+
+    #[test]
+    fn saturating_addition_of_metrics() {
+        let (first, second) = (mock_metrics(1), mock_metrics(3));
+        assert_eq!(first.saturating_add(second), first + second);
+
+        let half = usize::MAX / 2 + 1;
+        let half_u32 = u32::MAX / 2 + 1;
+        let large = VmExecutionMetrics {
+            gas_used: half,
+            published_bytecode_bytes: half,
+            l2_l1_long_messages: half,
+            l2_to_l1_logs: half,
+            user_l2_to_l1_logs: half,
+            contracts_used: half,
+            vm_events: half,
+            storage_logs: half,
+            total_log_queries: half,
+            cycles_used: half_u32,
+            computational_gas_used: half_u32,
+            pubdata_published: half_u32,
+            circuit_statistic: CircuitStatistic {
+                main_vm: 1.0,
+                ..CircuitStatistic::default()
+            },
+        };
+        let sum = large.saturating_add(large);
+        assert_eq!(
+            sum,
+            VmExecutionMetrics {
+                gas_used: usize::MAX,
+                published_bytecode_bytes: usize::MAX,
+                l2_l1_long_messages: usize::MAX,
+                l2_to_l1_logs: usize::MAX,
+                user_l2_to_l1_logs: usize::MAX,
+                contracts_used: usize::MAX,
+                vm_events: usize::MAX,
+                storage_logs: usize::MAX,
+                total_log_queries: usize::MAX,
+                cycles_used: u32::MAX,
+                computational_gas_used: u32::MAX,
+                pubdata_published: u32::MAX,
+                circuit_statistic: CircuitStatistic {
+                    main_vm: 2.0,
+                    ..CircuitStatistic::default()
+                },
+            }
+        );
+        assert_eq!(sum.saturating_add(large), sum);
+    }
+
+    #[test]
+    fn adding_metrics() {
+        let (first, second) = (mock_metrics(1), mock_metrics(3));
+        assert_eq!(first + second, second + first);
+        assert_eq!(first + second, mock_metrics(4));
+        assert_eq!(first + VmExecutionMetrics::default(), first);
+        assert_eq!(VmExecutionMetrics::default() + first, first);
+
+        let mut aggregate = VmExecutionMetrics::default();
+        for metrics in [first, second] {
+            aggregate += metrics;
+        }
+        assert_eq!(aggregate, first + second);
+    }
+
+    #[test]
+    fn empty_metrics() {
+        assert!(VmExecutionMetrics::default().is_empty());
+        assert!(!mock_metrics(1).is_empty());
+
+        let metrics = VmExecutionMetrics::default()
+            + VmExecutionMetrics {
+                vm_events: 1,
+                ..VmExecutionMetrics::default()
+            };
+        assert!(!metrics.is_empty());
+        let metrics = VmExecutionMetrics {
+            circuit_statistic: CircuitStatistic {
+                ecrecover: 0.5,
+                ..CircuitStatistic::default()
+            },
+            ..VmExecutionMetrics::default()
+        };
+        assert!(!metrics.is_empty());
+    }
+
+    #[test]
+    fn computing_pubdata_ratios() {
+        // Values are typical for an L1 batch with several token transfers.
+        let metrics = VmExecutionMetrics {
+            pubdata_published: 1_870,
+            storage_logs: 170,
+            vm_events: 34,
+            l2_to_l1_logs: 4,
+            ..VmExecutionMetrics::default()
+        };
+        assert_eq!(metrics.pubdata_per_storage_log(), Some(11.0));
+        assert_eq!(metrics.pubdata_per_event(), Some(55.0));
+        assert_eq!(metrics.pubdata_per_l2_to_l1_log(), Some(467.5));
+
+        let empty_metrics = VmExecutionMetrics::default();
+        assert_eq!(empty_metrics.pubdata_per_storage_log(), None);
+        assert_eq!(empty_metrics.pubdata_per_event(), None);
+        assert_eq!(empty_metrics.pubdata_per_l2_to_l1_log(), None);
+        let metrics = VmExecutionMetrics {
+            storage_logs: 10,
+            ..VmExecutionMetrics::default()
+        };
+        assert_eq!(metrics.pubdata_per_storage_log(), Some(0.0));
+    }
+
+    #[test]
+    fn finding_dominant_cost() {
+        let limits = BatchLimits {
+            gas: 1_000,
+            pubdata_bytes: 100,
+            vm_events: 10,
+            storage_logs: 10,
+            user_l2_to_l1_logs: 5,
+            circuits: 4,
+        };
+        let base_metrics = VmExecutionMetrics {
+            gas_used: 100,
+            pubdata_published: 10,
+            vm_events: 1,
+            storage_logs: 1,
+            user_l2_to_l1_logs: 0,
+            ..VmExecutionMetrics::default()
+        };
+        // All components have the same utilization, so the first one is returned.
+        assert_eq!(base_metrics.dominant_cost(&limits), CostComponent::Gas);
+        assert_eq!(
+            VmExecutionMetrics::default().dominant_cost(&limits),
+            CostComponent::Gas
+        );
+
+        let mut metrics = base_metrics;
+        metrics.gas_used = 200;
+        assert_eq!(metrics.dominant_cost(&limits), CostComponent::Gas);
+        let mut metrics = base_metrics;
+        metrics.pubdata_published = 20;
+        assert_eq!(metrics.dominant_cost(&limits), CostComponent::PubdataBytes);
+        let mut metrics = base_metrics;
+        metrics.vm_events = 2;
+        assert_eq!(metrics.dominant_cost(&limits), CostComponent::VmEvents);
+        let mut metrics = base_metrics;
+        metrics.storage_logs = 2;
+        assert_eq!(metrics.dominant_cost(&limits), CostComponent::StorageLogs);
+        let mut metrics = base_metrics;
+        metrics.user_l2_to_l1_logs = 1;
+        assert_eq!(
+            metrics.dominant_cost(&limits),
+            CostComponent::UserL2ToL1Logs
+        );
+        let mut metrics = base_metrics;
+        metrics.circuit_statistic.main_vm = 1.5;
+        assert_eq!(metrics.dominant_cost(&limits), CostComponent::Circuits);
+
+        // Exceeding a zero limit dominates any finite utilization.
+        let mut metrics = base_metrics;
+        metrics.gas_used = 1_000_000;
+        metrics.user_l2_to_l1_logs = 1;
+        let zero_logs_limit = BatchLimits {
+            user_l2_to_l1_logs: 0,
+            ..limits
+        };
+        assert_eq!(
+            metrics.dominant_cost(&zero_logs_limit),
+            CostComponent::UserL2ToL1Logs
+        );
+    }
+
+    #[test]
+    fn comparing_metrics_by_cost() {
+        let weights = CostWeights::default();
+        let gas_heavy = VmExecutionMetrics {
+            gas_used: 100_000,
+            ..VmExecutionMetrics::default()
+        };
+        let pubdata_heavy = VmExecutionMetrics {
+            gas_used: 10_000,
+            pubdata_published: 10_000,
+            vm_events: 2,
+            ..VmExecutionMetrics::default()
+        };
+        assert_eq!(gas_heavy.cost_weight(&weights), 100_000);
+        assert_eq!(pubdata_heavy.cost_weight(&weights), 10_000 + 160_000 + 750);
+        assert_eq!(
+            gas_heavy.cmp_by_cost(&pubdata_heavy, &weights),
+            Ordering::Less
+        );
+
+        let gas_only_weights = CostWeights {
+            gas: 1,
+            pubdata_byte: 0,
+            log: 0,
+        };
+        assert_eq!(
+            gas_heavy.cmp_by_cost(&pubdata_heavy, &gas_only_weights),
+            Ordering::Greater
+        );
+
+        let mut metrics = vec![pubdata_heavy, VmExecutionMetrics::default(), gas_heavy];
+        metrics.sort_by(|a, b| a.cmp_by_cost(b, &weights));
+        assert_eq!(
+            metrics,
+            [VmExecutionMetrics::default(), gas_heavy, pubdata_heavy]
+        );
+    }
+}
@@ -45,6 +45,7 @@ impl From<Halt> for SandboxExecutionError {
                 Self::PrePaymasterPreparationFailed(reason.to_string())
             }
             Halt::UnexpectedVMBehavior(reason) => Self::UnexpectedVMBehavior(reason),
+            Halt::BootloaderInternalError(err) => Self::UnexpectedVMBehavior(err.to_string()),
             Halt::BootloaderOutOfGas => {
                 Self::UnexpectedVMBehavior("bootloader is out of gas".to_string())
             }
@@ -58,10 +59,13 @@ impl From<Halt> for SandboxExecutionError {
             Halt::TooBigGasLimit => Self::Revert(Halt::TooBigGasLimit.to_string(), vec![]),
             Halt::MissingInvocationLimitReached => Self::InnerTxError,
             Halt::VMPanic => Self::UnexpectedVMBehavior("VM panic".to_string()),
-            Halt::FailedToSetL2Block(reason) => SandboxExecutionError::Revert(reason, vec![]),
-            Halt::FailedToAppendTransactionToL2Block(reason) => {
-                SandboxExecutionError::Revert(reason, vec![])
+            Halt::FailedToSetL2Block(reason) => {
+                SandboxExecutionError::Revert(reason.to_string(), vec![])
             }
+            Halt::FailedToAppendTransactionToL2Block(reason) => SandboxExecutionError::Revert(
+                format!("Failed to append transaction to L2 block: {reason}"),
+                vec![],
+            ),
             Halt::TracerCustom(reason) => SandboxExecutionError::Revert(reason, vec![]),
             Halt::ValidationOutOfGas => Self::AccountValidationFailed(
                 "The validation of the transaction ran out of gas".to_string(),
@@ -70,6 +74,9 @@ impl From<Halt> for SandboxExecutionError {
                 Self::UnexpectedVMBehavior("Failed to publish compressed bytecodes".to_string())
             }
             Halt::FailedBlockTimestampAssertion => Self::FailedBlockTimestampAssertion,
+            halt @ Halt::UnauthorizedDeployment(_) => Self::Revert(halt.to_string(), vec![]),
+            // `Halt` is non-exhaustive; variants without a dedicated error are reported as reverts.
+            halt => Self::Revert(halt.to_string(), vec![]),
         }
     }
 }
@@ -730,9 +730,9 @@ fn map_event(vm_event: VmEvent, tx_hash: H256) -> api::Log {
         data: web3::Bytes::from(vm_event.value),
         block_hash: None,
         block_number: None,
-        l1_batch_number: Some(U64::from(vm_event.location.0 .0)),
+        l1_batch_number: Some(U64::from(vm_event.location_batch().0)),
         transaction_hash: Some(tx_hash),
-        transaction_index: Some(web3::Index::from(vm_event.location.1)),
+        transaction_index: Some(web3::Index::from(vm_event.location_tx_index())),
         log_index: None,
         transaction_log_index: None,
         log_type: None,
@@ -120,7 +120,7 @@ pub(crate) fn convert_vm_events_to_log_queries(events: &[VmEvent]) -> Vec<LogQue
             // `timestamp`, `aux_byte`, `read_value`, `rw_flag`, `rollback` are set as per convention.
             let first_log = LogQuery {
                 timestamp: Timestamp(0),
-                tx_number_in_block: event.location.1 as u16,
+                tx_number_in_block: event.location_tx_index() as u16,
                 aux_byte: 0,
                 shard_id: 0,
                 address: EVENT_WRITER_ADDRESS,
@@ -154,7 +154,7 @@ pub(crate) fn convert_vm_events_to_log_queries(events: &[VmEvent]) -> Vec<LogQue
 
                 LogQuery {
                     timestamp: Timestamp(0),
-                    tx_number_in_block: event.location.1 as u16,
+                    tx_number_in_block: event.location_tx_index() as u16,
                     aux_byte: 0,
                     shard_id: 0,
                     address: EVENT_WRITER_ADDRESS,
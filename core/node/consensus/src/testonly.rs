@@ -284,6 +284,7 @@ impl StateKeeper {
                         virtual_blocks: 1,
                     },
                     pubdata_params: Default::default(),
+                    whitelisted_deployers: None,
                 },
                 number: self.last_batch,
                 first_l2_block_number: self.last_block,
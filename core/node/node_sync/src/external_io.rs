@@ -257,6 +257,8 @@ impl StateKeeperIO for ExternalIO {
                 &pending_l2_block_header,
                 super::VALIDATION_COMPUTATIONAL_GAS_LIMIT,
                 self.chain_id,
+                // The external node doesn't restrict deployments; it follows the main node
+                None,
             )
             .await
             .with_context(|| {
@@ -532,6 +534,7 @@ mod tests {
                 virtual_blocks: 1,
             },
             pubdata_params: Default::default(),
+            whitelisted_deployers: None,
         };
         actions_sender
             .push_action_unchecked(SyncAction::OpenBatch {
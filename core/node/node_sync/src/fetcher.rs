@@ -177,6 +177,7 @@ impl IoCursorExt for IoCursor {
                         virtual_blocks: block.virtual_blocks,
                     },
                     pubdata_params: block.pubdata_params,
+                    whitelisted_deployers: None,
                 },
                 number: block.l1_batch_number,
                 first_l2_block_number: block.number,
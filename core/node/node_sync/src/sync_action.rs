@@ -199,6 +199,7 @@ mod tests {
                     virtual_blocks: 1,
                 },
                 pubdata_params: Default::default(),
+                whitelisted_deployers: None,
             },
             number: L1BatchNumber(1),
             first_l2_block_number: L2BlockNumber(1),
@@ -45,6 +45,7 @@ fn open_l1_batch(number: u32, timestamp: u64, first_l2_block_number: u32) -> Syn
                 virtual_blocks: 1,
             },
             pubdata_params: Default::default(),
+            whitelisted_deployers: None,
         },
         number: L1BatchNumber(number),
         first_l2_block_number: L2BlockNumber(first_l2_block_number),
@@ -69,6 +70,7 @@ impl MockMainNodeClient {
             hash: Some(snapshot.l2_block_hash),
             protocol_version: ProtocolVersionId::latest(),
             pubdata_params: Default::default(),
+            whitelisted_deployers: None,
         };
 
         Self {
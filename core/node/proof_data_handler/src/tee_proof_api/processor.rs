@@ -128,6 +128,7 @@ impl RequestProcessor {
                 l1_batch_number,
                 validation_computational_gas_limit,
                 self.l2_chain_id,
+                None,
             )
             .await
             .map_err(|err| RequestProcessorError::GeneralError(err.to_string()))?
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use assert_matches::assert_matches;
 use rand::{thread_rng, Rng};
 use test_casing::{test_casing, Product};
@@ -534,6 +536,48 @@ async fn deploy_failedcall(vm_mode: FastVmMode) {
     executor.finish_batch().await.unwrap();
 }
 
+/// Checks that contract deployments from non-whitelisted addresses are rejected.
+#[test_casing(3, FAST_VM_MODES)]
+#[tokio::test]
+async fn deploy_from_non_whitelisted_deployer(vm_mode: FastVmMode) {
+    let connection_pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let mut alice = Account::random();
+    let mut bob = Account::random();
+
+    let mut tester = Tester::with_config(
+        connection_pool,
+        TestConfig {
+            whitelisted_deployers: Some(HashSet::from([alice.address()])),
+            ..TestConfig::new(vm_mode)
+        },
+    );
+    tester.genesis().await;
+    tester.fund(&[alice.address(), bob.address()]).await;
+    let mut executor = tester
+        .create_batch_executor(StorageType::AsyncRocksdbCache)
+        .await;
+
+    let res = executor
+        .execute_tx(bob.deploy_failed_call_tx().tx)
+        .await
+        .unwrap();
+    assert_matches!(
+        res.tx_result.result,
+        ExecutionResult::Halt {
+            reason: Halt::UnauthorizedDeployment(deployer)
+        } if deployer == bob.address()
+    );
+    assert!(res.call_traces.is_empty());
+    executor.rollback_last_tx().await.unwrap();
+
+    let res = executor
+        .execute_tx(alice.deploy_failed_call_tx().tx)
+        .await
+        .unwrap();
+    assert_executed(&res);
+    executor.finish_batch().await.unwrap();
+}
+
 /// Checks that a tx that is reverted by the VM still can be included into a batch.
 #[test_casing(3, FAST_VM_MODES)]
 #[tokio::test]
@@ -683,6 +727,7 @@ async fn bootloader_out_of_gas_for_any_tx(vm_mode: FastVmMode) {
             trace_calls: false,
             vm_gas_limit: Some(10),
             validation_computational_gas_limit: u32::MAX,
+            whitelisted_deployers: None,
             fast_vm_mode: vm_mode,
         },
     );
@@ -734,6 +779,7 @@ async fn bootloader_tip_out_of_gas() {
                 - 10,
         ),
         validation_computational_gas_limit: u32::MAX,
+        whitelisted_deployers: None,
         fast_vm_mode: FastVmMode::Old,
     });
 
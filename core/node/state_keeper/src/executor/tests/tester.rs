@@ -1,7 +1,11 @@
 //! Testing harness for the batch executor.
 //! Contains helper functionality to initialize test context and perform tests without too much boilerplate.
 
-use std::{collections::HashMap, fmt::Debug, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    sync::Arc,
+};
 
 use assert_matches::assert_matches;
 use tempfile::TempDir;
@@ -55,6 +59,7 @@ pub(super) struct TestConfig {
     pub(super) trace_calls: bool,
     pub(super) vm_gas_limit: Option<u32>,
     pub(super) validation_computational_gas_limit: u32,
+    pub(super) whitelisted_deployers: Option<HashSet<Address>>,
     pub(super) fast_vm_mode: FastVmMode,
 }
 
@@ -66,6 +71,7 @@ impl TestConfig {
             trace_calls: false,
             vm_gas_limit: None,
             validation_computational_gas_limit: config.validation_computational_gas_limit,
+            whitelisted_deployers: None,
             fast_vm_mode,
         }
     }
@@ -269,6 +275,7 @@ impl Tester {
         }
         system_params.default_validation_computational_gas_limit =
             self.config.validation_computational_gas_limit;
+        system_params.whitelisted_deployers = self.config.whitelisted_deployers.clone();
         let mut batch_params = default_l1_batch_env(l1_batch_number.0, timestamp, self.fee_account);
         batch_params.previous_batch_hash = Some(H256::zero()); // Not important in this context.
         (batch_params, system_params, PubdataParams::default())
@@ -324,6 +324,7 @@ async fn loading_pending_batch_with_genesis() {
             L1BatchNumber(1),
             u32::MAX,
             L2ChainId::default(),
+            None,
         )
         .await
         .unwrap()
@@ -406,6 +407,7 @@ async fn loading_pending_batch_after_snapshot_recovery() {
             snapshot_recovery.l1_batch_number + 1,
             u32::MAX,
             L2ChainId::default(),
+            None,
         )
         .await
         .unwrap()
@@ -1,6 +1,6 @@
 use std::{
     cmp,
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     sync::Arc,
     time::{Duration, Instant},
 };
@@ -52,6 +52,7 @@ pub struct MempoolIO {
     l1_batch_params_provider: L1BatchParamsProvider,
     fee_account: Address,
     validation_computational_gas_limit: u32,
+    whitelisted_deployers: Option<HashSet<Address>>,
     max_allowed_tx_gas_limit: U256,
     delay_interval: Duration,
     // Used to keep track of gas prices to set accepted price per pubdata byte in blocks.
@@ -101,6 +102,8 @@ impl StateKeeperIO for MempoolIO {
 
         L2BlockSealProcess::clear_pending_l2_block(&mut storage, cursor.next_l2_block - 1).await?;
 
+        // Deployments are not restricted for the pending batch. Its transactions were already sealed, so they must
+        // be re-executed the same way as before the restart, even if the whitelist has changed since then.
         let Some((system_env, l1_batch_env, pubdata_params)) = self
             .l1_batch_params_provider
             .load_l1_batch_env(
@@ -108,6 +111,7 @@ impl StateKeeperIO for MempoolIO {
                 cursor.l1_batch,
                 self.validation_computational_gas_limit,
                 self.chain_id,
+                None,
             )
             .await?
         else {
@@ -176,6 +180,7 @@ impl StateKeeperIO for MempoolIO {
                     virtual_blocks: 1,
                 },
                 pubdata_params: self.pubdata_params(protocol_version)?,
+                whitelisted_deployers: self.whitelisted_deployers.clone(),
             }));
         }
 
@@ -262,6 +267,7 @@ impl StateKeeperIO for MempoolIO {
                     virtual_blocks: 1,
                 },
                 pubdata_params: self.pubdata_params(protocol_version)?,
+                whitelisted_deployers: self.whitelisted_deployers.clone(),
             }));
         }
         Ok(None)
@@ -519,6 +525,8 @@ impl MempoolIO {
             l1_batch_params_provider: L1BatchParamsProvider::uninitialized(),
             fee_account,
             validation_computational_gas_limit: config.validation_computational_gas_limit,
+            whitelisted_deployers: (!config.whitelisted_deployers.is_empty())
+                .then(|| config.whitelisted_deployers.iter().copied().collect()),
             max_allowed_tx_gas_limit: config.max_allowed_l2_tx_gas_limit.into(),
             delay_interval,
             batch_fee_input_provider,
@@ -1,4 +1,4 @@
-use std::{fmt, time::Duration};
+use std::{collections::HashSet, fmt, time::Duration};
 
 use async_trait::async_trait;
 use zksync_contracts::BaseSystemContracts;
@@ -74,6 +74,8 @@ pub struct L1BatchParams {
     pub first_l2_block: L2BlockParams,
     /// Params related to how the pubdata should be processed by the bootloader in the batch.
     pub pubdata_params: PubdataParams,
+    /// Addresses allowed to deploy contracts in L2 transactions. If not set, deployments are not restricted.
+    pub whitelisted_deployers: Option<HashSet<Address>>,
 }
 
 impl L1BatchParams {
@@ -97,6 +99,7 @@ impl L1BatchParams {
             self.protocol_version,
             self.first_l2_block.virtual_blocks,
             chain_id,
+            self.whitelisted_deployers,
         );
 
         (system_env, l1_batch_env, self.pubdata_params)
@@ -3,7 +3,7 @@ use async_trait::async_trait;
 use once_cell::sync::Lazy;
 use zksync_dal::{Connection, Core, CoreDal};
 use zksync_multivm::interface::VmEvent;
-use zksync_system_constants::{CONTRACT_DEPLOYER_ADDRESS, L2_NATIVE_TOKEN_VAULT_ADDRESS};
+use zksync_system_constants::L2_NATIVE_TOKEN_VAULT_ADDRESS;
 use zksync_types::{
     ethabi, h256_to_address,
     tokens::{TokenInfo, TokenMetadata},
@@ -22,14 +22,9 @@ fn extract_added_tokens(
 ) -> Vec<TokenInfo> {
     let deployed_tokens = all_generated_events
         .iter()
-        .filter(|event| {
-            // Filter events from the deployer contract that match the expected signature.
-            event.address == CONTRACT_DEPLOYER_ADDRESS
-                && event.indexed_topics.len() == 4
-                && event.indexed_topics[0] == VmEvent::DEPLOY_EVENT_SIGNATURE
-                && h256_to_address(&event.indexed_topics[1]) == l2_token_deployer_addr
-        })
-        .map(|event| h256_to_address(&event.indexed_topics[3]));
+        .filter_map(VmEvent::decode_deploy_event)
+        .filter(|event| event.deployer == l2_token_deployer_addr)
+        .map(|event| event.contract_address);
 
     extract_added_token_info_from_addresses(all_generated_events, deployed_tokens)
 }
@@ -430,7 +430,7 @@ impl L2BlockSealCommand {
         };
 
         for event in &self.l2_block.events {
-            let tx_index = event.location.1 as usize;
+            let tx_index = event.location_tx_index() as usize;
             anyhow::ensure!(
                 tx_index_range.contains(&tx_index),
                 "event transaction index {tx_index} is outside of the expected range {tx_index_range:?}"
@@ -454,7 +454,9 @@ impl L2BlockSealCommand {
     }
 
     fn extract_events(&self, is_fictive: bool) -> Vec<(IncludedTxLocation, Vec<&VmEvent>)> {
-        self.group_by_tx_location(&self.l2_block.events, is_fictive, |event| event.location.1)
+        self.group_by_tx_location(&self.l2_block.events, is_fictive, |event| {
+            event.location_tx_index()
+        })
     }
 
     fn group_by_tx_location<'a, T>(
@@ -1,4 +1,7 @@
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{
+    collections::HashSet,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use test_casing::test_casing;
 use zksync_contracts::BaseSystemContractsHashes;
@@ -148,6 +151,57 @@ async fn test_filter_with_no_pending_batch(commitment_mode: L1BatchCommitmentMod
     assert_eq!(mempool.filter(), &want_filter);
 }
 
+/// Ensure that deployer whitelist is applied to new batches, but not to the pending batch re-executed after a restart.
+#[tokio::test]
+async fn whitelisted_deployers_are_not_applied_to_pending_batch() {
+    let connection_pool = ConnectionPool::<Core>::constrained_test_pool(1).await;
+    let mut tester = Tester::new(L1BatchCommitmentMode::Rollup);
+    let deployer = Address::repeat_byte(0x23);
+    tester.set_whitelisted_deployers(vec![deployer]);
+    tester.genesis(&connection_pool).await;
+    let tx_result = tester
+        .insert_l2_block(&connection_pool, 1, 5, BatchFeeInput::l1_pegged(55, 555))
+        .await;
+    tester
+        .insert_sealed_batch(&connection_pool, 1, &[tx_result])
+        .await;
+
+    let (mut mempool, mut guard) = tester.create_test_mempool_io(connection_pool.clone()).await;
+    let (io_cursor, pending_batch) = mempool.initialize().await.unwrap();
+    assert!(pending_batch.is_none());
+    let tx_filter = l2_tx_filter(
+        &tester.create_batch_fee_input_provider().await,
+        ProtocolVersionId::latest().into(),
+    )
+    .await
+    .unwrap();
+    tester.insert_tx(
+        &mut guard,
+        tx_filter.fee_per_gas,
+        tx_filter.gas_per_pubdata,
+        TransactionTimeRangeConstraint::default(),
+    );
+    let l1_batch_params = mempool
+        .wait_for_new_batch_params(&io_cursor, Duration::from_secs(10))
+        .await
+        .unwrap()
+        .expect("No batch params in the test mempool");
+    assert_eq!(
+        l1_batch_params.whitelisted_deployers,
+        Some(HashSet::from([deployer]))
+    );
+
+    // Insert a pending L2 block and emulate node restart.
+    tester.set_timestamp(2);
+    tester
+        .insert_l2_block(&connection_pool, 2, 10, BatchFeeInput::l1_pegged(55, 555))
+        .await;
+    let (mut mempool, _) = tester.create_test_mempool_io(connection_pool).await;
+    let (_, pending_batch) = mempool.initialize().await.unwrap();
+    let pending_batch = pending_batch.expect("no pending batch");
+    assert_eq!(pending_batch.system_env.whitelisted_deployers, None);
+}
+
 async fn test_timestamps_are_distinct(
     connection_pool: ConnectionPool<Core>,
     prev_l2_block_timestamp: u64,
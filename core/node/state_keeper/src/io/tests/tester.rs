@@ -32,8 +32,8 @@ use zksync_types::{
     protocol_version::{L1VerifierConfig, ProtocolSemanticVersion},
     pubdata_da::PubdataSendingMode,
     system_contracts::get_system_smart_contracts,
-    L2BlockNumber, L2ChainId, PriorityOpId, ProtocolVersionId, TransactionTimeRangeConstraint,
-    H256,
+    Address, L2BlockNumber, L2ChainId, PriorityOpId, ProtocolVersionId,
+    TransactionTimeRangeConstraint, H256,
 };
 
 use crate::{MempoolGuard, MempoolIO};
@@ -43,6 +43,7 @@ pub struct Tester {
     base_system_contracts: BaseSystemContracts,
     current_timestamp: u64,
     commitment_mode: L1BatchCommitmentMode,
+    whitelisted_deployers: Vec<Address>,
 }
 
 impl Tester {
@@ -52,6 +53,7 @@ impl Tester {
             base_system_contracts,
             current_timestamp: 0,
             commitment_mode,
+            whitelisted_deployers: vec![],
         }
     }
 
@@ -139,6 +141,7 @@ impl Tester {
         let config = StateKeeperConfig {
             minimal_l2_gas_price: self.minimal_l2_gas_price(),
             validation_computational_gas_limit: BATCH_COMPUTATIONAL_GAS_LIMIT,
+            whitelisted_deployers: self.whitelisted_deployers.clone(),
             ..StateKeeperConfig::for_tests()
         };
         let wallets = Wallets::for_tests();
@@ -162,6 +165,10 @@ impl Tester {
         self.current_timestamp = timestamp;
     }
 
+    pub(super) fn set_whitelisted_deployers(&mut self, deployers: Vec<Address>) {
+        self.whitelisted_deployers = deployers;
+    }
+
     pub(super) async fn genesis(&self, pool: &ConnectionPool<Core>) {
         let mut storage = pool.connection_tagged("state_keeper").await.unwrap();
         if storage.blocks_dal().is_genesis_needed().await.unwrap() {
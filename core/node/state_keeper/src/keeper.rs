@@ -11,7 +11,7 @@ use zksync_health_check::{HealthUpdater, ReactiveHealthCheck};
 use zksync_multivm::{
     interface::{
         executor::{BatchExecutor, BatchExecutorFactory},
-        Halt, L1BatchEnv, SystemEnv,
+        Halt, L1BatchEnv, SystemEnv, TxExecutionStatus,
     },
     utils::StorageWritesDeduplicator,
 };
@@ -742,7 +742,7 @@ impl ZkSyncStateKeeper {
 
                 // Despite success of upgrade transaction is not enforced by protocol,
                 // we panic here because failed upgrade tx is not intended in any case.
-                if tx_result.result.is_failed() {
+                if tx_result.execution_status() == TxExecutionStatus::Failure {
                     anyhow::bail!("Failed upgrade tx {:?}", tx.hash());
                 }
 
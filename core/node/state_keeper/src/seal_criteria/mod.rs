@@ -37,6 +37,8 @@ fn halt_as_metric_label(halt: &Halt) -> &'static str {
         Halt::InnerTxError => "InnerTxError",
         Halt::Unknown(_) => "Unknown",
         Halt::UnexpectedVMBehavior(_) => "UnexpectedVMBehavior",
+        // Bootloader errors used to be reported as `UnexpectedVMBehavior`; the label is kept for metric continuity.
+        Halt::BootloaderInternalError(_) => "UnexpectedVMBehavior",
         Halt::BootloaderOutOfGas => "BootloaderOutOfGas",
         Halt::ValidationOutOfGas => "ValidationOutOfGas",
         Halt::TooBigGasLimit => "TooBigGasLimit",
@@ -48,6 +50,9 @@ fn halt_as_metric_label(halt: &Halt) -> &'static str {
         Halt::TracerCustom(_) => "TracerCustom",
         Halt::FailedToPublishCompressedBytecodes => "FailedToPublishCompressedBytecodes",
         Halt::FailedBlockTimestampAssertion => "FailedBlockTimestampAssertion",
+        Halt::UnauthorizedDeployment(_) => "UnauthorizedDeployment",
+        // `Halt` is non-exhaustive
+        _ => "Other",
     }
 }
 
@@ -269,6 +274,8 @@ impl L2BlockMaxPayloadSizeSealer {
 
 #[cfg(test)]
 mod tests {
+    use zksync_multivm::interface::BootloaderInternalError;
+
     use super::*;
     use crate::tests::{
         create_execution_result, create_transaction, create_updates_manager, seconds_since_epoch,
@@ -338,4 +345,12 @@ mod tests {
             "L2 block with payload encoding size equal or greater than max payload size should be sealed"
         );
     }
+
+    #[test]
+    fn bootloader_internal_error_metric_label() {
+        let reason = UnexecutableReason::Halt(Halt::BootloaderInternalError(
+            BootloaderInternalError::UnacceptableGasPrice,
+        ));
+        assert_eq!(reason.as_metric_label(), "UnexpectedVMBehavior");
+    }
 }
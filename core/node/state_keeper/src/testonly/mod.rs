@@ -28,11 +28,7 @@ pub(super) static BASE_SYSTEM_CONTRACTS: Lazy<BaseSystemContracts> =
 
 /// Creates a `TxExecutionResult` object denoting a successful tx execution.
 pub(crate) fn successful_exec() -> BatchTransactionExecutionResult {
-    BatchTransactionExecutionResult {
-        tx_result: Box::new(VmExecutionResultAndLogs::mock_success()),
-        compression_result: Ok(()),
-        call_traces: vec![],
-    }
+    BatchTransactionExecutionResult::mock(VmExecutionResultAndLogs::mock_success())
 }
 
 /// `BatchExecutor` which doesn't check anything at all. Accepts all transactions.
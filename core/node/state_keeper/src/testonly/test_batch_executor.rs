@@ -267,28 +267,20 @@ pub(crate) fn random_upgrade_tx(tx_number: u64) -> ProtocolUpgradeTx {
 
 /// Creates a `TxExecutionResult` object denoting a successful tx execution with the given execution metrics.
 pub(crate) fn successful_exec_with_log() -> BatchTransactionExecutionResult {
-    BatchTransactionExecutionResult {
-        tx_result: Box::new(VmExecutionResultAndLogs {
-            logs: VmExecutionLogs {
-                user_l2_to_l1_logs: vec![UserL2ToL1Log::default()],
-                ..VmExecutionLogs::default()
-            },
-            ..VmExecutionResultAndLogs::mock_success()
-        }),
-        compression_result: Ok(()),
-        call_traces: vec![],
-    }
+    BatchTransactionExecutionResult::mock(VmExecutionResultAndLogs {
+        logs: VmExecutionLogs {
+            user_l2_to_l1_logs: vec![UserL2ToL1Log::default()],
+            ..VmExecutionLogs::default()
+        },
+        ..VmExecutionResultAndLogs::mock_success()
+    })
 }
 
 /// Creates a `TxExecutionResult` object denoting a tx that was rejected.
 pub(crate) fn rejected_exec(reason: Halt) -> BatchTransactionExecutionResult {
-    BatchTransactionExecutionResult {
-        tx_result: Box::new(VmExecutionResultAndLogs::mock(ExecutionResult::Halt {
-            reason,
-        })),
-        compression_result: Ok(()),
-        call_traces: vec![],
-    }
+    BatchTransactionExecutionResult::mock(VmExecutionResultAndLogs::mock(ExecutionResult::Halt {
+        reason,
+    }))
 }
 
 #[allow(clippy::type_complexity, clippy::large_enum_variant)] // It's OK for tests.
@@ -388,11 +380,12 @@ impl TestBatchExecutorBuilder {
         for item in &scenario.actions {
             match item {
                 ScenarioItem::Tx(_, tx, result) => {
-                    result.compression_result.as_ref().unwrap();
+                    let compression_metrics = *result.compression_result.as_ref().unwrap();
                     let result = BatchTransactionExecutionResult {
                         tx_result: result.tx_result.clone(),
-                        compression_result: Ok(()),
+                        compression_result: Ok(compression_metrics),
                         call_traces: result.call_traces.clone(),
+                        execution_time: result.execution_time,
                     };
 
                     if let Some(txs) = batch_txs.get_mut(&tx.hash()) {
@@ -720,6 +713,7 @@ impl StateKeeperIO for TestIO {
                 virtual_blocks: 1,
             },
             pubdata_params: Default::default(),
+            whitelisted_deployers: None,
         };
         self.l2_block_number += 1;
         self.timestamp += 1;
@@ -2,8 +2,8 @@ use std::collections::HashMap;
 
 use zksync_multivm::{
     interface::{
-        Call, ExecutionResult, L2BlockEnv, TransactionExecutionResult, TxExecutionStatus, VmEvent,
-        VmExecutionMetrics, VmExecutionResultAndLogs,
+        Call, ExecutionResult, L2BlockEnv, TransactionExecutionResult, VmEvent, VmExecutionMetrics,
+        VmExecutionResultAndLogs,
     },
     vm_latest::TransactionVmExt,
 };
@@ -88,11 +88,7 @@ impl L2BlockUpdates {
             VmEvent::extract_bytecodes_marked_as_known(&tx_execution_result.logs.events);
 
         let gas_refunded = tx_execution_result.refunds.gas_refunded;
-        let execution_status = if tx_execution_result.result.is_failed() {
-            TxExecutionStatus::Failure
-        } else {
-            TxExecutionStatus::Success
-        };
+        let execution_status = tx_execution_result.execution_status();
 
         let revert_reason = match &tx_execution_result.result {
             ExecutionResult::Success { .. } => {
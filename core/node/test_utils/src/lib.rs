@@ -40,6 +40,7 @@ pub fn default_system_env() -> SystemEnv {
         execution_mode: TxExecutionMode::VerifyExecute,
         default_validation_computational_gas_limit: u32::MAX,
         chain_id: L2ChainId::from(270),
+        whitelisted_deployers: None,
     }
 }
 
@@ -406,6 +406,8 @@ pub(crate) async fn load_batch_execute_data(
             // are re-executing so none of them should be rejected
             u32::MAX,
             chain_id,
+            // Deployments were already checked when the batch was sealed
+            None,
         )
         .await?
     else {
@@ -72,6 +72,7 @@ impl OutputHandlerTester {
             execution_mode: TxExecutionMode::VerifyExecute,
             default_validation_computational_gas_limit: 0,
             chain_id: Default::default(),
+            whitelisted_deployers: None,
         };
 
         let mut output_handler = self
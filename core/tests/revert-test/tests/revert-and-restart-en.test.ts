@@ -31,6 +31,11 @@ import { IZkSyncHyperchain, IZkSyncHyperchain__factory } from 'zksync-ethers/bui
 
 const pathToHome = path.join(__dirname, '../../../..');
 const fileConfig = shouldLoadConfigFromFile();
+// Number of L1 batches that are committed, but not executed, and then reverted.
+const blocksBeforeRevert = BigInt(process.env.BLOCKS_BEFORE_REVERT ?? '1');
+if (blocksBeforeRevert <= 0n) {
+    throw new Error(`BLOCKS_BEFORE_REVERT must be positive, got ${blocksBeforeRevert}`);
+}
 
 async function logsPath(name: string): Promise<string> {
     return await logsTestPath(fileConfig.chain, 'logs/revert/en', name);
@@ -130,6 +135,8 @@ describe('Block reverting test', function () {
     let depositL1BatchNumber: number;
     let batchesCommittedBeforeRevert: bigint;
 
+    // One deposit in the executed batch, and one in each reverted batch.
+    const expectedDeposits = blocksBeforeRevert + 1n;
     const autoKill: boolean = !fileConfig.loadFromFile || !process.env.NO_KILL;
 
     before('initialize test', async () => {
@@ -237,18 +244,23 @@ describe('Block reverting test', function () {
     });
 
     // FIXME: need 2 batches?
-    step('seal another L1 batch', async () => {
-        await extNode.createBatchWithDeposit(alice.address, depositAmount);
+    step('seal more L1 batches', async () => {
+        for (let i = 0n; i < blocksBeforeRevert; i++) {
+            await extNode.createBatchWithDeposit(alice.address, depositAmount);
+        }
     });
 
     step('check wallet balance', async () => {
         const balance = await alice.getBalance();
         console.log(`Balance before revert: ${balance}`);
-        assert(balance === depositAmount * 2n, 'Incorrect balance after deposits');
+        assert(balance === depositAmount * expectedDeposits, 'Incorrect balance after deposits');
     });
 
-    step('wait for the new batch to be committed', async () => {
-        batchesCommittedBeforeRevert = await waitToCommitBatchesWithoutExecution(settlementLayerMainContract);
+    step('wait for the new batches to be committed', async () => {
+        batchesCommittedBeforeRevert = await waitToCommitBatchesWithoutExecution(
+            settlementLayerMainContract,
+            blocksBeforeRevert
+        );
     });
 
     step('stop server', async () => {
@@ -262,7 +274,8 @@ describe('Block reverting test', function () {
             operatorAddress,
             batchesCommittedBeforeRevert,
             settlementLayerMainContract,
-            mainEnv
+            mainEnv,
+            blocksBeforeRevert
         );
     });
 
@@ -281,19 +294,19 @@ describe('Block reverting test', function () {
     step('wait until last deposit is re-executed', async () => {
         let balanceBefore;
         let tryCount = 0;
-        while ((balanceBefore = await alice.getBalance()) !== 2n * depositAmount && tryCount < 30) {
+        while ((balanceBefore = await alice.getBalance()) !== expectedDeposits * depositAmount && tryCount < 30) {
             console.log(`Balance after revert: ${balanceBefore}`);
             tryCount++;
             await utils.sleep(1);
         }
-        assert(balanceBefore === 2n * depositAmount, 'Incorrect balance after revert');
+        assert(balanceBefore === expectedDeposits * depositAmount, 'Incorrect balance after revert');
     });
 
     step('execute transaction after revert', async () => {
         await executeDepositAfterRevert(extNode.tester, alice, depositAmount);
         const balanceAfter = await alice.getBalance();
         console.log(`Balance after another deposit: ${balanceAfter}`);
-        assert(balanceAfter === depositAmount * 3n, 'Incorrect balance after another deposit');
+        assert(balanceAfter === depositAmount * (expectedDeposits + 1n), 'Incorrect balance after another deposit');
     });
 
     step('check random transfer', async () => {
@@ -171,7 +171,8 @@ export async function executeRevert(
     operatorAddress: string,
     batchesCommittedBeforeRevert: bigint,
     mainContract: IZkSyncHyperchain,
-    env?: ProcessEnvOptions['env']
+    env?: ProcessEnvOptions['env'],
+    expectedRevertedBatches: bigint = 1n
 ) {
     const suggestedValuesOutput = await runBlockReverter(pathToHome, chain, env, [
         'print-suggested-values',
@@ -184,6 +185,11 @@ export async function executeRevert(
         values.lastExecutedL1BatchNumber < batchesCommittedBeforeRevert,
         'There should be at least one block for revert'
     );
+    const revertedBatches = batchesCommittedBeforeRevert - values.lastExecutedL1BatchNumber;
+    assert(
+        revertedBatches >= expectedRevertedBatches,
+        `Expected to revert at least ${expectedRevertedBatches} batches, but only ${revertedBatches} can be reverted`
+    );
 
     console.log('Reverting with parameters', values);
 
@@ -415,20 +421,26 @@ export async function waitToExecuteBatch(mainContract: IZkSyncHyperchain, latest
     assert(lastExecutedBatch > initialExecutedBatch);
 }
 
-export async function waitToCommitBatchesWithoutExecution(mainContract: IZkSyncHyperchain): Promise<bigint> {
+export async function waitToCommitBatchesWithoutExecution(
+    mainContract: IZkSyncHyperchain,
+    minBatches: bigint = 1n
+): Promise<bigint> {
     let batchesCommitted = await mainContract.getTotalBatchesCommitted();
     let batchesExecuted = await mainContract.getTotalBatchesExecuted();
     console.log(`Batches committed: ${batchesCommitted}, executed: ${batchesExecuted}`);
 
     let tryCount = 0;
-    while ((batchesExecuted === 0n || batchesCommitted === batchesExecuted) && tryCount < 100) {
+    while ((batchesExecuted === 0n || batchesCommitted - batchesExecuted < minBatches) && tryCount < 100) {
         await utils.sleep(1);
         batchesCommitted = await mainContract.getTotalBatchesCommitted();
         batchesExecuted = await mainContract.getTotalBatchesExecuted();
         console.log(`Batches committed: ${batchesCommitted}, executed: ${batchesExecuted}`);
         tryCount += 1;
     }
-    expect(batchesCommitted > batchesExecuted, 'There is no committed but not executed batch').to.be.true;
+    expect(
+        batchesCommitted - batchesExecuted >= minBatches,
+        `There are less than ${minBatches} committed but not executed batches`
+    ).to.be.true;
     return batchesCommitted;
 }
 
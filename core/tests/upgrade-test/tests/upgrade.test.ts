@@ -666,7 +666,18 @@ async function prepareUpgradeCalldata(
     const settlementLayerCTMAddress = await settlementLayerDiamondProxy.getChainTypeManager();
 
     const oldProtocolVersion = Number(await settlementLayerDiamondProxy.getProtocolVersion());
-    const newProtocolVersion = addToProtocolVersion(oldProtocolVersion, 1, 1);
+    if (process.env.UPGRADE_FROM_VERSION) {
+        const expectedProtocolVersion = parseSemver(process.env.UPGRADE_FROM_VERSION);
+        if (oldProtocolVersion !== expectedProtocolVersion) {
+            throw new Error(
+                `Chain has protocol version ${unpackNumberSemVer(oldProtocolVersion).join('.')}, ` +
+                    `expected ${process.env.UPGRADE_FROM_VERSION}`
+            );
+        }
+    }
+    const newProtocolVersion = process.env.UPGRADE_TO_VERSION
+        ? parseSemver(process.env.UPGRADE_TO_VERSION)
+        : addToProtocolVersion(oldProtocolVersion, 1, 1);
 
     params.l2ProtocolUpgradeTx.nonce ??= BigInt(unpackNumberSemVer(newProtocolVersion)[1]);
     const upgradeInitData = contracts.l1DefaultUpgradeAbi.encodeFunctionData('upgrade', [
@@ -873,6 +884,16 @@ export function packSemver(major: number, minor: number, patch: number) {
     return minor * SEMVER_MINOR_VERSION_MULTIPLIER + patch;
 }
 
+// Parses a version string like `0.25.1` into the packed form
+export function parseSemver(version: string): number {
+    const parts = version.split('.').map(Number);
+    if (parts.length !== 3 || parts.some((part) => !Number.isInteger(part) || part < 0)) {
+        throw new Error(`Invalid protocol version: ${version}`);
+    }
+    const [major, minor, patch] = parts;
+    return packSemver(major, minor, patch);
+}
+
 export function addToProtocolVersion(packedProtocolVersion: number, minor: number, patch: number) {
     const [major, minorVersion, patchVersion] = unpackNumberSemVer(packedProtocolVersion);
     return packSemver(major, minorVersion + minor, patchVersion + patch);
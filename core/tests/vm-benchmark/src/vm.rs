@@ -228,6 +228,7 @@ fn test_env() -> (SystemEnv, L1BatchEnv) {
         execution_mode: TxExecutionMode::VerifyExecute,
         default_validation_computational_gas_limit: BATCH_COMPUTATIONAL_GAS_LIMIT,
         chain_id: L2ChainId::from(270),
+        whitelisted_deployers: None,
     };
     let l1_batch_env = L1BatchEnv {
         previous_batch_hash: None,
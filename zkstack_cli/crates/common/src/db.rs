@@ -148,6 +148,108 @@ pub async fn migrate_db(
     Ok(())
 }
 
+/// Migration that is not yet applied to a database.
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingMigration {
+    pub version: i64,
+    pub description: String,
+    /// Hex-encoded checksum of the migration.
+    pub checksum: String,
+    pub sql: String,
+}
+
+/// Lists migrations from `migrations_folder` that are not applied to the database, without applying them.
+pub async fn pending_migrations(
+    shell: &Shell,
+    migrations_folder: PathBuf,
+    db_url: &Url,
+) -> anyhow::Result<Vec<PendingMigration>> {
+    if !shell.path_exists(&migrations_folder) {
+        anyhow::bail!("Migrations folder {migrations_folder:?} doesn't exist");
+    }
+    let migrator = Migrator::new(migrations_folder).await?;
+
+    let mut conn = PgConnection::connect(db_url.as_str()).await?;
+    // Unlike `ensure_migrations_table()`, this check doesn't modify the database. If the migrations table
+    // doesn't exist, no migrations are applied yet.
+    let (has_migrations_table,): (bool,) =
+        sqlx::query_as("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+            .fetch_one(&mut conn)
+            .await?;
+    let applied_migrations: HashMap<_, _> = if has_migrations_table {
+        conn.list_applied_migrations()
+            .await?
+            .into_iter()
+            .map(|m| (m.version, m))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+    let _ = conn.close().await;
+
+    let mut pending = vec![];
+    for migration in migrator.iter() {
+        if migration.migration_type.is_down_migration() {
+            continue;
+        }
+        match applied_migrations.get(&migration.version) {
+            Some(applied_migration) => {
+                if migration.checksum != applied_migration.checksum {
+                    anyhow::bail!(MigrateError::VersionMismatch(migration.version));
+                }
+            }
+            None => pending.push(PendingMigration {
+                version: migration.version,
+                description: migration.description.to_string(),
+                checksum: migration
+                    .checksum
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect(),
+                sql: migration.sql.to_string(),
+            }),
+        }
+    }
+    Ok(pending)
+}
+
+/// Column of a database table as reported by `information_schema.columns`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnSchema {
+    pub table_name: String,
+    pub column_name: String,
+    pub data_type: String,
+    pub is_nullable: bool,
+}
+
+/// Fetches columns of all tables in the `public` schema of the database.
+pub async fn fetch_columns_schema(db_url: &Url) -> anyhow::Result<Vec<ColumnSchema>> {
+    let mut connection = PgConnection::connect(db_url.as_str()).await?;
+
+    // `information_schema` uses its own domain types, so we cast them to `text` to be able to decode as strings.
+    let rows: Vec<(String, String, String, String)> = sqlx::query_as(
+        "SELECT table_name::text, column_name::text, data_type::text, is_nullable::text \
+        FROM information_schema.columns \
+        WHERE table_schema = 'public' \
+        ORDER BY table_name, ordinal_position",
+    )
+    .fetch_all(&mut connection)
+    .await?;
+    let _ = connection.close().await;
+
+    Ok(rows
+        .into_iter()
+        .map(
+            |(table_name, column_name, data_type, is_nullable)| ColumnSchema {
+                table_name,
+                column_name,
+                data_type,
+                is_nullable: is_nullable == "YES",
+            },
+        )
+        .collect())
+}
+
 pub async fn wait_for_db(db_url: &Url, tries: u32) -> anyhow::Result<()> {
     for i in 0..tries {
         if PgConnection::connect(db_url.as_str()).await.is_ok() {
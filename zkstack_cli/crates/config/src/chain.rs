@@ -1,9 +1,11 @@
 use std::{
     cell::OnceCell,
+    collections::BTreeMap,
     path::{Path, PathBuf},
 };
 
 use serde::{Deserialize, Serialize, Serializer};
+use url::Url;
 use xshell::Shell;
 use zkstack_cli_types::{BaseToken, L1BatchCommitmentMode, L1Network, ProverMode, WalletCreation};
 use zksync_basic_types::L2ChainId;
@@ -44,6 +46,20 @@ pub struct ChainConfigInternal {
     pub legacy_bridge: Option<bool>,
     #[serde(default)] // for backward compatibility
     pub evm_emulator: bool,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub verification_networks: BTreeMap<String, VerificationNetworkConfig>,
+}
+
+/// Block explorer used to verify contracts deployed by the chain, keyed by the network name
+/// in the chain configuration file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VerificationNetworkConfig {
+    /// URL of the Etherscan-compatible explorer API, e.g. `https://api-sepolia.etherscan.io/api`.
+    /// Blockscout exposes a compatible API at the `/api` path.
+    pub api_url: Url,
+    /// API key for the explorer. Not required by some explorers (e.g., Blockscout).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
 }
 
 /// Chain configuration file. This file is created in the chain
@@ -66,6 +82,7 @@ pub struct ChainConfig {
     pub shell: OnceCell<Shell>,
     pub legacy_bridge: Option<bool>,
     pub evm_emulator: bool,
+    pub verification_networks: BTreeMap<String, VerificationNetworkConfig>,
 }
 
 #[derive(Debug, Clone)]
@@ -179,6 +196,7 @@ impl ChainConfig {
             wallet_creation: self.wallet_creation,
             legacy_bridge: self.legacy_bridge,
             evm_emulator: self.evm_emulator,
+            verification_networks: self.verification_networks.clone(),
         }
     }
 }
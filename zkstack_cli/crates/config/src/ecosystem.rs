@@ -179,6 +179,7 @@ impl EcosystemConfig {
                 .unwrap_or_else(|| self.get_chain_artifacts_path(name)),
             legacy_bridge: config.legacy_bridge,
             evm_emulator: config.evm_emulator,
+            verification_networks: config.verification_networks,
         })
     }
 
@@ -303,15 +303,39 @@ _zkstack() {
             zkstack__dev__clean__help,help)
                 cmd="zkstack__dev__clean__help__help"
                 ;;
+            zkstack__dev__contracts,help)
+                cmd="zkstack__dev__contracts__help"
+                ;;
+            zkstack__dev__contracts,size-report)
+                cmd="zkstack__dev__contracts__size__report"
+                ;;
+            zkstack__dev__contracts,verify)
+                cmd="zkstack__dev__contracts__verify"
+                ;;
+            zkstack__dev__contracts__help,help)
+                cmd="zkstack__dev__contracts__help__help"
+                ;;
+            zkstack__dev__contracts__help,size-report)
+                cmd="zkstack__dev__contracts__help__size__report"
+                ;;
+            zkstack__dev__contracts__help,verify)
+                cmd="zkstack__dev__contracts__help__verify"
+                ;;
             zkstack__dev__database,check-sqlx-data)
                 cmd="zkstack__dev__database__check__sqlx__data"
                 ;;
             zkstack__dev__database,drop)
                 cmd="zkstack__dev__database__drop"
                 ;;
+            zkstack__dev__database,export)
+                cmd="zkstack__dev__database__export"
+                ;;
             zkstack__dev__database,help)
                 cmd="zkstack__dev__database__help"
                 ;;
+            zkstack__dev__database,import)
+                cmd="zkstack__dev__database__import"
+                ;;
             zkstack__dev__database,migrate)
                 cmd="zkstack__dev__database__migrate"
                 ;;
@@ -327,15 +351,24 @@ _zkstack() {
             zkstack__dev__database,setup)
                 cmd="zkstack__dev__database__setup"
                 ;;
+            zkstack__dev__database,verify-schema)
+                cmd="zkstack__dev__database__verify__schema"
+                ;;
             zkstack__dev__database__help,check-sqlx-data)
                 cmd="zkstack__dev__database__help__check__sqlx__data"
                 ;;
             zkstack__dev__database__help,drop)
                 cmd="zkstack__dev__database__help__drop"
                 ;;
+            zkstack__dev__database__help,export)
+                cmd="zkstack__dev__database__help__export"
+                ;;
             zkstack__dev__database__help,help)
                 cmd="zkstack__dev__database__help__help"
                 ;;
+            zkstack__dev__database__help,import)
+                cmd="zkstack__dev__database__help__import"
+                ;;
             zkstack__dev__database__help,migrate)
                 cmd="zkstack__dev__database__help__migrate"
                 ;;
@@ -351,6 +384,9 @@ _zkstack() {
             zkstack__dev__database__help,setup)
                 cmd="zkstack__dev__database__help__setup"
                 ;;
+            zkstack__dev__database__help,verify-schema)
+                cmd="zkstack__dev__database__help__verify__schema"
+                ;;
             zkstack__dev__fmt,contract)
                 cmd="zkstack__dev__fmt__contract"
                 ;;
@@ -423,12 +459,24 @@ _zkstack() {
             zkstack__dev__help__clean,contracts-cache)
                 cmd="zkstack__dev__help__clean__contracts__cache"
                 ;;
+            zkstack__dev__help__contracts,size-report)
+                cmd="zkstack__dev__help__contracts__size__report"
+                ;;
+            zkstack__dev__help__contracts,verify)
+                cmd="zkstack__dev__help__contracts__verify"
+                ;;
             zkstack__dev__help__database,check-sqlx-data)
                 cmd="zkstack__dev__help__database__check__sqlx__data"
                 ;;
             zkstack__dev__help__database,drop)
                 cmd="zkstack__dev__help__database__drop"
                 ;;
+            zkstack__dev__help__database,export)
+                cmd="zkstack__dev__help__database__export"
+                ;;
+            zkstack__dev__help__database,import)
+                cmd="zkstack__dev__help__database__import"
+                ;;
             zkstack__dev__help__database,migrate)
                 cmd="zkstack__dev__help__database__migrate"
                 ;;
@@ -444,6 +492,9 @@ _zkstack() {
             zkstack__dev__help__database,setup)
                 cmd="zkstack__dev__help__database__setup"
                 ;;
+            zkstack__dev__help__database,verify-schema)
+                cmd="zkstack__dev__help__database__verify__schema"
+                ;;
             zkstack__dev__help__fmt,contract)
                 cmd="zkstack__dev__help__fmt__contract"
                 ;;
@@ -465,9 +516,15 @@ _zkstack() {
             zkstack__dev__help__snapshot,create)
                 cmd="zkstack__dev__help__snapshot__create"
                 ;;
+            zkstack__dev__help__snapshot,export)
+                cmd="zkstack__dev__help__snapshot__export"
+                ;;
             zkstack__dev__help__status,ports)
                 cmd="zkstack__dev__help__status__ports"
                 ;;
+            zkstack__dev__help__test,benchmark)
+                cmd="zkstack__dev__help__test__benchmark"
+                ;;
             zkstack__dev__help__test,build)
                 cmd="zkstack__dev__help__test__build"
                 ;;
@@ -528,12 +585,18 @@ _zkstack() {
             zkstack__dev__snapshot,create)
                 cmd="zkstack__dev__snapshot__create"
                 ;;
+            zkstack__dev__snapshot,export)
+                cmd="zkstack__dev__snapshot__export"
+                ;;
             zkstack__dev__snapshot,help)
                 cmd="zkstack__dev__snapshot__help"
                 ;;
             zkstack__dev__snapshot__help,create)
                 cmd="zkstack__dev__snapshot__help__create"
                 ;;
+            zkstack__dev__snapshot__help,export)
+                cmd="zkstack__dev__snapshot__help__export"
+                ;;
             zkstack__dev__snapshot__help,help)
                 cmd="zkstack__dev__snapshot__help__help"
                 ;;
@@ -549,6 +612,9 @@ _zkstack() {
             zkstack__dev__status__help,ports)
                 cmd="zkstack__dev__status__help__ports"
                 ;;
+            zkstack__dev__test,benchmark)
+                cmd="zkstack__dev__test__benchmark"
+                ;;
             zkstack__dev__test,build)
                 cmd="zkstack__dev__test__build"
                 ;;
@@ -585,6 +651,9 @@ _zkstack() {
             zkstack__dev__test,wallet)
                 cmd="zkstack__dev__test__wallet"
                 ;;
+            zkstack__dev__test__help,benchmark)
+                cmd="zkstack__dev__test__help__benchmark"
+                ;;
             zkstack__dev__test__help,build)
                 cmd="zkstack__dev__test__help__build"
                 ;;
@@ -879,12 +948,24 @@ _zkstack() {
             zkstack__help__dev__clean,contracts-cache)
                 cmd="zkstack__help__dev__clean__contracts__cache"
                 ;;
+            zkstack__help__dev__contracts,size-report)
+                cmd="zkstack__help__dev__contracts__size__report"
+                ;;
+            zkstack__help__dev__contracts,verify)
+                cmd="zkstack__help__dev__contracts__verify"
+                ;;
             zkstack__help__dev__database,check-sqlx-data)
                 cmd="zkstack__help__dev__database__check__sqlx__data"
                 ;;
             zkstack__help__dev__database,drop)
                 cmd="zkstack__help__dev__database__drop"
                 ;;
+            zkstack__help__dev__database,export)
+                cmd="zkstack__help__dev__database__export"
+                ;;
+            zkstack__help__dev__database,import)
+                cmd="zkstack__help__dev__database__import"
+                ;;
             zkstack__help__dev__database,migrate)
                 cmd="zkstack__help__dev__database__migrate"
                 ;;
@@ -900,6 +981,9 @@ _zkstack() {
             zkstack__help__dev__database,setup)
                 cmd="zkstack__help__dev__database__setup"
                 ;;
+            zkstack__help__dev__database,verify-schema)
+                cmd="zkstack__help__dev__database__verify__schema"
+                ;;
             zkstack__help__dev__fmt,contract)
                 cmd="zkstack__help__dev__fmt__contract"
                 ;;
@@ -921,9 +1005,15 @@ _zkstack() {
             zkstack__help__dev__snapshot,create)
                 cmd="zkstack__help__dev__snapshot__create"
                 ;;
+            zkstack__help__dev__snapshot,export)
+                cmd="zkstack__help__dev__snapshot__export"
+                ;;
             zkstack__help__dev__status,ports)
                 cmd="zkstack__help__dev__status__ports"
                 ;;
+            zkstack__help__dev__test,benchmark)
+                cmd="zkstack__help__dev__test__benchmark"
+                ;;
             zkstack__help__dev__test,build)
                 cmd="zkstack__help__dev__test__build"
                 ;;
@@ -2810,7 +2900,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__contracts)
-            opts="-v -h --l1-contracts --l1-da-contracts --l2-contracts --system-contracts --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --l1-contracts --l1-da-contracts --l2-contracts --system-contracts --verbose --chain --ignore-prerequisites --help verify size-report help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2843,8 +2933,116 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__contracts__help)
+            opts="verify size-report help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__contracts__help__help)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__contracts__help__size__report)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__contracts__help__verify)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__contracts__size__report)
+            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__contracts__verify)
+            opts="-v -h --network --contract --address --constructor-args --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --network)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --contract)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --address)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --constructor-args)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__database)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help check-sqlx-data drop migrate new-migration prepare reset setup help"
+            opts="-v -h --json --verbose --chain --ignore-prerequisites --help check-sqlx-data drop export import migrate new-migration prepare reset setup verify-schema help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2862,7 +3060,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__check__sqlx__data)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --json --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2904,7 +3102,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__drop)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --json --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2945,8 +3143,42 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__database__export)
+            opts="-v -h --database --url --tables --to-file --json --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --database)
+                    COMPREPLY=($(compgen -W "prover core" -- "${cur}"))
+                    return 0
+                    ;;
+                --url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --tables)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --to-file)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__database__help)
-            opts="check-sqlx-data drop migrate new-migration prepare reset setup help"
+            opts="check-sqlx-data drop export import migrate new-migration prepare reset setup verify-schema help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2987,6 +3219,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__database__help__export)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__database__help__help)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
@@ -3001,6 +3247,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__database__help__import)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__database__help__migrate)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
@@ -3071,8 +3331,52 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__database__help__verify__schema)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__database__import)
+            opts="-v -h --database --url --from-file --json --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --database)
+                    COMPREPLY=($(compgen -W "prover core" -- "${cur}"))
+                    return 0
+                    ;;
+                --url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --from-file)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__database__migrate)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --dry-run --parallel --json --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3114,7 +3418,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__new__migration)
-            opts="-v -h --database --name --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --database --name --template --json --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3128,6 +3432,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --template)
+                    COMPREPLY=($(compgen -W "add-column add-index add-table" -- "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -3140,7 +3448,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__prepare)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --json --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3182,7 +3490,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__reset)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --json --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3224,7 +3532,49 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__setup)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --json --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --prover)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                -p)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --prover-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --core)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                -c)
+                    COMPREPLY=($(compgen -W "true false" -- "${cur}"))
+                    return 0
+                    ;;
+                --core-url)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__database__verify__schema)
+            opts="-p -c -v -h --prover --prover-url --core --core-url --json --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3398,12 +3748,16 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__fmt__rustfmt)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --edition --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --edition)
+                    COMPREPLY=($(compgen -W "2021 2024" -- "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -3433,9 +3787,65 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__help)
-            opts="database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help"
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+        zkstack__dev__help)
+            opts="database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__help__clean)
+            opts="all containers contracts-cache"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__help__clean__all)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__help__clean__containers)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__dev__help__clean__contracts__cache)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
@@ -3447,8 +3857,8 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__help__clean)
-            opts="all containers contracts-cache"
+        zkstack__dev__help__config__writer)
+            opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3461,9 +3871,9 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__help__clean__all)
-            opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+        zkstack__dev__help__contracts)
+            opts="verify size-report"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
@@ -3475,7 +3885,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__help__clean__containers)
+        zkstack__dev__help__contracts__size__report)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -3489,7 +3899,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__help__clean__contracts__cache)
+        zkstack__dev__help__contracts__verify)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -3503,8 +3913,8 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__help__config__writer)
-            opts=""
+        zkstack__dev__help__database)
+            opts="check-sqlx-data drop export import migrate new-migration prepare reset setup verify-schema"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3517,9 +3927,9 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__help__contracts)
+        zkstack__dev__help__database__check__sqlx__data)
             opts=""
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
@@ -3531,9 +3941,9 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__help__database)
-            opts="check-sqlx-data drop migrate new-migration prepare reset setup"
-            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+        zkstack__dev__help__database__drop)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
@@ -3545,7 +3955,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__help__database__check__sqlx__data)
+        zkstack__dev__help__database__export)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -3559,7 +3969,7 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
-        zkstack__dev__help__database__drop)
+        zkstack__dev__help__database__import)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
@@ -3643,6 +4053,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__help__database__verify__schema)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__help__fmt)
             opts="rustfmt contract prettier"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -3812,7 +4236,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__help__snapshot)
-            opts="create"
+            opts="create export"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3839,6 +4263,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__help__snapshot__export)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__help__status)
             opts="ports"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -3868,7 +4306,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__help__test)
-            opts="integration fees revert recovery upgrade build rust l1-contracts prover wallet loadtest"
+            opts="integration fees revert recovery upgrade build rust l1-contracts prover wallet loadtest benchmark"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3881,6 +4319,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__help__test__benchmark)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__help__test__build)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
@@ -4036,7 +4488,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__lint)
-            opts="-c -t -v -h --check --targets --verbose --chain --ignore-prerequisites --help"
+            opts="-c -t -v -h --check --fix --targets --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4258,7 +4710,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__snapshot)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help create help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --help create export help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4293,8 +4745,34 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__snapshot__export)
+            opts="-v -h --format --output --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --format)
+                    COMPREPLY=($(compgen -W "json cbor" -- "${cur}"))
+                    return 0
+                    ;;
+                --output)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__snapshot__help)
-            opts="create help"
+            opts="create export help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4321,6 +4799,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__snapshot__help__export)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__snapshot__help__help)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
@@ -4422,7 +4914,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help integration fees revert recovery upgrade build rust l1-contracts prover wallet loadtest help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --help integration fees revert recovery upgrade build rust l1-contracts prover wallet loadtest benchmark help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4439,6 +4931,36 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__test__benchmark)
+            opts="-v -h --duration-secs --target-tps --accounts --verbose --chain --ignore-prerequisites --help"
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                --duration-secs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --target-tps)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --accounts)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --chain)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__test__build)
             opts="-v -h --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -4476,7 +4998,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__help)
-            opts="integration fees revert recovery upgrade build rust l1-contracts prover wallet loadtest help"
+            opts="integration fees revert recovery upgrade build rust l1-contracts prover wallet loadtest benchmark help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4489,6 +5011,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__dev__test__help__benchmark)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__dev__test__help__build)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
@@ -4658,7 +5194,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__integration)
-            opts="-e -n -t -v -h --external-node --no-deps --test-pattern --verbose --chain --ignore-prerequisites --help"
+            opts="-e -n -t -v -h --external-node --no-deps --test-pattern --timeout-secs --capture-traces --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4672,6 +5208,10 @@ _zkstack() {
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
                     ;;
+                --timeout-secs)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -4702,12 +5242,16 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__loadtest)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --scenario --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --scenario)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -4720,12 +5264,16 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__prover)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --filter --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --filter)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -4756,12 +5304,16 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__revert)
-            opts="-e -n -v -h --enable-consensus --external-node --no-deps --no-kill --verbose --chain --ignore-prerequisites --help"
+            opts="-e -n -v -h --enable-consensus --external-node --no-deps --no-kill --blocks-before-revert --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --blocks-before-revert)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -4796,12 +5348,20 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__upgrade)
-            opts="-n -v -h --no-deps --verbose --chain --ignore-prerequisites --help"
+            opts="-n -v -h --no-deps --from-version --to-version --verbose --chain --ignore-prerequisites --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
             fi
             case "${prev}" in
+                --from-version)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
+                --to-version)
+                    COMPREPLY=($(compgen -f "${cur}"))
+                    return 0
+                    ;;
                 --chain)
                     COMPREPLY=($(compgen -f "${cur}"))
                     return 0
@@ -6128,7 +6688,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__help__dev__contracts)
-            opts=""
+            opts="verify size-report"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -6141,8 +6701,36 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__dev__contracts__size__report)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__help__dev__contracts__verify)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__dev__database)
-            opts="check-sqlx-data drop migrate new-migration prepare reset setup"
+            opts="check-sqlx-data drop export import migrate new-migration prepare reset setup verify-schema"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -6183,6 +6771,34 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__dev__database__export)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
+        zkstack__help__dev__database__import)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__dev__database__migrate)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
@@ -6253,6 +6869,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__dev__database__verify__schema)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__dev__fmt)
             opts="rustfmt contract prettier"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -6408,7 +7038,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__help__dev__snapshot)
-            opts="create"
+            opts="create export"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -6435,6 +7065,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__dev__snapshot__export)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__dev__status)
             opts="ports"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
@@ -6464,7 +7108,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__help__dev__test)
-            opts="integration fees revert recovery upgrade build rust l1-contracts prover wallet loadtest"
+            opts="integration fees revert recovery upgrade build rust l1-contracts prover wallet loadtest benchmark"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -6477,6 +7121,20 @@ _zkstack() {
             COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
             return 0
             ;;
+        zkstack__help__dev__test__benchmark)
+            opts=""
+            if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
+                COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+                return 0
+            fi
+            case "${prev}" in
+                *)
+                    COMPREPLY=()
+                    ;;
+            esac
+            COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
+            return 0
+            ;;
         zkstack__help__dev__test__build)
             opts=""
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 5 ]] ; then
@@ -1,4 +1,4 @@
-use std::cell::OnceCell;
+use std::{cell::OnceCell, collections::BTreeMap};
 
 use anyhow::Context;
 use xshell::Shell;
@@ -109,6 +109,7 @@ pub(crate) async fn create_chain_inner(
         shell: OnceCell::from(shell.clone()),
         legacy_bridge,
         evm_emulator: args.evm_emulator,
+        verification_networks: BTreeMap::new(),
     };
 
     create_wallets(
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use xshell::Shell;
 use zkstack_cli_common::{
     contracts::{
@@ -11,16 +11,31 @@ use zkstack_cli_common::{
 };
 use zkstack_cli_config::EcosystemConfig;
 
+use self::verify::ContractsVerifyArgs;
 use crate::commands::dev::messages::{
     MSG_BUILDING_CONTRACTS, MSG_BUILDING_CONTRACTS_SUCCESS, MSG_BUILDING_L1_CONTRACTS_SPINNER,
     MSG_BUILDING_L1_DA_CONTRACTS_SPINNER, MSG_BUILDING_L2_CONTRACTS_SPINNER,
     MSG_BUILDING_SYSTEM_CONTRACTS_SPINNER, MSG_BUILD_L1_CONTRACTS_HELP,
     MSG_BUILD_L1_DA_CONTRACTS_HELP, MSG_BUILD_L2_CONTRACTS_HELP, MSG_BUILD_SYSTEM_CONTRACTS_HELP,
-    MSG_NOTHING_TO_BUILD_MSG,
+    MSG_CONTRACTS_SIZE_REPORT_ABOUT, MSG_CONTRACTS_VERIFY_ABOUT, MSG_NOTHING_TO_BUILD_MSG,
 };
 
+mod size_report;
+mod verify;
+
+#[derive(Debug, Subcommand)]
+pub enum ContractsCommands {
+    #[clap(about = MSG_CONTRACTS_VERIFY_ABOUT)]
+    Verify(ContractsVerifyArgs),
+    #[clap(about = MSG_CONTRACTS_SIZE_REPORT_ABOUT)]
+    SizeReport,
+}
+
 #[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct ContractsArgs {
+    #[command(subcommand)]
+    pub command: Option<ContractsCommands>,
     #[clap(long, alias = "l1", help = MSG_BUILD_L1_CONTRACTS_HELP, default_missing_value = "true", num_args = 0..=1)]
     pub l1_contracts: Option<bool>,
     #[clap(long, alias = "l1-da", help = MSG_BUILD_L1_DA_CONTRACTS_HELP, default_missing_value = "true", num_args = 0..=1)]
@@ -112,6 +127,12 @@ impl ContractBuilder {
 }
 
 pub fn run(shell: &Shell, args: ContractsArgs) -> anyhow::Result<()> {
+    match args.command {
+        Some(ContractsCommands::Verify(args)) => return verify::run(shell, args),
+        Some(ContractsCommands::SizeReport) => return size_report::run(shell),
+        None => {}
+    }
+
     let contracts = args.contracts();
     if contracts.is_empty() {
         logger::outro(MSG_NOTHING_TO_BUILD_MSG);
@@ -0,0 +1,243 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use console::style;
+use serde_json::Value;
+use xshell::Shell;
+use zkstack_cli_common::logger;
+use zkstack_cli_config::EcosystemConfig;
+
+use crate::commands::dev::messages::{
+    msg_contract_artifacts_not_found, msg_contracts_near_size_limit,
+    MSG_CONTRACTS_SIZE_REPORT_EMPTY, MSG_CONTRACTS_SIZE_REPORT_HEADER,
+};
+
+/// Maximum size of deployed EVM bytecode (EIP-170).
+const EVM_BYTECODE_LIMIT: usize = 24_576;
+/// Maximum size of EraVM bytecode: its length in 32-byte words must fit into `u16`.
+const ERA_VM_BYTECODE_LIMIT: usize = u16::MAX as usize * 32;
+/// Bytecode size (as a percentage of the limit) starting from which contracts are highlighted as a warning.
+const WARNING_THRESHOLD: f64 = 80.0;
+/// Bytecode size (as a percentage of the limit) above which contracts are highlighted as critical.
+const CRITICAL_THRESHOLD: f64 = 95.0;
+
+/// Directories with Foundry build artifacts relative to the `contracts` dir, together with the applicable
+/// bytecode size limit.
+const ARTIFACT_DIRS: [(&str, usize); 4] = [
+    ("l1-contracts/out", EVM_BYTECODE_LIMIT),
+    ("da-contracts/out", EVM_BYTECODE_LIMIT),
+    ("l2-contracts/zkout", ERA_VM_BYTECODE_LIMIT),
+    ("system-contracts/zkout", ERA_VM_BYTECODE_LIMIT),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SizeLevel {
+    Ok,
+    Warning,
+    Critical,
+}
+
+impl SizeLevel {
+    fn new(usage_percent: f64) -> Self {
+        if usage_percent > CRITICAL_THRESHOLD {
+            Self::Critical
+        } else if usage_percent >= WARNING_THRESHOLD {
+            Self::Warning
+        } else {
+            Self::Ok
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct ContractSize {
+    /// Contract name in the `<file>:<name>` format.
+    name: String,
+    size: usize,
+    limit: usize,
+}
+
+impl ContractSize {
+    fn usage_percent(&self) -> f64 {
+        self.size as f64 / self.limit as f64 * 100.0
+    }
+
+    fn level(&self) -> SizeLevel {
+        SizeLevel::new(self.usage_percent())
+    }
+
+    fn format_row(&self, name_width: usize) -> String {
+        let row = format!(
+            "{:<name_width$}  {:>10}  {:>10}  {:>7.2}%",
+            self.name,
+            self.size,
+            self.limit,
+            self.usage_percent()
+        );
+        match self.level() {
+            SizeLevel::Ok => style(row).green(),
+            SizeLevel::Warning => style(row).yellow(),
+            SizeLevel::Critical => style(row).red(),
+        }
+        .to_string()
+    }
+}
+
+pub fn run(shell: &Shell) -> anyhow::Result<()> {
+    let ecosystem = EcosystemConfig::from_file(shell)?;
+    let contracts_path = ecosystem.link_to_code.join("contracts");
+
+    let mut sizes = vec![];
+    for (dir, limit) in ARTIFACT_DIRS {
+        let artifacts_dir = contracts_path.join(dir);
+        if !artifacts_dir.is_dir() {
+            logger::warn(msg_contract_artifacts_not_found(&artifacts_dir));
+            continue;
+        }
+        sizes.extend(collect_sizes(&artifacts_dir, limit)?);
+    }
+
+    if sizes.is_empty() {
+        logger::outro(MSG_CONTRACTS_SIZE_REPORT_EMPTY);
+        return Ok(());
+    }
+    // Show the largest contracts (relative to the limit) first.
+    sizes.sort_by(|a, b| b.usage_percent().total_cmp(&a.usage_percent()));
+
+    let name_width = sizes.iter().map(|size| size.name.len()).max().unwrap_or(0);
+    let header = format!(
+        "{:<name_width$}  {:>10}  {:>10}  {:>8}",
+        "Contract", "Size", "Limit", "Usage"
+    );
+    let rows: Vec<_> = sizes
+        .iter()
+        .map(|size| size.format_row(name_width))
+        .collect();
+    logger::note(
+        MSG_CONTRACTS_SIZE_REPORT_HEADER,
+        format!("{header}\n{}", rows.join("\n")),
+    );
+
+    let near_limit_count = sizes
+        .iter()
+        .filter(|size| size.level() != SizeLevel::Ok)
+        .count();
+    if near_limit_count > 0 {
+        logger::warn(msg_contracts_near_size_limit(
+            near_limit_count,
+            WARNING_THRESHOLD,
+        ));
+    }
+    Ok(())
+}
+
+/// Collects bytecode sizes from Foundry artifacts, which are laid out as `<dir>/<file>.sol/<name>.json`.
+/// Artifacts for tests and scripts, and artifacts without bytecode (e.g., for interfaces) are skipped.
+fn collect_sizes(dir: &Path, limit: usize) -> anyhow::Result<Vec<ContractSize>> {
+    let mut sizes = vec![];
+    for source_dir in read_dir(dir)? {
+        let Some(file_name) = source_dir.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if !source_dir.is_dir() || !is_contract_source_dir(file_name) {
+            continue;
+        }
+
+        for artifact_path in read_dir(&source_dir)? {
+            if artifact_path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let artifact = fs::read_to_string(&artifact_path)
+                .with_context(|| format!("failed reading `{}`", artifact_path.display()))?;
+            let artifact: Value = serde_json::from_str(&artifact)
+                .with_context(|| format!("failed parsing `{}`", artifact_path.display()))?;
+            let Some(size) = bytecode_size(&artifact) else {
+                continue;
+            };
+            let contract_name = artifact_path.file_stem().unwrap().to_string_lossy();
+            sizes.push(ContractSize {
+                name: format!("{file_name}:{contract_name}"),
+                size,
+                limit,
+            });
+        }
+    }
+    Ok(sizes)
+}
+
+/// Checks whether an artifacts subdirectory corresponds to a contract source file, rather than to a test
+/// or a script, or to auxiliary data such as `build-info`.
+fn is_contract_source_dir(name: &str) -> bool {
+    name.ends_with(".sol") && !name.ends_with(".t.sol") && !name.ends_with(".s.sol")
+}
+
+fn read_dir(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let entries =
+        fs::read_dir(dir).with_context(|| format!("failed reading `{}`", dir.display()))?;
+    entries
+        .map(|entry| Ok(entry?.path()))
+        .collect::<std::io::Result<_>>()
+        .with_context(|| format!("failed reading `{}`", dir.display()))
+}
+
+/// Returns the size of deployed bytecode from a Foundry artifact, or `None` if the artifact has no bytecode.
+/// EraVM artifacts may only contain `bytecode` since EraVM doesn't distinguish between deployed and init code.
+fn bytecode_size(artifact: &Value) -> Option<usize> {
+    let object = ["deployedBytecode", "bytecode"]
+        .into_iter()
+        .find_map(|field| artifact.get(field)?.get("object")?.as_str())?;
+    let hex = object.strip_prefix("0x").unwrap_or(object);
+    (!hex.is_empty()).then_some(hex.len() / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn extracting_bytecode_size() {
+        let artifact = json!({
+            "bytecode": { "object": "0x60806040" },
+            "deployedBytecode": { "object": "0x6080" },
+        });
+        assert_eq!(bytecode_size(&artifact), Some(2));
+
+        let zk_artifact = json!({ "bytecode": { "object": "000000000000000a" } });
+        assert_eq!(bytecode_size(&zk_artifact), Some(8));
+
+        let interface_artifact = json!({
+            "bytecode": { "object": "0x" },
+            "deployedBytecode": { "object": "0x" },
+        });
+        assert_eq!(bytecode_size(&interface_artifact), None);
+        assert_eq!(bytecode_size(&json!({ "abi": [] })), None);
+    }
+
+    #[test]
+    fn classifying_contract_sizes() {
+        let size = |size| ContractSize {
+            name: "Test.sol:Test".to_owned(),
+            size,
+            limit: 1_000,
+        };
+        assert_eq!(size(0).level(), SizeLevel::Ok);
+        assert_eq!(size(799).level(), SizeLevel::Ok);
+        assert_eq!(size(800).level(), SizeLevel::Warning);
+        assert_eq!(size(950).level(), SizeLevel::Warning);
+        assert_eq!(size(951).level(), SizeLevel::Critical);
+        assert_eq!(size(1_500).level(), SizeLevel::Critical);
+    }
+
+    #[test]
+    fn filtering_source_dirs() {
+        assert!(is_contract_source_dir("Bridgehub.sol"));
+        assert!(!is_contract_source_dir("Bridgehub.t.sol"));
+        assert!(!is_contract_source_dir("DeployL1.s.sol"));
+        assert!(!is_contract_source_dir("build-info"));
+    }
+}
@@ -0,0 +1,421 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use anyhow::Context;
+use clap::Parser;
+use serde::Deserialize;
+use serde_json::{json, Map, Value};
+use xshell::Shell;
+use zkstack_cli_common::{logger, spinner::Spinner};
+use zkstack_cli_config::{EcosystemConfig, VerificationNetworkConfig};
+use zksync_basic_types::Address;
+
+use crate::commands::dev::messages::{
+    msg_contract_already_verified, msg_contract_artifact_read_err,
+    msg_contract_verification_failed, msg_contract_verified, msg_verification_network_not_found,
+    msg_verifying_contract_spinner, MSG_CHAIN_NOT_FOUND_ERR, MSG_CONTRACTS_VERIFY_ADDRESS_HELP,
+    MSG_CONTRACTS_VERIFY_CONSTRUCTOR_ARGS_HELP, MSG_CONTRACTS_VERIFY_CONTRACT_HELP,
+    MSG_CONTRACTS_VERIFY_NETWORK_HELP, MSG_CONTRACTS_VERIFY_TIMEOUT_ERR,
+};
+
+/// Interval between polls of the verification status.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// Maximum number of verification status polls before giving up.
+const MAX_POLLS: usize = 60;
+
+#[derive(Debug, Parser)]
+pub struct ContractsVerifyArgs {
+    #[clap(long, help = MSG_CONTRACTS_VERIFY_NETWORK_HELP)]
+    pub network: String,
+    #[clap(long, help = MSG_CONTRACTS_VERIFY_CONTRACT_HELP)]
+    pub contract: String,
+    #[clap(long, help = MSG_CONTRACTS_VERIFY_ADDRESS_HELP)]
+    pub address: Address,
+    #[clap(long, help = MSG_CONTRACTS_VERIFY_CONSTRUCTOR_ARGS_HELP)]
+    pub constructor_args: Option<String>,
+}
+
+/// Relevant part of a Foundry build artifact.
+#[derive(Debug, Deserialize)]
+struct Artifact {
+    metadata: ContractMetadata,
+}
+
+/// Solidity contract metadata (as output by `solc`) included into a build artifact.
+#[derive(Debug, Deserialize)]
+struct ContractMetadata {
+    compiler: CompilerMetadata,
+    language: String,
+    settings: Map<String, Value>,
+    sources: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CompilerMetadata {
+    version: String,
+}
+
+/// Response of the Etherscan-compatible contract API.
+#[derive(Debug, Deserialize)]
+struct ExplorerResponse {
+    status: String,
+    result: String,
+}
+
+#[derive(Debug, PartialEq)]
+enum VerificationStatus {
+    Pending,
+    Verified,
+    AlreadyVerified,
+    Failed(String),
+}
+
+impl ExplorerResponse {
+    fn verification_status(&self) -> VerificationStatus {
+        let result = self.result.to_lowercase();
+        if result.contains("already verified") {
+            VerificationStatus::AlreadyVerified
+        } else if result.contains("pending") {
+            VerificationStatus::Pending
+        } else if self.status == "1" {
+            VerificationStatus::Verified
+        } else {
+            VerificationStatus::Failed(self.result.clone())
+        }
+    }
+}
+
+/// Client for the Etherscan-compatible contract verification API, which is supported by both Etherscan and Blockscout.
+#[derive(Debug)]
+struct ExplorerClient<'a> {
+    client: reqwest::blocking::Client,
+    network: &'a VerificationNetworkConfig,
+}
+
+impl<'a> ExplorerClient<'a> {
+    fn new(network: &'a VerificationNetworkConfig) -> Self {
+        Self {
+            client: reqwest::blocking::Client::new(),
+            network,
+        }
+    }
+
+    fn with_api_key(&self, mut params: Vec<(&'static str, String)>) -> Vec<(&'static str, String)> {
+        if let Some(api_key) = &self.network.api_key {
+            params.push(("apikey", api_key.clone()));
+        }
+        params
+    }
+
+    fn parse_response(response: reqwest::blocking::Response) -> anyhow::Result<ExplorerResponse> {
+        let response = response.error_for_status()?.text()?;
+        serde_json::from_str(&response)
+            .with_context(|| format!("failed parsing explorer response: {response}"))
+    }
+
+    /// Submits a verification request. Returns the GUID of the request to poll its status,
+    /// or `None` if the contract is already verified.
+    fn submit(&self, params: Vec<(&'static str, String)>) -> anyhow::Result<Option<String>> {
+        let response = self
+            .client
+            .post(self.network.api_url.clone())
+            .form(&self.with_api_key(params))
+            .send()?;
+        let response = Self::parse_response(response)?;
+        if response.status == "1" {
+            return Ok(Some(response.result));
+        }
+        match response.verification_status() {
+            VerificationStatus::AlreadyVerified => Ok(None),
+            _ => anyhow::bail!(response.result),
+        }
+    }
+
+    fn check_status(&self, guid: &str) -> anyhow::Result<VerificationStatus> {
+        let params = vec![
+            ("module", "contract".to_owned()),
+            ("action", "checkverifystatus".to_owned()),
+            ("guid", guid.to_owned()),
+        ];
+        let response = self
+            .client
+            .get(self.network.api_url.clone())
+            .query(&self.with_api_key(params))
+            .send()?;
+        Ok(Self::parse_response(response)?.verification_status())
+    }
+
+    fn wait_for_verification(&self, guid: &str) -> anyhow::Result<VerificationStatus> {
+        for _ in 0..MAX_POLLS {
+            let status = self.check_status(guid)?;
+            if status != VerificationStatus::Pending {
+                return Ok(status);
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+        anyhow::bail!(MSG_CONTRACTS_VERIFY_TIMEOUT_ERR)
+    }
+}
+
+pub(super) fn run(shell: &Shell, args: ContractsVerifyArgs) -> anyhow::Result<()> {
+    let ecosystem = EcosystemConfig::from_file(shell)?;
+    let chain = ecosystem
+        .load_current_chain()
+        .context(MSG_CHAIN_NOT_FOUND_ERR)?;
+    let network = chain
+        .verification_networks
+        .get(&args.network)
+        .with_context(|| msg_verification_network_not_found(&args.network))?;
+
+    let foundry_path = chain.path_to_l1_foundry();
+    let artifact_path = foundry_path.join(artifact_path(&args.contract));
+    let artifact = shell
+        .read_file(&artifact_path)
+        .with_context(|| msg_contract_artifact_read_err(&artifact_path))?;
+    let artifact: Artifact = serde_json::from_str(&artifact)
+        .with_context(|| msg_contract_artifact_read_err(&artifact_path))?;
+    let (input, contract_name) = standard_json_input(&artifact.metadata, |path| {
+        Ok(shell.read_file(foundry_path.join(path))?)
+    })?;
+    let constructor_args = parse_constructor_args(args.constructor_args.as_deref())?;
+
+    let spinner = Spinner::new(&msg_verifying_contract_spinner(
+        &contract_name,
+        args.address,
+    ));
+    let client = ExplorerClient::new(network);
+    let params = vec![
+        ("module", "contract".to_owned()),
+        ("action", "verifysourcecode".to_owned()),
+        ("contractaddress", format!("{:?}", args.address)),
+        ("sourceCode", input.to_string()),
+        ("codeformat", "solidity-standard-json-input".to_owned()),
+        ("contractname", contract_name.clone()),
+        (
+            "compilerversion",
+            format!("v{}", artifact.metadata.compiler.version),
+        ),
+        // Misspelling is a part of the API.
+        ("constructorArguements", constructor_args),
+    ];
+    let status = match client.submit(params)? {
+        Some(guid) => client.wait_for_verification(&guid)?,
+        None => VerificationStatus::AlreadyVerified,
+    };
+    spinner.finish();
+
+    match status {
+        VerificationStatus::Verified => {
+            logger::outro(msg_contract_verified(&contract_name, args.address))
+        }
+        VerificationStatus::AlreadyVerified => {
+            logger::outro(msg_contract_already_verified(&contract_name, args.address))
+        }
+        VerificationStatus::Failed(reason) => {
+            anyhow::bail!(msg_contract_verification_failed(&contract_name, &reason))
+        }
+        VerificationStatus::Pending => unreachable!("pending status is awaited"),
+    }
+    Ok(())
+}
+
+/// Returns the path to the build artifact for a contract relative to the Foundry project root. The contract
+/// is identified either as `<file>:<name>` or as `<name>`, in which case it's assumed to be defined in `<name>.sol`.
+fn artifact_path(contract: &str) -> PathBuf {
+    let (file_name, name) = match contract.split_once(':') {
+        Some((path, name)) => {
+            let file_name = Path::new(path).file_name().map_or_else(
+                || path.to_owned(),
+                |name| name.to_string_lossy().into_owned(),
+            );
+            (file_name, name)
+        }
+        None => (format!("{contract}.sol"), contract),
+    };
+    Path::new("out")
+        .join(file_name)
+        .join(format!("{name}.json"))
+}
+
+/// Builds the Solidity standard JSON input for the contract from its metadata. Sources are loaded using
+/// `read_source`, which accepts paths relative to the Foundry project root. Returns the input together with
+/// the fully qualified contract name (`<source path>:<name>`) expected by explorers.
+fn standard_json_input(
+    metadata: &ContractMetadata,
+    read_source: impl Fn(&str) -> anyhow::Result<String>,
+) -> anyhow::Result<(Value, String)> {
+    let mut settings = metadata.settings.clone();
+    let target = settings
+        .remove("compilationTarget")
+        .context("contract metadata has no compilation target")?;
+    let (target_path, target_name) = target
+        .as_object()
+        .and_then(|target| target.iter().next())
+        .and_then(|(path, name)| Some((path, name.as_str()?)))
+        .context("malformed compilation target in contract metadata")?;
+    let contract_name = format!("{target_path}:{target_name}");
+
+    // Metadata lists linked libraries as `<path>:<name>` -> address, while the standard JSON input groups them by path.
+    if let Some(libraries) = settings.remove("libraries") {
+        let libraries = libraries
+            .as_object()
+            .context("malformed libraries in contract metadata")?;
+        let mut grouped_libraries = Map::new();
+        for (library, address) in libraries {
+            let (path, name) = library
+                .rsplit_once(':')
+                .with_context(|| format!("malformed library identifier `{library}`"))?;
+            let entry = grouped_libraries
+                .entry(path)
+                .or_insert_with(|| Value::Object(Map::new()));
+            // `entry` is always an object since it's only created above.
+            entry
+                .as_object_mut()
+                .unwrap()
+                .insert(name.to_owned(), address.clone());
+        }
+        settings.insert("libraries".to_owned(), Value::Object(grouped_libraries));
+    }
+
+    let sources = metadata
+        .sources
+        .keys()
+        .map(|path| {
+            let content = read_source(path)
+                .with_context(|| format!("failed reading contract source `{path}`"))?;
+            Ok((path.clone(), json!({ "content": content })))
+        })
+        .collect::<anyhow::Result<Map<_, _>>>()?;
+
+    let input = json!({
+        "language": metadata.language,
+        "sources": sources,
+        "settings": settings,
+    });
+    Ok((input, contract_name))
+}
+
+/// Normalizes hex-encoded ABI-encoded constructor arguments to the format expected by explorers (no `0x` prefix).
+fn parse_constructor_args(args: Option<&str>) -> anyhow::Result<String> {
+    let Some(args) = args else {
+        return Ok(String::new());
+    };
+    let args = args.strip_prefix("0x").unwrap_or(args);
+    anyhow::ensure!(
+        args.len() % 2 == 0 && args.chars().all(|ch| ch.is_ascii_hexdigit()),
+        "constructor args must be a hex string"
+    );
+    Ok(args.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn getting_artifact_path() {
+        assert_eq!(
+            artifact_path("Bridgehub"),
+            Path::new("out/Bridgehub.sol/Bridgehub.json")
+        );
+        assert_eq!(
+            artifact_path("contracts/bridgehub/Bridgehub.sol:Bridgehub"),
+            Path::new("out/Bridgehub.sol/Bridgehub.json")
+        );
+        assert_eq!(
+            artifact_path("Utils.sol:L2ContractHelper"),
+            Path::new("out/Utils.sol/L2ContractHelper.json")
+        );
+    }
+
+    #[test]
+    fn building_standard_json_input() {
+        let artifact = json!({
+            "metadata": {
+                "compiler": { "version": "0.8.24+commit.e11b9ed9" },
+                "language": "Solidity",
+                "settings": {
+                    "compilationTarget": { "contracts/Counter.sol": "Counter" },
+                    "evmVersion": "cancun",
+                    "libraries": {
+                        "contracts/Math.sol:Math": "0x0000000000000000000000000000000000000001",
+                    },
+                    "optimizer": { "enabled": true, "runs": 200 },
+                    "remappings": ["@openzeppelin/=lib/openzeppelin-contracts/"],
+                },
+                "sources": {
+                    "contracts/Counter.sol": { "keccak256": "0x01" },
+                    "contracts/Math.sol": { "keccak256": "0x02" },
+                },
+            },
+        });
+        let artifact: Artifact = serde_json::from_value(artifact).unwrap();
+
+        let (input, contract_name) = standard_json_input(&artifact.metadata, |path| {
+            Ok(format!("// source of {path}"))
+        })
+        .unwrap();
+        assert_eq!(contract_name, "contracts/Counter.sol:Counter");
+        assert_eq!(
+            input,
+            json!({
+                "language": "Solidity",
+                "sources": {
+                    "contracts/Counter.sol": { "content": "// source of contracts/Counter.sol" },
+                    "contracts/Math.sol": { "content": "// source of contracts/Math.sol" },
+                },
+                "settings": {
+                    "evmVersion": "cancun",
+                    "libraries": {
+                        "contracts/Math.sol": {
+                            "Math": "0x0000000000000000000000000000000000000001",
+                        },
+                    },
+                    "optimizer": { "enabled": true, "runs": 200 },
+                    "remappings": ["@openzeppelin/=lib/openzeppelin-contracts/"],
+                },
+            })
+        );
+
+        let err =
+            standard_json_input(&artifact.metadata, |_| anyhow::bail!("no such file")).unwrap_err();
+        assert!(format!("{err:#}").contains("no such file"), "{err:#}");
+    }
+
+    #[test]
+    fn parsing_constructor_args() {
+        assert_eq!(parse_constructor_args(None).unwrap(), "");
+        assert_eq!(parse_constructor_args(Some("0xABcd")).unwrap(), "abcd");
+        assert_eq!(parse_constructor_args(Some("00ff")).unwrap(), "00ff");
+        parse_constructor_args(Some("0xabc")).unwrap_err();
+        parse_constructor_args(Some("xyz0")).unwrap_err();
+    }
+
+    #[test]
+    fn interpreting_explorer_responses() {
+        let response = |status: &str, result: &str| ExplorerResponse {
+            status: status.to_owned(),
+            result: result.to_owned(),
+        };
+        assert_eq!(
+            response("1", "Pass - Verified").verification_status(),
+            VerificationStatus::Verified
+        );
+        assert_eq!(
+            response("0", "Pending in queue").verification_status(),
+            VerificationStatus::Pending
+        );
+        assert_eq!(
+            response("0", "Contract source code already verified").verification_status(),
+            VerificationStatus::AlreadyVerified
+        );
+        assert_eq!(
+            response("0", "Fail - Unable to verify").verification_status(),
+            VerificationStatus::Failed("Fail - Unable to verify".to_owned())
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use super::new_migration::SelectedDatabase;
+use crate::commands::dev::messages::{
+    MSG_DATABASE_EXPORT_DATABASE_HELP, MSG_DATABASE_EXPORT_FROM_FILE_HELP,
+    MSG_DATABASE_EXPORT_TABLES_HELP, MSG_DATABASE_EXPORT_TO_FILE_HELP,
+    MSG_DATABASE_EXPORT_URL_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct DatabaseExportArgs {
+    #[clap(long, value_enum, default_value_t = SelectedDatabase::Core, help = MSG_DATABASE_EXPORT_DATABASE_HELP)]
+    pub database: SelectedDatabase,
+    #[clap(long, help = MSG_DATABASE_EXPORT_URL_HELP)]
+    pub url: Option<String>,
+    #[clap(long, value_delimiter = ',', required = true, help = MSG_DATABASE_EXPORT_TABLES_HELP)]
+    pub tables: Vec<String>,
+    #[clap(long, help = MSG_DATABASE_EXPORT_TO_FILE_HELP)]
+    pub to_file: PathBuf,
+}
+
+#[derive(Debug, Parser)]
+pub struct DatabaseImportArgs {
+    #[clap(long, value_enum, default_value_t = SelectedDatabase::Core, help = MSG_DATABASE_EXPORT_DATABASE_HELP)]
+    pub database: SelectedDatabase,
+    #[clap(long, help = MSG_DATABASE_EXPORT_URL_HELP)]
+    pub url: Option<String>,
+    #[clap(long, help = MSG_DATABASE_EXPORT_FROM_FILE_HELP)]
+    pub from_file: PathBuf,
+}
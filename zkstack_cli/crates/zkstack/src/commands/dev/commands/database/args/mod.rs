@@ -5,9 +5,11 @@ use crate::commands::dev::{
     messages::{
         MSG_DATABASE_COMMON_CORE_HELP, MSG_DATABASE_COMMON_CORE_URL_HELP,
         MSG_DATABASE_COMMON_PROVER_HELP, MSG_DATABASE_COMMON_PROVER_URL_HELP,
+        MSG_DATABASE_MIGRATE_DRY_RUN_HELP, MSG_DATABASE_MIGRATE_PARALLEL_HELP,
     },
 };
 
+pub mod export;
 pub mod new_migration;
 
 #[derive(Debug, Parser)]
@@ -65,3 +67,13 @@ pub struct DatabaseCommonArgsFinal {
     pub selected_dals: SelectedDals,
     pub urls: DalUrls,
 }
+
+#[derive(Debug, Parser)]
+pub struct DatabaseMigrateArgs {
+    #[clap(flatten)]
+    pub common: DatabaseCommonArgs,
+    #[clap(long, help = MSG_DATABASE_MIGRATE_DRY_RUN_HELP)]
+    pub dry_run: bool,
+    #[clap(long, help = MSG_DATABASE_MIGRATE_PARALLEL_HELP)]
+    pub parallel: bool,
+}
@@ -5,6 +5,7 @@ use zkstack_cli_common::{Prompt, PromptSelect};
 use crate::commands::dev::messages::{
     MSG_DATABASE_NEW_MIGRATION_DATABASE_HELP, MSG_DATABASE_NEW_MIGRATION_DB_PROMPT,
     MSG_DATABASE_NEW_MIGRATION_NAME_HELP, MSG_DATABASE_NEW_MIGRATION_NAME_PROMPT,
+    MSG_DATABASE_NEW_MIGRATION_TEMPLATE_HELP,
 };
 
 #[derive(Debug, Parser)]
@@ -13,6 +14,8 @@ pub struct DatabaseNewMigrationArgs {
     pub database: Option<SelectedDatabase>,
     #[clap(long, help = MSG_DATABASE_NEW_MIGRATION_NAME_HELP)]
     pub name: Option<String>,
+    #[clap(long, value_enum, help = MSG_DATABASE_NEW_MIGRATION_TEMPLATE_HELP)]
+    pub template: Option<MigrationTemplate>,
 }
 
 impl DatabaseNewMigrationArgs {
@@ -31,6 +34,7 @@ impl DatabaseNewMigrationArgs {
         DatabaseNewMigrationArgsFinal {
             selected_database,
             name,
+            template: self.template,
         }
     }
 }
@@ -39,6 +43,7 @@ impl DatabaseNewMigrationArgs {
 pub struct DatabaseNewMigrationArgsFinal {
     pub selected_database: SelectedDatabase,
     pub name: String,
+    pub template: Option<MigrationTemplate>,
 }
 
 #[derive(Debug, Clone, ValueEnum, EnumIter, PartialEq, Eq, Display)]
@@ -46,3 +51,11 @@ pub enum SelectedDatabase {
     Prover,
     Core,
 }
+
+/// Skeleton for the SQL statements of a new migration.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+pub enum MigrationTemplate {
+    AddColumn,
+    AddIndex,
+    AddTable,
+}
@@ -1,3 +1,4 @@
+use serde_json::json;
 use xshell::Shell;
 use zkstack_cli_common::{
     db::{drop_db_if_exists, DatabaseConfig},
@@ -5,19 +6,24 @@ use zkstack_cli_common::{
     spinner::Spinner,
 };
 
-use super::args::DatabaseCommonArgs;
+use super::{args::DatabaseCommonArgs, output::DatabaseOutput};
 use crate::commands::dev::{
     dals::{get_dals, Dal},
     messages::{
-        msg_database_info, msg_database_loading, msg_database_success, MSG_DATABASE_DROP_GERUND,
-        MSG_DATABASE_DROP_PAST, MSG_NO_DATABASES_SELECTED,
+        msg_database_dal_success, msg_database_info, msg_database_loading, msg_database_success,
+        MSG_DATABASE_DROP_GERUND, MSG_DATABASE_DROP_PAST, MSG_NO_DATABASES_SELECTED,
     },
 };
 
-pub async fn run(shell: &Shell, args: DatabaseCommonArgs) -> anyhow::Result<()> {
+pub async fn run(
+    shell: &Shell,
+    args: DatabaseCommonArgs,
+    output: DatabaseOutput,
+) -> anyhow::Result<()> {
     let args = args.parse();
     if args.selected_dals.none() {
         logger::outro(MSG_NO_DATABASES_SELECTED);
+        output.ok(MSG_NO_DATABASES_SELECTED, json!({}));
         return Ok(());
     }
 
@@ -25,10 +31,16 @@ pub async fn run(shell: &Shell, args: DatabaseCommonArgs) -> anyhow::Result<()>
 
     let dals = get_dals(shell, &args.selected_dals, &args.urls).await?;
     for dal in dals {
+        let dal_path = dal.path.clone();
         drop_database(dal).await?;
+        output.ok(
+            msg_database_dal_success(MSG_DATABASE_DROP_PAST, &dal_path),
+            json!({ "dal": dal_path }),
+        );
     }
 
     logger::outro(msg_database_success(MSG_DATABASE_DROP_PAST));
+    output.ok(msg_database_success(MSG_DATABASE_DROP_PAST), json!({}));
 
     Ok(())
 }
@@ -0,0 +1,249 @@
+use std::{
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::{self, Stdio},
+    thread,
+};
+
+use serde_json::json;
+use xshell::{cmd, Shell};
+use zkstack_cli_common::{
+    cmd::{Cmd, CmdError},
+    logger,
+    spinner::Spinner,
+};
+
+use super::{
+    args::{
+        export::{DatabaseExportArgs, DatabaseImportArgs},
+        new_migration::SelectedDatabase,
+    },
+    output::DatabaseOutput,
+};
+use crate::commands::dev::{
+    dals::{get_core_dal, get_prover_dal, Dal},
+    messages::{
+        msg_database_exported, msg_database_exporting, msg_database_imported,
+        msg_database_importing,
+    },
+};
+
+/// Error exporting a database dump or importing it back.
+#[derive(Debug, thiserror::Error)]
+pub enum ExportError {
+    #[error("invalid table name `{0}`; only ASCII letters, digits, `_` and `.` are allowed")]
+    InvalidTableName(String),
+    #[error("file `{}` already exists", .0.display())]
+    FileExists(PathBuf),
+    #[error("file `{}` does not exist", .0.display())]
+    FileNotFound(PathBuf),
+    #[error("failed to get the database URL")]
+    DatabaseUrl(#[source] anyhow::Error),
+    #[error("`{command}` failed")]
+    Command {
+        command: &'static str,
+        #[source]
+        source: CmdError,
+    },
+}
+
+impl ExportError {
+    fn command(command: &'static str) -> impl FnOnce(CmdError) -> Self {
+        move |source| Self::Command { command, source }
+    }
+}
+
+async fn get_dal(
+    shell: &Shell,
+    database: SelectedDatabase,
+    url: Option<String>,
+) -> Result<Dal, ExportError> {
+    let dal = match database {
+        SelectedDatabase::Core => get_core_dal(shell, url).await,
+        SelectedDatabase::Prover => get_prover_dal(shell, url).await,
+    };
+    dal.map_err(ExportError::DatabaseUrl)
+}
+
+/// Converts table names to `pg_dump` arguments, checking that the names cannot be interpreted as patterns.
+fn pg_dump_table_args(tables: &[String]) -> Result<Vec<String>, ExportError> {
+    tables
+        .iter()
+        .map(|table| {
+            let is_valid = !table.is_empty()
+                && table
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '.');
+            if is_valid {
+                Ok(format!("--table={table}"))
+            } else {
+                Err(ExportError::InvalidTableName(table.clone()))
+            }
+        })
+        .collect()
+}
+
+pub async fn run_export(
+    shell: &Shell,
+    args: DatabaseExportArgs,
+    output: DatabaseOutput,
+) -> Result<(), ExportError> {
+    let table_args = pg_dump_table_args(&args.tables)?;
+    if shell.path_exists(&args.to_file) {
+        return Err(ExportError::FileExists(args.to_file));
+    }
+    let dal = get_dal(shell, args.database, args.url).await?;
+
+    let spinner = Spinner::new(&msg_database_exporting(&dal.path));
+    let url = dal.url.as_str();
+    let to_file = &args.to_file;
+    // `--clean` makes the dump drop the tables before recreating them, so that it can be imported
+    // into a database that already has them. For the plain format, `--compress` gzips the entire output.
+    let dump_cmd = cmd!(
+        shell,
+        "pg_dump --dbname={url} {table_args...} --clean --if-exists --no-owner --no-privileges --compress=9 --file={to_file}"
+    );
+    Cmd::new(dump_cmd)
+        .run()
+        .map_err(ExportError::command("pg_dump"))?;
+    spinner.finish();
+
+    let message = msg_database_exported(&dal.path, to_file);
+    logger::outro(&message);
+    output.ok(
+        message,
+        json!({ "dal": dal.path, "tables": args.tables, "file": to_file }),
+    );
+    Ok(())
+}
+
+pub async fn run_import(
+    shell: &Shell,
+    args: DatabaseImportArgs,
+    output: DatabaseOutput,
+) -> Result<(), ExportError> {
+    if !shell.path_exists(&args.from_file) {
+        return Err(ExportError::FileNotFound(args.from_file));
+    }
+    let dal = get_dal(shell, args.database, args.url).await?;
+
+    let spinner = Spinner::new(&msg_database_importing(&dal.path));
+    let from_file = &args.from_file;
+    import_dump(shell, from_file, dal.url.as_str())?;
+    spinner.finish();
+
+    let message = msg_database_imported(&dal.path, from_file);
+    logger::outro(&message);
+    output.ok(message, json!({ "dal": dal.path, "file": from_file }));
+    Ok(())
+}
+
+/// Decompresses the dump at `from_file` and streams it to `psql`, so that the dump is never loaded into memory as a whole.
+fn import_dump(shell: &Shell, from_file: &Path, url: &str) -> Result<(), ExportError> {
+    let gzip_cmd = cmd!(shell, "gzip --decompress --stdout {from_file}");
+    let gzip_txt = gzip_cmd.to_string();
+    let mut gzip = process::Command::from(gzip_cmd)
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|err| ExportError::command("gzip")(err.into()))?;
+    let psql_cmd = cmd!(
+        shell,
+        "psql {url} --quiet --single-transaction --set=ON_ERROR_STOP=1"
+    );
+    let psql_txt = psql_cmd.to_string();
+    let psql = process::Command::from(psql_cmd)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut psql = match psql {
+        Ok(psql) => psql,
+        Err(err) => {
+            gzip.kill().ok();
+            gzip.wait().ok();
+            return Err(ExportError::command("psql")(err.into()));
+        }
+    };
+
+    // `psql` stderr is drained concurrently; otherwise, `psql` could block writing to a full pipe.
+    let mut psql_stderr = psql.stderr.take().expect("psql stderr is piped");
+    let stderr_task = thread::spawn(move || {
+        let mut stderr = vec![];
+        psql_stderr.read_to_end(&mut stderr).map(|_| stderr)
+    });
+
+    let mut gzip_stdout = gzip.stdout.take().expect("gzip stdout is piped");
+    let mut psql_stdin = psql.stdin.take().expect("psql stdin is piped");
+    let copy_result = io::copy(&mut gzip_stdout, &mut psql_stdin);
+    // Closing the pipe terminates `gzip` if copying was aborted.
+    drop(gzip_stdout);
+    let gzip_status = gzip
+        .wait()
+        .map_err(|err| ExportError::command("gzip")(err.into()))?;
+    if copy_result.is_err() || !gzip_status.success() {
+        // Closing stdin would make `psql` commit a partially imported dump, so it's killed instead;
+        // this rolls back the transaction.
+        psql.kill().ok();
+    }
+    drop(psql_stdin);
+    let psql_status = psql
+        .wait()
+        .map_err(|err| ExportError::command("psql")(err.into()))?;
+    let psql_stderr = stderr_task
+        .join()
+        .expect("reading psql stderr panicked")
+        .unwrap_or_default();
+
+    let psql_err = || ExportError::Command {
+        command: "psql",
+        source: CmdError {
+            stderr: Some(String::from_utf8_lossy(&psql_stderr).into_owned()),
+            source: anyhow::anyhow!("Command failed to run: {psql_txt}"),
+        },
+    };
+    if let Err(err) = copy_result {
+        // If `psql` has exited early (e.g., on an SQL error), its error is more informative than a broken pipe.
+        return Err(
+            if err.kind() == io::ErrorKind::BrokenPipe && !psql_status.success() {
+                psql_err()
+            } else {
+                ExportError::command("psql")(err.into())
+            },
+        );
+    }
+    if !gzip_status.success() {
+        return Err(ExportError::Command {
+            command: "gzip",
+            source: CmdError {
+                stderr: None,
+                source: anyhow::anyhow!("Command failed to run: {gzip_txt}"),
+            },
+        });
+    }
+    if !psql_status.success() {
+        return Err(psql_err());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converting_tables_to_pg_dump_args() {
+        let tables = ["transactions".to_owned(), "public.miniblocks".to_owned()];
+        assert_eq!(
+            pg_dump_table_args(&tables).unwrap(),
+            ["--table=transactions", "--table=public.miniblocks"]
+        );
+
+        for table in ["", "miniblocks*", "l1_batches; DROP TABLE transactions"] {
+            let err = pg_dump_table_args(&[table.to_owned()]).unwrap_err();
+            assert!(
+                matches!(&err, ExportError::InvalidTableName(name) if name == table),
+                "{err:?}"
+            );
+        }
+    }
+}
@@ -1,49 +1,169 @@
 use std::path::Path;
 
+use serde_json::json;
 use xshell::{cmd, Shell};
-use zkstack_cli_common::{cmd::Cmd, logger, spinner::Spinner};
+use zkstack_cli_common::{cmd::Cmd, db::pending_migrations, logger, spinner::Spinner};
 use zkstack_cli_config::EcosystemConfig;
 
-use super::args::DatabaseCommonArgs;
+use super::{
+    args::DatabaseMigrateArgs,
+    output::{DatabaseOutput, ReportedError},
+};
 use crate::commands::dev::{
     dals::{get_dals, Dal},
     messages::{
-        msg_database_info, msg_database_loading, msg_database_success, MSG_DATABASE_MIGRATE_GERUND,
+        msg_database_dal_migration_failed, msg_database_dal_success, msg_database_info,
+        msg_database_loading, msg_database_migration_failed_dals,
+        msg_database_no_pending_migrations, msg_database_pending_migration, msg_database_success,
+        MSG_DATABASE_MIGRATE_DRY_RUN_GERUND, MSG_DATABASE_MIGRATE_DRY_RUN_PAST,
+        MSG_DATABASE_MIGRATE_GERUND, MSG_DATABASE_MIGRATE_PARALLEL_DRY_RUN_WARNING,
         MSG_DATABASE_MIGRATE_PAST, MSG_NO_DATABASES_SELECTED,
     },
 };
 
-pub async fn run(shell: &Shell, args: DatabaseCommonArgs) -> anyhow::Result<()> {
-    let args = args.parse();
+pub async fn run(
+    shell: &Shell,
+    args: DatabaseMigrateArgs,
+    output: DatabaseOutput,
+) -> anyhow::Result<()> {
+    let dry_run = args.dry_run;
+    let parallel = args.parallel;
+    let args = args.common.parse();
     if args.selected_dals.none() {
         logger::outro(MSG_NO_DATABASES_SELECTED);
+        output.ok(MSG_NO_DATABASES_SELECTED, json!({}));
         return Ok(());
     }
 
-    logger::info(msg_database_info(MSG_DATABASE_MIGRATE_GERUND));
+    let (gerund, past) = if dry_run {
+        (
+            MSG_DATABASE_MIGRATE_DRY_RUN_GERUND,
+            MSG_DATABASE_MIGRATE_DRY_RUN_PAST,
+        )
+    } else {
+        (MSG_DATABASE_MIGRATE_GERUND, MSG_DATABASE_MIGRATE_PAST)
+    };
+    logger::info(msg_database_info(gerund));
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
 
     let dals = get_dals(shell, &args.selected_dals, &args.urls).await?;
+    if parallel && dry_run {
+        logger::warn(MSG_DATABASE_MIGRATE_PARALLEL_DRY_RUN_WARNING);
+    } else if parallel {
+        migrate_databases_in_parallel(shell, &ecosystem_config.link_to_code, dals, output).await?;
+        logger::outro(msg_database_success(past));
+        output.ok(msg_database_success(past), json!({}));
+        return Ok(());
+    }
+
     for dal in dals {
-        migrate_database(shell, &ecosystem_config.link_to_code, dal)?;
+        if dry_run {
+            print_pending_migrations(shell, &ecosystem_config.link_to_code, dal, output).await?;
+        } else {
+            let dal_path = dal.path.clone();
+            migrate_database(shell, &ecosystem_config.link_to_code, dal)?;
+            output.ok(
+                msg_database_dal_success(past, &dal_path),
+                json!({ "dal": dal_path }),
+            );
+        }
     }
 
-    logger::outro(msg_database_success(MSG_DATABASE_MIGRATE_PAST));
+    logger::outro(msg_database_success(past));
+    output.ok(msg_database_success(past), json!({}));
 
     Ok(())
 }
 
 fn migrate_database(shell: &Shell, link_to_code: impl AsRef<Path>, dal: Dal) -> anyhow::Result<()> {
-    let dir = link_to_code.as_ref().join(&dal.path);
-    let _dir_guard = shell.push_dir(dir);
-    let url = dal.url.as_str();
-
     let spinner = Spinner::new(&msg_database_loading(
         MSG_DATABASE_MIGRATE_GERUND,
         &dal.path,
     ));
-    Cmd::new(cmd!(shell, "cargo sqlx migrate run --database-url {url}")).run()?;
+    run_migrations(shell, link_to_code, &dal)?;
     spinner.finish();
 
     Ok(())
 }
+
+fn run_migrations(shell: &Shell, link_to_code: impl AsRef<Path>, dal: &Dal) -> anyhow::Result<()> {
+    let dir = link_to_code.as_ref().join(&dal.path);
+    let _dir_guard = shell.push_dir(dir);
+    let url = dal.url.as_str();
+    Cmd::new(cmd!(shell, "cargo sqlx migrate run --database-url {url}")).run()?;
+    Ok(())
+}
+
+/// Applies migrations to all `dals` concurrently. Unlike the sequential mode, a failure for one DAL
+/// doesn't prevent migrating the others; all errors are reported once every task has finished.
+async fn migrate_databases_in_parallel(
+    shell: &Shell,
+    link_to_code: &Path,
+    dals: Vec<Dal>,
+    output: DatabaseOutput,
+) -> anyhow::Result<()> {
+    // Spinners would interleave with each other, so progress is reported with plain log lines instead.
+    let mut tasks = Vec::with_capacity(dals.len());
+    for dal in dals {
+        logger::info(msg_database_loading(MSG_DATABASE_MIGRATE_GERUND, &dal.path));
+        // Each task gets its own shell so that directory changes don't race with each other.
+        let shell = shell.clone();
+        let link_to_code = link_to_code.to_path_buf();
+        let dal_path = dal.path.clone();
+        let task = tokio::task::spawn_blocking(move || run_migrations(&shell, link_to_code, &dal));
+        tasks.push((dal_path, task));
+    }
+
+    let mut failed_dals = vec![];
+    for (dal_path, task) in tasks {
+        let result = match task.await {
+            Ok(result) => result,
+            Err(err) => Err(anyhow::anyhow!("migration task panicked: {err}")),
+        };
+        match result {
+            Ok(()) => {
+                let message = msg_database_dal_success(MSG_DATABASE_MIGRATE_PAST, &dal_path);
+                logger::info(&message);
+                output.ok(message, json!({ "dal": dal_path }));
+            }
+            Err(err) => {
+                let message = msg_database_dal_migration_failed(&dal_path, &err);
+                logger::error(&message);
+                output.error(message, json!({ "dal": dal_path }));
+                failed_dals.push(dal_path);
+            }
+        }
+    }
+
+    if !failed_dals.is_empty() {
+        // Errors for each DAL are already in the output.
+        return Err(ReportedError(msg_database_migration_failed_dals(&failed_dals)).into());
+    }
+    Ok(())
+}
+
+async fn print_pending_migrations(
+    shell: &Shell,
+    link_to_code: impl AsRef<Path>,
+    dal: Dal,
+    output: DatabaseOutput,
+) -> anyhow::Result<()> {
+    let migrations_folder = link_to_code.as_ref().join(&dal.path).join("migrations");
+    let migrations = pending_migrations(shell, migrations_folder, &dal.url).await?;
+
+    if migrations.is_empty() {
+        let message = msg_database_no_pending_migrations(&dal.path);
+        logger::info(&message);
+        output.ok(message, json!({ "dal": dal.path }));
+    }
+    for migration in migrations {
+        let message = msg_database_pending_migration(
+            migration.version,
+            &migration.description,
+            &migration.checksum,
+        );
+        logger::note(&message, &migration.sql);
+        output.ok(message, json!({ "dal": dal.path, "migration": migration }));
+    }
+    Ok(())
+}
@@ -1,21 +1,32 @@
-use clap::Subcommand;
+use clap::{Parser, Subcommand};
 use xshell::Shell;
 
-use self::args::{new_migration::DatabaseNewMigrationArgs, DatabaseCommonArgs};
+use self::{
+    args::{
+        export::{DatabaseExportArgs, DatabaseImportArgs},
+        new_migration::DatabaseNewMigrationArgs,
+        DatabaseCommonArgs, DatabaseMigrateArgs,
+    },
+    output::DatabaseOutput,
+};
 use crate::commands::dev::messages::{
-    MSG_DATABASE_CHECK_SQLX_DATA_ABOUT, MSG_DATABASE_DROP_ABOUT, MSG_DATABASE_MIGRATE_ABOUT,
+    MSG_DATABASE_CHECK_SQLX_DATA_ABOUT, MSG_DATABASE_DROP_ABOUT, MSG_DATABASE_EXPORT_ABOUT,
+    MSG_DATABASE_IMPORT_ABOUT, MSG_DATABASE_JSON_HELP, MSG_DATABASE_MIGRATE_ABOUT,
     MSG_DATABASE_NEW_MIGRATION_ABOUT, MSG_DATABASE_PREPARE_ABOUT, MSG_DATABASE_RESET_ABOUT,
-    MSG_DATABASE_SETUP_ABOUT,
+    MSG_DATABASE_SETUP_ABOUT, MSG_DATABASE_VERIFY_SCHEMA_ABOUT,
 };
 
 pub mod args;
 mod check_sqlx_data;
 mod drop;
+mod export;
 mod migrate;
 mod new_migration;
+pub mod output;
 mod prepare;
 pub mod reset;
 mod setup;
+mod verify_schema;
 
 #[derive(Subcommand, Debug)]
 pub enum DatabaseCommands {
@@ -23,8 +34,12 @@ pub enum DatabaseCommands {
     CheckSqlxData(DatabaseCommonArgs),
     #[clap(about = MSG_DATABASE_DROP_ABOUT)]
     Drop(DatabaseCommonArgs),
+    #[clap(about = MSG_DATABASE_EXPORT_ABOUT)]
+    Export(DatabaseExportArgs),
+    #[clap(about = MSG_DATABASE_IMPORT_ABOUT)]
+    Import(DatabaseImportArgs),
     #[clap(about = MSG_DATABASE_MIGRATE_ABOUT)]
-    Migrate(DatabaseCommonArgs),
+    Migrate(DatabaseMigrateArgs),
     #[clap(about = MSG_DATABASE_NEW_MIGRATION_ABOUT)]
     NewMigration(DatabaseNewMigrationArgs),
     #[clap(about = MSG_DATABASE_PREPARE_ABOUT)]
@@ -33,16 +48,38 @@ pub enum DatabaseCommands {
     Reset(DatabaseCommonArgs),
     #[clap(about = MSG_DATABASE_SETUP_ABOUT)]
     Setup(DatabaseCommonArgs),
+    #[clap(about = MSG_DATABASE_VERIFY_SCHEMA_ABOUT)]
+    VerifySchema(DatabaseCommonArgs),
+}
+
+#[derive(Parser, Debug)]
+pub struct DatabaseArgs {
+    #[clap(long, global = true, help = MSG_DATABASE_JSON_HELP)]
+    pub json: bool,
+    #[command(subcommand)]
+    pub command: DatabaseCommands,
 }
 
-pub async fn run(shell: &Shell, args: DatabaseCommands) -> anyhow::Result<()> {
-    match args {
-        DatabaseCommands::CheckSqlxData(args) => check_sqlx_data::run(shell, args).await,
-        DatabaseCommands::Drop(args) => drop::run(shell, args).await,
-        DatabaseCommands::Migrate(args) => migrate::run(shell, args).await,
-        DatabaseCommands::NewMigration(args) => new_migration::run(shell, args).await,
-        DatabaseCommands::Prepare(args) => prepare::run(shell, args).await,
-        DatabaseCommands::Reset(args) => reset::run(shell, args).await,
-        DatabaseCommands::Setup(args) => setup::run(shell, args).await,
+pub async fn run(shell: &Shell, args: DatabaseArgs) -> anyhow::Result<()> {
+    let output = DatabaseOutput::new(args.json);
+    let result = match args.command {
+        DatabaseCommands::CheckSqlxData(args) => check_sqlx_data::run(shell, args, output).await,
+        DatabaseCommands::Drop(args) => drop::run(shell, args, output).await,
+        DatabaseCommands::Export(args) => export::run_export(shell, args, output)
+            .await
+            .map_err(Into::into),
+        DatabaseCommands::Import(args) => export::run_import(shell, args, output)
+            .await
+            .map_err(Into::into),
+        DatabaseCommands::Migrate(args) => migrate::run(shell, args, output).await,
+        DatabaseCommands::NewMigration(args) => new_migration::run(shell, args, output).await,
+        DatabaseCommands::Prepare(args) => prepare::run(shell, args, output).await,
+        DatabaseCommands::Reset(args) => reset::run(shell, args, output).await,
+        DatabaseCommands::Setup(args) => setup::run(shell, args, output).await,
+        DatabaseCommands::VerifySchema(args) => verify_schema::run(shell, args, output).await,
+    };
+    if let Err(err) = &result {
+        output.report_error(err);
     }
+    result
 }
@@ -1,16 +1,28 @@
 use std::path::Path;
 
+use anyhow::Context as _;
+use serde_json::json;
 use xshell::{cmd, Shell};
 use zkstack_cli_common::{cmd::Cmd, logger, spinner::Spinner};
 use zkstack_cli_config::EcosystemConfig;
 
-use super::args::new_migration::{DatabaseNewMigrationArgs, SelectedDatabase};
+use super::{
+    args::new_migration::{DatabaseNewMigrationArgs, MigrationTemplate, SelectedDatabase},
+    output::DatabaseOutput,
+};
 use crate::commands::dev::{
     dals::{get_core_dal, get_prover_dal, Dal},
-    messages::{msg_database_new_migration_loading, MSG_DATABASE_NEW_MIGRATION_SUCCESS},
+    messages::{
+        msg_database_new_migration_loading, msg_database_new_migration_not_found,
+        MSG_DATABASE_NEW_MIGRATION_SUCCESS,
+    },
 };
 
-pub async fn run(shell: &Shell, args: DatabaseNewMigrationArgs) -> anyhow::Result<()> {
+pub async fn run(
+    shell: &Shell,
+    args: DatabaseNewMigrationArgs,
+    output: DatabaseOutput,
+) -> anyhow::Result<()> {
     let args = args.fill_values_with_prompt();
 
     let dal = match args.selected_database {
@@ -19,9 +31,20 @@ pub async fn run(shell: &Shell, args: DatabaseNewMigrationArgs) -> anyhow::Resul
     };
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
 
-    generate_migration(shell, ecosystem_config.link_to_code, dal, args.name)?;
+    let dal_path = dal.path.clone();
+    generate_migration(
+        shell,
+        ecosystem_config.link_to_code,
+        dal,
+        args.name.clone(),
+        args.template,
+    )?;
 
     logger::outro(MSG_DATABASE_NEW_MIGRATION_SUCCESS);
+    output.ok(
+        MSG_DATABASE_NEW_MIGRATION_SUCCESS,
+        json!({ "dal": dal_path, "migration": args.name }),
+    );
 
     Ok(())
 }
@@ -31,13 +54,107 @@ fn generate_migration(
     link_to_code: impl AsRef<Path>,
     dal: Dal,
     name: String,
+    template: Option<MigrationTemplate>,
 ) -> anyhow::Result<()> {
     let dir = link_to_code.as_ref().join(&dal.path);
     let _dir_guard = shell.push_dir(dir);
 
     let spinner = Spinner::new(&msg_database_new_migration_loading(&dal.path));
     Cmd::new(cmd!(shell, "cargo sqlx migrate add -r {name}")).run()?;
+    if let Some(template) = template {
+        fill_migration(shell, &name, template)?;
+    }
     spinner.finish();
 
     Ok(())
 }
+
+/// Overwrites the empty files of the just created migration with the template SQL.
+fn fill_migration(shell: &Shell, name: &str, template: MigrationTemplate) -> anyhow::Result<()> {
+    let file_names = shell
+        .read_dir("migrations")?
+        .into_iter()
+        .filter_map(|path| Some(path.file_name()?.to_str()?.to_owned()));
+    let migration = latest_migration(file_names, name)
+        .with_context(|| msg_database_new_migration_not_found(name))?;
+
+    let (up_sql, down_sql) = template_sql(template);
+    shell.write_file(format!("migrations/{migration}.up.sql"), up_sql)?;
+    shell.write_file(format!("migrations/{migration}.down.sql"), down_sql)?;
+    Ok(())
+}
+
+/// Returns the full name (i.e., `{timestamp}_{name}`) of the newest migration with the specified name.
+fn latest_migration(file_names: impl Iterator<Item = String>, name: &str) -> Option<String> {
+    file_names
+        .filter_map(|file_name| {
+            let migration = file_name.strip_suffix(".up.sql")?;
+            let (timestamp, migration_name) = migration.split_once('_')?;
+            let is_match = migration_name == name
+                && !timestamp.is_empty()
+                && timestamp.bytes().all(|byte| byte.is_ascii_digit());
+            is_match.then(|| migration.to_owned())
+        })
+        .max()
+}
+
+/// Returns up and down SQL for the template. Statements are idempotent so that a partially applied migration
+/// can be re-run.
+fn template_sql(template: MigrationTemplate) -> (&'static str, &'static str) {
+    match template {
+        MigrationTemplate::AddColumn => (
+            "ALTER TABLE table_name ADD COLUMN IF NOT EXISTS column_name BIGINT;\n",
+            "ALTER TABLE table_name DROP COLUMN IF EXISTS column_name;\n",
+        ),
+        MigrationTemplate::AddIndex => (
+            "CREATE INDEX IF NOT EXISTS table_name_column_name_idx ON table_name (column_name);\n",
+            "DROP INDEX IF EXISTS table_name_column_name_idx;\n",
+        ),
+        MigrationTemplate::AddTable => (
+            "CREATE TABLE IF NOT EXISTS table_name (\n    \
+                 id BIGSERIAL PRIMARY KEY,\n    \
+                 created_at TIMESTAMP NOT NULL,\n    \
+                 updated_at TIMESTAMP NOT NULL\n\
+             );\n",
+            "DROP TABLE IF EXISTS table_name;\n",
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finding_latest_migration() {
+        let file_names = [
+            "20240101000000_add_foo.up.sql",
+            "20240101000000_add_foo.down.sql",
+            "20250101000000_add_foo.up.sql",
+            "20250101000000_add_foo.down.sql",
+            "20260101000000_add_foo_bar.up.sql",
+            "20260101000000_readd_foo.up.sql",
+            "20260101000000_x_add_foo.up.sql",
+        ];
+        let file_names = || file_names.iter().map(|&name| name.to_owned());
+
+        assert_eq!(
+            latest_migration(file_names(), "add_foo").as_deref(),
+            Some("20250101000000_add_foo")
+        );
+        assert_eq!(latest_migration(file_names(), "bar"), None);
+    }
+
+    #[test]
+    fn templates_are_idempotent() {
+        for template in [
+            MigrationTemplate::AddColumn,
+            MigrationTemplate::AddIndex,
+            MigrationTemplate::AddTable,
+        ] {
+            let (up_sql, down_sql) = template_sql(template);
+            assert!(up_sql.contains("IF NOT EXISTS"), "{template:?}");
+            assert!(down_sql.contains("IF EXISTS"), "{template:?}");
+        }
+    }
+}
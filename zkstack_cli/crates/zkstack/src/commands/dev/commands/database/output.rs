@@ -0,0 +1,116 @@
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Error that has already been reported by a subcommand as one or more error records, so that
+/// [`DatabaseOutput::report_error()`] doesn't duplicate it.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct ReportedError(pub String);
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    Ok,
+    Error,
+}
+
+/// Machine-readable output of `database` subcommands.
+///
+/// If enabled, every record is printed to stdout as a single-line JSON object with `status` and `message`
+/// fields plus command-specific ones (e.g., `dal`). Human-readable logs are written to stderr, so they
+/// don't interfere with the records.
+#[derive(Debug, Clone, Copy)]
+pub struct DatabaseOutput {
+    json: bool,
+}
+
+impl DatabaseOutput {
+    pub fn new(json: bool) -> Self {
+        Self { json }
+    }
+
+    /// Reports a successful step. `fields` must be a JSON object.
+    pub fn ok(&self, message: impl Into<String>, fields: Value) {
+        self.print(Status::Ok, message.into(), fields);
+    }
+
+    /// Reports a failure. `fields` must be a JSON object.
+    pub fn error(&self, message: impl Into<String>, fields: Value) {
+        self.print(Status::Error, message.into(), fields);
+    }
+
+    /// Reports an error returned by a subcommand, unless the subcommand has already reported it.
+    pub fn report_error(&self, err: &anyhow::Error) {
+        if let Some(record) = Self::error_record(err) {
+            self.print_record(record);
+        }
+    }
+
+    fn print(&self, status: Status, message: String, fields: Value) {
+        self.print_record(Self::record(status, message, fields));
+    }
+
+    fn print_record(&self, record: Value) {
+        if self.json {
+            println!("{record}");
+        }
+    }
+
+    fn error_record(err: &anyhow::Error) -> Option<Value> {
+        if err.is::<ReportedError>() {
+            return None;
+        }
+        Some(Self::record(Status::Error, format!("{err:#}"), json!({})))
+    }
+
+    fn record(status: Status, message: String, fields: Value) -> Value {
+        let mut record = match fields {
+            Value::Object(fields) => fields,
+            other => panic!("JSON output fields must be an object, got {other}"),
+        };
+        record.insert("status".to_owned(), json!(status));
+        record.insert("message".to_owned(), json!(message));
+        Value::Object(record)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_contains_status_message_and_fields() {
+        let record = DatabaseOutput::record(
+            Status::Ok,
+            "Databases migrated successfully".to_owned(),
+            json!({ "dal": "core/lib/dal" }),
+        );
+        assert_eq!(
+            record,
+            json!({
+                "status": "ok",
+                "message": "Databases migrated successfully",
+                "dal": "core/lib/dal",
+            })
+        );
+
+        let record = DatabaseOutput::record(Status::Error, "boom".to_owned(), json!({}));
+        assert_eq!(record, json!({ "status": "error", "message": "boom" }));
+        // Records must fit on a single line to be newline-delimited.
+        assert!(!record.to_string().contains('\n'));
+    }
+
+    #[test]
+    fn errors_are_reported_once() {
+        let err = anyhow::anyhow!("boom").context("failed migrating");
+        assert_eq!(
+            DatabaseOutput::error_record(&err),
+            Some(json!({ "status": "error", "message": "failed migrating: boom" }))
+        );
+
+        let err = anyhow::Error::new(ReportedError("failed migrating core/lib/dal".to_owned()));
+        assert_eq!(DatabaseOutput::error_record(&err), None);
+        let err = err.context("failed running command");
+        assert_eq!(DatabaseOutput::error_record(&err), None);
+    }
+}
@@ -1,22 +1,28 @@
 use std::path::Path;
 
+use serde_json::json;
 use xshell::{cmd, Shell};
 use zkstack_cli_common::{cmd::Cmd, logger, spinner::Spinner};
 use zkstack_cli_config::EcosystemConfig;
 
-use super::args::DatabaseCommonArgs;
+use super::{args::DatabaseCommonArgs, output::DatabaseOutput};
 use crate::commands::dev::{
     dals::{get_dals, Dal},
     messages::{
-        msg_database_info, msg_database_loading, msg_database_success, MSG_DATABASE_PREPARE_GERUND,
-        MSG_DATABASE_PREPARE_PAST, MSG_NO_DATABASES_SELECTED,
+        msg_database_dal_success, msg_database_info, msg_database_loading, msg_database_success,
+        MSG_DATABASE_PREPARE_GERUND, MSG_DATABASE_PREPARE_PAST, MSG_NO_DATABASES_SELECTED,
     },
 };
 
-pub async fn run(shell: &Shell, args: DatabaseCommonArgs) -> anyhow::Result<()> {
+pub async fn run(
+    shell: &Shell,
+    args: DatabaseCommonArgs,
+    output: DatabaseOutput,
+) -> anyhow::Result<()> {
     let args = args.parse();
     if args.selected_dals.none() {
         logger::outro(MSG_NO_DATABASES_SELECTED);
+        output.ok(MSG_NO_DATABASES_SELECTED, json!({}));
         return Ok(());
     }
 
@@ -26,10 +32,16 @@ pub async fn run(shell: &Shell, args: DatabaseCommonArgs) -> anyhow::Result<()>
 
     let dals = get_dals(shell, &args.selected_dals, &args.urls).await?;
     for dal in dals {
+        let dal_path = dal.path.clone();
         prepare_sqlx_data(shell, &ecosystem_config.link_to_code, dal)?;
+        output.ok(
+            msg_database_dal_success(MSG_DATABASE_PREPARE_PAST, &dal_path),
+            json!({ "dal": dal_path }),
+        );
     }
 
     logger::outro(msg_database_success(MSG_DATABASE_PREPARE_PAST));
+    output.ok(msg_database_success(MSG_DATABASE_PREPARE_PAST), json!({}));
 
     Ok(())
 }
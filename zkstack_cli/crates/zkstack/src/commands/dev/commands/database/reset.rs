@@ -1,22 +1,30 @@
 use std::path::Path;
 
+use serde_json::json;
 use xshell::Shell;
 use zkstack_cli_common::logger;
 use zkstack_cli_config::EcosystemConfig;
 
-use super::{args::DatabaseCommonArgs, drop::drop_database, setup::setup_database};
+use super::{
+    args::DatabaseCommonArgs, drop::drop_database, output::DatabaseOutput, setup::setup_database,
+};
 use crate::commands::dev::{
     dals::{get_dals, Dal},
     messages::{
-        msg_database_info, msg_database_loading, msg_database_success, MSG_DATABASE_RESET_GERUND,
-        MSG_DATABASE_RESET_PAST, MSG_NO_DATABASES_SELECTED,
+        msg_database_dal_success, msg_database_info, msg_database_loading, msg_database_success,
+        MSG_DATABASE_RESET_GERUND, MSG_DATABASE_RESET_PAST, MSG_NO_DATABASES_SELECTED,
     },
 };
 
-pub async fn run(shell: &Shell, args: DatabaseCommonArgs) -> anyhow::Result<()> {
+pub async fn run(
+    shell: &Shell,
+    args: DatabaseCommonArgs,
+    output: DatabaseOutput,
+) -> anyhow::Result<()> {
     let args = args.parse();
     if args.selected_dals.none() {
         logger::outro(MSG_NO_DATABASES_SELECTED);
+        output.ok(MSG_NO_DATABASES_SELECTED, json!({}));
         return Ok(());
     }
 
@@ -27,10 +35,16 @@ pub async fn run(shell: &Shell, args: DatabaseCommonArgs) -> anyhow::Result<()>
     let dals = get_dals(shell, &args.selected_dals, &args.urls).await?;
     for dal in dals {
         logger::info(msg_database_loading(MSG_DATABASE_RESET_GERUND, &dal.path));
+        let dal_path = dal.path.clone();
         reset_database(shell, ecosystem_config.link_to_code.clone(), dal).await?;
+        output.ok(
+            msg_database_dal_success(MSG_DATABASE_RESET_PAST, &dal_path),
+            json!({ "dal": dal_path }),
+        );
     }
 
     logger::outro(msg_database_success(MSG_DATABASE_RESET_PAST));
+    output.ok(msg_database_success(MSG_DATABASE_RESET_PAST), json!({}));
 
     Ok(())
 }
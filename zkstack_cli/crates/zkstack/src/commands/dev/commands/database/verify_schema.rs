@@ -0,0 +1,215 @@
+use std::{collections::BTreeMap, path::Path};
+
+use serde_json::json;
+use xshell::Shell;
+use zkstack_cli_common::{
+    db::{
+        drop_db_if_exists, fetch_columns_schema, init_db, migrate_db, ColumnSchema, DatabaseConfig,
+    },
+    logger,
+    spinner::Spinner,
+};
+use zkstack_cli_config::EcosystemConfig;
+
+use super::{
+    args::DatabaseCommonArgs,
+    output::{DatabaseOutput, ReportedError},
+};
+use crate::commands::dev::{
+    dals::{get_dals, Dal},
+    messages::{
+        msg_database_dal_success, msg_database_info, msg_database_loading,
+        msg_database_schema_column_mismatch, msg_database_schema_missing_column,
+        msg_database_schema_unexpected_column, msg_database_success,
+        MSG_DATABASE_VERIFY_SCHEMA_GERUND, MSG_DATABASE_VERIFY_SCHEMA_MISMATCH_ERR,
+        MSG_DATABASE_VERIFY_SCHEMA_PAST, MSG_NO_DATABASES_SELECTED,
+    },
+};
+
+/// Suffix of the scratch database used to build the reference schema.
+const REFERENCE_DB_SUFFIX: &str = "_verify_schema";
+
+pub async fn run(
+    shell: &Shell,
+    args: DatabaseCommonArgs,
+    output: DatabaseOutput,
+) -> anyhow::Result<()> {
+    let args = args.parse();
+    if args.selected_dals.none() {
+        logger::outro(MSG_NO_DATABASES_SELECTED);
+        output.ok(MSG_NO_DATABASES_SELECTED, json!({}));
+        return Ok(());
+    }
+
+    let ecosystem_config = EcosystemConfig::from_file(shell)?;
+
+    logger::info(msg_database_info(MSG_DATABASE_VERIFY_SCHEMA_GERUND));
+
+    let dals = get_dals(shell, &args.selected_dals, &args.urls).await?;
+    let mut mismatches = vec![];
+    for dal in dals {
+        let dal_path = dal.path.clone();
+        let dal_mismatches = verify_schema(shell, &ecosystem_config.link_to_code, dal).await?;
+        if dal_mismatches.is_empty() {
+            output.ok(
+                msg_database_dal_success(MSG_DATABASE_VERIFY_SCHEMA_PAST, &dal_path),
+                json!({ "dal": dal_path }),
+            );
+        }
+        for mismatch in &dal_mismatches {
+            output.error(mismatch, json!({ "dal": dal_path }));
+        }
+        mismatches.extend(dal_mismatches);
+    }
+
+    if !mismatches.is_empty() {
+        for mismatch in &mismatches {
+            logger::error(mismatch);
+        }
+        // Mismatches are already in the output.
+        return Err(ReportedError(MSG_DATABASE_VERIFY_SCHEMA_MISMATCH_ERR.to_owned()).into());
+    }
+
+    logger::outro(msg_database_success(MSG_DATABASE_VERIFY_SCHEMA_PAST));
+    output.ok(
+        msg_database_success(MSG_DATABASE_VERIFY_SCHEMA_PAST),
+        json!({}),
+    );
+
+    Ok(())
+}
+
+/// Compares the schema of the live database with the schema obtained by applying migrations to a scratch database.
+/// Returns human-readable descriptions of all mismatches.
+async fn verify_schema(
+    shell: &Shell,
+    link_to_code: impl AsRef<Path>,
+    dal: Dal,
+) -> anyhow::Result<Vec<String>> {
+    let spinner = Spinner::new(&msg_database_loading(
+        MSG_DATABASE_VERIFY_SCHEMA_GERUND,
+        &dal.path,
+    ));
+
+    let mut reference_db = DatabaseConfig::from_url(&dal.url)?;
+    reference_db.name.push_str(REFERENCE_DB_SUFFIX);
+    drop_db_if_exists(&reference_db).await?;
+    init_db(&reference_db).await?;
+    let migrations_folder = link_to_code.as_ref().join(&dal.path).join("migrations");
+    let expected = async {
+        migrate_db(shell, migrations_folder, &reference_db.full_url()).await?;
+        fetch_columns_schema(&reference_db.full_url()).await
+    }
+    .await;
+    drop_db_if_exists(&reference_db).await?;
+    let expected = expected?;
+    let actual = fetch_columns_schema(&dal.url).await?;
+
+    spinner.finish();
+    Ok(diff_schemas(&dal.path, expected, actual))
+}
+
+fn diff_schemas(dal: &str, expected: Vec<ColumnSchema>, actual: Vec<ColumnSchema>) -> Vec<String> {
+    let index = |columns: Vec<ColumnSchema>| -> BTreeMap<_, _> {
+        columns
+            .into_iter()
+            .map(|column| {
+                let definition = if column.is_nullable {
+                    format!("{} NULL", column.data_type)
+                } else {
+                    format!("{} NOT NULL", column.data_type)
+                };
+                ((column.table_name, column.column_name), definition)
+            })
+            .collect()
+    };
+    let expected = index(expected);
+    let mut actual = index(actual);
+
+    let mut mismatches = vec![];
+    for ((table, column), expected_definition) in &expected {
+        match actual.remove(&(table.clone(), column.clone())) {
+            None => mismatches.push(msg_database_schema_missing_column(dal, table, column)),
+            Some(actual_definition) if actual_definition != *expected_definition => {
+                mismatches.push(msg_database_schema_column_mismatch(
+                    dal,
+                    table,
+                    column,
+                    expected_definition,
+                    &actual_definition,
+                ));
+            }
+            Some(_) => { /* definitions match */ }
+        }
+    }
+    for (table, column) in actual.keys() {
+        mismatches.push(msg_database_schema_unexpected_column(dal, table, column));
+    }
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(table: &str, column: &str, data_type: &str, is_nullable: bool) -> ColumnSchema {
+        ColumnSchema {
+            table_name: table.to_owned(),
+            column_name: column.to_owned(),
+            data_type: data_type.to_owned(),
+            is_nullable,
+        }
+    }
+
+    fn expected_schema() -> Vec<ColumnSchema> {
+        vec![
+            column("blocks", "number", "bigint", false),
+            column("blocks", "hash", "bytea", true),
+            column("transactions", "hash", "bytea", false),
+        ]
+    }
+
+    #[test]
+    fn matching_schemas() {
+        let mut actual = expected_schema();
+        actual.reverse();
+        assert!(diff_schemas("core", expected_schema(), actual).is_empty());
+    }
+
+    #[test]
+    fn missing_column() {
+        let mut actual = expected_schema();
+        actual.remove(1);
+        let mismatches = diff_schemas("core", expected_schema(), actual);
+        assert_eq!(
+            mismatches,
+            ["[core] Column `blocks.hash` is missing in the database"]
+        );
+    }
+
+    #[test]
+    fn extra_column() {
+        let mut actual = expected_schema();
+        actual.push(column("blocks", "extra", "integer", true));
+        let mismatches = diff_schemas("core", expected_schema(), actual);
+        assert_eq!(
+            mismatches,
+            ["[core] Column `blocks.extra` is not defined by migrations"]
+        );
+    }
+
+    #[test]
+    fn column_type_mismatch() {
+        let mut actual = expected_schema();
+        actual[0].data_type = "integer".to_owned();
+        actual[2].is_nullable = true;
+        let mismatches = diff_schemas("core", expected_schema(), actual);
+        assert_eq!(
+            mismatches,
+            [
+                "[core] Column `blocks.number` has definition `integer NOT NULL`, expected `bigint NOT NULL`",
+                "[core] Column `transactions.hash` has definition `bytea NULL`, expected `bytea NOT NULL`",
+            ]
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use xshell::{cmd, Shell};
 use zkstack_cli_common::{cmd::Cmd, logger, spinner::Spinner};
 use zkstack_cli_config::EcosystemConfig;
@@ -9,65 +9,148 @@ use super::sql_fmt::format_sql;
 use crate::commands::dev::{
     commands::lint_utils::{get_unignored_files, Target},
     messages::{
+        msg_file_is_not_formatted, msg_fmt_check_command_failed, msg_fmt_check_failed,
         msg_running_fmt_for_extension_spinner, msg_running_fmt_for_extensions_spinner,
-        msg_running_rustfmt_for_dir_spinner, MSG_RUNNING_CONTRACTS_FMT_SPINNER,
+        msg_running_rustfmt_for_dir_spinner, MSG_FMT_CHECK_HELP, MSG_FMT_RUST_EDITION_HELP,
+        MSG_RUNNING_CONTRACTS_FMT_SPINNER,
     },
 };
 
-async fn prettier(shell: Shell, target: Target, check: bool) -> anyhow::Result<()> {
+/// Runs a formatter in the check mode and returns the files that need formatting, as extracted from the formatter
+/// output by `parse_files`. Fails if the formatter fails without reporting any files (e.g., due to a syntax error).
+fn run_check(mut cmd: Cmd, parse_files: fn(&str) -> Vec<String>) -> anyhow::Result<Vec<String>> {
+    let output = cmd.run_with_output()?;
+    if output.status.success() {
+        return Ok(vec![]);
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let files = parse_files(&format!("{stdout}\n{stderr}"));
+    if files.is_empty() {
+        anyhow::bail!(msg_fmt_check_command_failed(&stderr));
+    }
+    Ok(files)
+}
+
+/// Extracts files reported by `prettier --check`, which are output as `[warn] <file>` lines.
+fn parse_prettier_check_output(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("[warn] "))
+        // Skip the summary line, e.g. "Code style issues found in 2 files. Run Prettier with --write to fix."
+        .filter(|file| !file.contains(' '))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Extracts files reported by `rustfmt --check --files-with-diff`, which outputs one file path per line.
+fn parse_rustfmt_check_output(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.ends_with(".rs"))
+        .map(str::to_owned)
+        .collect()
+}
+
+async fn prettier(shell: Shell, target: Target, check: bool) -> anyhow::Result<Vec<String>> {
     let spinner = Spinner::new(&msg_running_fmt_for_extension_spinner(target));
     let files = get_unignored_files(&shell, &target, None)?;
 
     if files.is_empty() {
         logger::info(format!("No files for {target} found"));
-        return Ok(());
+        return Ok(vec![]);
     }
 
     spinner.freeze();
     let mode = if check { "--check" } else { "--write" };
     let config = format!("etc/prettier-config/{target}.js");
-    Ok(
-        Cmd::new(cmd!(shell, "yarn --silent prettier {mode} --config {config}").args(files))
-            .run()?,
-    )
+    let cmd = Cmd::new(cmd!(shell, "yarn --silent prettier {mode} --config {config}").args(files));
+    if check {
+        run_check(cmd, parse_prettier_check_output)
+    } else {
+        cmd.run()?;
+        Ok(vec![])
+    }
 }
 
-async fn prettier_contracts(shell: Shell, check: bool) -> anyhow::Result<()> {
+async fn prettier_contracts(shell: Shell, check: bool) -> anyhow::Result<Vec<String>> {
     let spinner = Spinner::new(MSG_RUNNING_CONTRACTS_FMT_SPINNER);
     spinner.freeze();
     let prettier_command = cmd!(shell, "yarn --silent --cwd contracts")
         .arg(format!("prettier:{}", if check { "check" } else { "fix" }));
 
-    Ok(Cmd::new(prettier_command).run()?)
+    let cmd = Cmd::new(prettier_command);
+    if check {
+        run_check(cmd, parse_prettier_check_output)
+    } else {
+        cmd.run()?;
+        Ok(vec![])
+    }
 }
 
-async fn rustfmt(shell: Shell, check: bool, link_to_code: PathBuf) -> anyhow::Result<()> {
+async fn rustfmt(
+    shell: Shell,
+    check: bool,
+    edition: Option<RustEdition>,
+    link_to_code: PathBuf,
+) -> anyhow::Result<Vec<String>> {
+    let mut unformatted_files = vec![];
     for dir in ["core", "prover", "zkstack_cli"] {
         let spinner = Spinner::new(&msg_running_rustfmt_for_dir_spinner(dir));
         let _dir = shell.push_dir(link_to_code.join(dir));
         let mut cmd = cmd!(shell, "cargo fmt -- --config imports_granularity=Crate --config group_imports=StdExternalCrate");
+        if let Some(edition) = edition {
+            cmd = cmd.args(["--edition", edition.as_str()]);
+        }
         if check {
-            cmd = cmd.arg("--check");
+            cmd = cmd.args(["--check", "--files-with-diff"]);
         }
         spinner.freeze();
-        Cmd::new(cmd).run()?;
+        if check {
+            unformatted_files.extend(run_check(Cmd::new(cmd), parse_rustfmt_check_output)?);
+        } else {
+            Cmd::new(cmd).run()?;
+        }
     }
-    Ok(())
+    Ok(unformatted_files)
 }
 
 async fn run_all_rust_formatters(
     shell: Shell,
     check: bool,
+    edition: Option<RustEdition>,
     link_to_code: PathBuf,
-) -> anyhow::Result<()> {
-    format_sql(shell.clone(), check).await?;
-    rustfmt(shell.clone(), check, link_to_code).await?;
-    Ok(())
+) -> anyhow::Result<Vec<String>> {
+    let mut unformatted_files = format_sql(shell.clone(), check).await?;
+    unformatted_files.extend(rustfmt(shell.clone(), check, edition, link_to_code).await?);
+    Ok(unformatted_files)
+}
+
+/// Rust edition passed to `rustfmt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RustEdition {
+    #[value(name = "2021")]
+    Edition2021,
+    #[value(name = "2024")]
+    Edition2024,
+}
+
+impl RustEdition {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Edition2021 => "2021",
+            Self::Edition2024 => "2024",
+        }
+    }
 }
 
 #[derive(Debug, Parser)]
 pub enum Formatter {
-    Rustfmt,
+    Rustfmt {
+        #[arg(long, help = MSG_FMT_RUST_EDITION_HELP)]
+        edition: Option<RustEdition>,
+    },
     Contract,
     Prettier {
         #[arg(short, long)]
@@ -77,7 +160,7 @@ pub enum Formatter {
 
 #[derive(Debug, Parser)]
 pub struct FmtArgs {
-    #[clap(long, short = 'c')]
+    #[clap(long, short = 'c', help = MSG_FMT_CHECK_HELP)]
     pub check: bool,
     #[clap(subcommand)]
     pub formatter: Option<Formatter>,
@@ -86,6 +169,7 @@ pub struct FmtArgs {
 pub async fn run(shell: Shell, args: FmtArgs) -> anyhow::Result<()> {
     let ecosystem = EcosystemConfig::from_file(&shell)?;
     shell.set_var("ZKSYNC_USE_CUDA_STUBS", "true");
+    let mut unformatted_files = vec![];
     match args.formatter {
         None => {
             let mut tasks = vec![];
@@ -98,12 +182,13 @@ pub async fn run(shell: Shell, args: FmtArgs) -> anyhow::Result<()> {
             tasks.push(tokio::spawn(run_all_rust_formatters(
                 shell.clone(),
                 args.check,
+                None,
                 ecosystem.link_to_code,
             )));
             tasks.push(tokio::spawn(prettier_contracts(shell.clone(), args.check)));
 
             for result in futures::future::join_all(tasks).await {
-                result??;
+                unformatted_files.extend(result??);
             }
         }
         Some(Formatter::Prettier { mut targets }) => {
@@ -112,14 +197,65 @@ pub async fn run(shell: Shell, args: FmtArgs) -> anyhow::Result<()> {
             }
             let spinner = Spinner::new(&msg_running_fmt_for_extensions_spinner(&targets));
             for target in targets {
-                prettier(shell.clone(), target, args.check).await?
+                unformatted_files.extend(prettier(shell.clone(), target, args.check).await?);
             }
             spinner.finish()
         }
-        Some(Formatter::Rustfmt) => {
-            run_all_rust_formatters(shell.clone(), args.check, ".".into()).await?
+        Some(Formatter::Rustfmt { edition }) => {
+            unformatted_files =
+                run_all_rust_formatters(shell.clone(), args.check, edition, ".".into()).await?;
+        }
+        Some(Formatter::Contract) => {
+            unformatted_files = prettier_contracts(shell.clone(), args.check).await?;
+        }
+    }
+
+    if !unformatted_files.is_empty() {
+        for file in &unformatted_files {
+            logger::error(msg_file_is_not_formatted(file));
         }
-        Some(Formatter::Contract) => prettier_contracts(shell.clone(), args.check).await?,
+        // The error is propagated to `main`, which terminates the process with a non-zero exit code.
+        anyhow::bail!(msg_fmt_check_failed(unformatted_files.len()));
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_prettier_check_output() {
+        let output = "Checking formatting...\n\
+            [warn] core/tests/ts-integration/src/env.ts\n\
+            [warn] docs/README.md\n\
+            [warn] Code style issues found in 2 files. Run Prettier with --write to fix.\n";
+        assert_eq!(
+            parse_prettier_check_output(output),
+            ["core/tests/ts-integration/src/env.ts", "docs/README.md"]
+        );
+        assert!(parse_prettier_check_output("Checking formatting...\n").is_empty());
+    }
+
+    #[test]
+    fn parsing_rust_edition() {
+        for edition in RustEdition::value_variants() {
+            let parsed = RustEdition::from_str(edition.as_str(), false).unwrap();
+            assert_eq!(parsed, *edition);
+        }
+        RustEdition::from_str("2018", false).unwrap_err();
+    }
+
+    #[test]
+    fn parsing_rustfmt_check_output() {
+        let output = "/code/core/lib/types/src/lib.rs\n/code/core/lib/dal/src/lib.rs\n\n";
+        assert_eq!(
+            parse_rustfmt_check_output(output),
+            [
+                "/code/core/lib/types/src/lib.rs",
+                "/code/core/lib/dal/src/lib.rs"
+            ]
+        );
+        assert!(parse_rustfmt_check_output("error: expected item, found `}`").is_empty());
+    }
+}
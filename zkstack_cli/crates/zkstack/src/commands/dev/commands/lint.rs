@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     fs::File,
     io::{Read, Write},
     path::Path,
@@ -15,8 +16,9 @@ use crate::commands::{
     dev::{
         commands::lint_utils::{get_unignored_files, Target},
         messages::{
-            msg_running_linter_for_extension_spinner, msg_running_linters_for_files,
-            MSG_LINT_CONFIG_PATH_ERR, MSG_RUNNING_CONTRACTS_LINTER_SPINNER,
+            msg_lint_fix_summary, msg_running_linter_for_extension_spinner,
+            msg_running_linters_for_files, MSG_LINT_CHECK_HELP, MSG_LINT_CONFIG_PATH_ERR,
+            MSG_LINT_FIX_HELP, MSG_RUNNING_CONTRACTS_LINTER_SPINNER,
         },
     },
 };
@@ -25,8 +27,10 @@ const CONFIG_PATH: &str = "etc/lint-config";
 
 #[derive(Debug, Parser)]
 pub struct LintArgs {
-    #[clap(long, short = 'c')]
+    #[clap(long, short = 'c', conflicts_with = "fix", help = MSG_LINT_CHECK_HELP)]
     pub check: bool,
+    #[clap(long, help = MSG_LINT_FIX_HELP)]
+    pub fix: bool,
     #[clap(long, short = 't')]
     pub targets: Vec<Target>,
 }
@@ -50,17 +54,28 @@ pub fn run(shell: &Shell, args: LintArgs) -> anyhow::Result<()> {
     logger::info(msg_running_linters_for_files(&targets));
 
     let ecosystem = EcosystemConfig::from_file(shell)?;
+    let check = args.check;
+    let changed_files_before = if args.fix {
+        Some(changed_files(shell, &ecosystem)?)
+    } else {
+        None
+    };
 
     for target in targets {
         match target {
-            Target::Rs => lint_rs(shell, &ecosystem, args.check)?,
-            Target::Contracts => lint_contracts(shell, &ecosystem, args.check)?,
-            Target::Autocompletion => lint_autocompletion_files(shell, args.check)?,
+            Target::Rs => lint_rs(shell, &ecosystem, check)?,
+            Target::Contracts => lint_contracts(shell, &ecosystem, check)?,
+            Target::Autocompletion => lint_autocompletion_files(shell, check)?,
             Target::RustToolchain => check_rust_toolchain(shell)?,
-            ext => lint(shell, &ecosystem, &ext, args.check)?,
+            ext => lint(shell, &ecosystem, &ext, check)?,
         }
     }
 
+    if let Some(before) = changed_files_before {
+        let after = changed_files(shell, &ecosystem)?;
+        logger::info(msg_lint_fix_summary(count_modified_files(&before, &after)));
+    }
+
     logger::outro("Linting complete.");
 
     Ok(())
@@ -94,6 +109,50 @@ fn lint_rs(shell: &Shell, ecosystem: &EcosystemConfig, check: bool) -> anyhow::R
     Ok(())
 }
 
+/// Returns files that differ from `HEAD` (including untracked ones) mapped to hashes of their contents.
+fn changed_files(
+    shell: &Shell,
+    ecosystem: &EcosystemConfig,
+) -> anyhow::Result<HashMap<String, String>> {
+    let _dir_guard = shell.push_dir(&ecosystem.link_to_code);
+    let diff = cmd!(shell, "git diff --name-only HEAD").read()?;
+    let untracked = cmd!(shell, "git ls-files --others --exclude-standard").read()?;
+    // Deleted files cannot be hashed, and linters don't delete files anyway.
+    let paths: Vec<_> = diff
+        .lines()
+        .chain(untracked.lines())
+        .filter(|path| shell.path_exists(path))
+        .collect();
+    if paths.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let hashes = cmd!(shell, "git hash-object -- {paths...}").read()?;
+    let hashes: Vec<_> = hashes.lines().collect();
+    anyhow::ensure!(
+        hashes.len() == paths.len(),
+        "`git hash-object` returned {} hashes for {} files",
+        hashes.len(),
+        paths.len()
+    );
+    Ok(paths
+        .into_iter()
+        .zip(hashes)
+        .map(|(path, hash)| (path.to_owned(), hash.to_owned()))
+        .collect())
+}
+
+/// Counts files that were changed or created between two [`changed_files()`] snapshots.
+fn count_modified_files(
+    before: &HashMap<String, String>,
+    after: &HashMap<String, String>,
+) -> usize {
+    after
+        .iter()
+        .filter(|&(path, hash)| before.get(path) != Some(hash))
+        .count()
+}
+
 fn check_rust_toolchain(shell: &Shell) -> anyhow::Result<()> {
     // deserialize /zkstack_cli/rust-toolchain as TOML
     let path = Path::new("zkstack_cli/rust-toolchain");
@@ -226,3 +285,25 @@ fn lint_autocompletion_files(_shell: &Shell, check: bool) -> anyhow::Result<()>
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counting_modified_files() {
+        let before = HashMap::from([
+            ("core/lib/a.rs".to_owned(), "01".to_owned()),
+            ("core/lib/b.rs".to_owned(), "02".to_owned()),
+        ]);
+        assert_eq!(count_modified_files(&before, &before), 0);
+
+        let after = HashMap::from([
+            ("core/lib/a.rs".to_owned(), "01".to_owned()),
+            ("core/lib/b.rs".to_owned(), "03".to_owned()),
+            ("core/lib/c.rs".to_owned(), "04".to_owned()),
+        ]);
+        assert_eq!(count_modified_files(&before, &after), 2);
+        assert_eq!(count_modified_files(&HashMap::new(), &after), 3);
+    }
+}
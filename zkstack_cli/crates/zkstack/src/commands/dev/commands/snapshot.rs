@@ -1,27 +1,53 @@
+use std::path::PathBuf;
+
 use anyhow::Context;
-use clap::Subcommand;
+use clap::{Parser, Subcommand, ValueEnum};
 use xshell::{cmd, Shell};
 use zkstack_cli_common::{cmd::Cmd, logger};
 use zkstack_cli_config::EcosystemConfig;
 
-use crate::commands::dev::messages::{MSG_CHAIN_NOT_FOUND_ERR, MSG_RUNNING_SNAPSHOT_CREATOR};
+use crate::commands::dev::messages::{
+    MSG_CHAIN_NOT_FOUND_ERR, MSG_RUNNING_SNAPSHOT_CREATOR, MSG_SNAPSHOT_EXPORT_FORMAT_HELP,
+    MSG_SNAPSHOT_EXPORT_OUTPUT_HELP,
+};
 
 #[derive(Subcommand, Debug)]
 pub enum SnapshotCommands {
     Create,
+    /// Create a snapshot and export its metadata to a file
+    Export(SnapshotExportArgs),
+}
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum, strum::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum SnapshotExportFormat {
+    #[default]
+    Json,
+    Cbor,
+}
+
+#[derive(Debug, Parser)]
+pub struct SnapshotExportArgs {
+    #[clap(long, value_enum, default_value_t, help = MSG_SNAPSHOT_EXPORT_FORMAT_HELP)]
+    pub format: SnapshotExportFormat,
+    #[clap(long, help = MSG_SNAPSHOT_EXPORT_OUTPUT_HELP)]
+    pub output: PathBuf,
 }
 
 pub(crate) async fn run(shell: &Shell, args: SnapshotCommands) -> anyhow::Result<()> {
     match args {
         SnapshotCommands::Create => {
-            create(shell).await?;
+            create(shell, None).await?;
+        }
+        SnapshotCommands::Export(args) => {
+            create(shell, Some(args)).await?;
         }
     }
 
     Ok(())
 }
 
-async fn create(shell: &Shell) -> anyhow::Result<()> {
+async fn create(shell: &Shell, export: Option<SnapshotExportArgs>) -> anyhow::Result<()> {
     let ecosystem = EcosystemConfig::from_file(shell)?;
     let chain = ecosystem
         .load_current_chain()
@@ -29,10 +55,18 @@ async fn create(shell: &Shell) -> anyhow::Result<()> {
 
     let config_path = chain.path_to_general_config();
     let secrets_path = chain.path_to_secrets_config();
+    let export_args = export
+        .map(|args| {
+            vec![
+                format!("--export-path={}", args.output.display()),
+                format!("--export-format={}", args.format),
+            ]
+        })
+        .unwrap_or_default();
 
     logger::info(MSG_RUNNING_SNAPSHOT_CREATOR);
 
-    let mut cmd = Cmd::new(cmd!(shell, "cargo run --manifest-path ./core/Cargo.toml --bin snapshots_creator --release -- --config-path={config_path} --secrets-path={secrets_path}"))
+    let mut cmd = Cmd::new(cmd!(shell, "cargo run --manifest-path ./core/Cargo.toml --bin snapshots_creator --release -- --config-path={config_path} --secrets-path={secrets_path} {export_args...}"))
         .env("RUST_LOG", "snapshots_creator=debug");
 
     cmd = cmd.with_force_run();
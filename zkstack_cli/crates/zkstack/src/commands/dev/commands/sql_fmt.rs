@@ -1,12 +1,12 @@
 use std::mem::take;
 
-use anyhow::{bail, Result};
+use anyhow::Result;
 use sqruff_lib::{api::simple::get_simple_config, core::linter::core::Linter};
 use xshell::Shell;
 use zkstack_cli_common::spinner::Spinner;
 
 use super::lint_utils::{get_unignored_files, IgnoredData, Target};
-use crate::commands::dev::messages::{msg_file_is_not_formatted, MSG_RUNNING_SQL_FMT_SPINNER};
+use crate::commands::dev::messages::MSG_RUNNING_SQL_FMT_SPINNER;
 
 fn format_query(query: &str) -> anyhow::Result<String> {
     let exclude_rules = vec!["LT12".to_string()]; // avoid adding newline before `$` character
@@ -64,7 +64,9 @@ fn format_rust_string_query(query: &str, is_raw: bool) -> anyhow::Result<String>
     Ok(add_indent(&reconstructed_rust_string, base_indent))
 }
 
-fn fmt_file(shell: &Shell, file_path: &str, check: bool) -> Result<()> {
+/// Formats SQL queries in the specified file. Returns `true` if the file is not formatted and `check` is set;
+/// otherwise, the file is rewritten in place.
+fn fmt_file(shell: &Shell, file_path: &str, check: bool) -> Result<bool> {
     let content = shell.read_file(file_path)?;
     let mut modified_file = String::new();
 
@@ -125,25 +127,28 @@ fn fmt_file(shell: &Shell, file_path: &str, check: bool) -> Result<()> {
 
     if content != modified_file {
         if check {
-            bail!(msg_file_is_not_formatted(file_path));
-        } else {
-            shell.write_file(file_path, &modified_file)?;
+            return Ok(true);
         }
+        shell.write_file(file_path, &modified_file)?;
     }
 
-    Ok(())
+    Ok(false)
 }
 
-pub async fn format_sql(shell: Shell, check: bool) -> anyhow::Result<()> {
+/// Formats SQL queries in Rust files. In the `check` mode, returns the list of files that are not formatted.
+pub async fn format_sql(shell: Shell, check: bool) -> anyhow::Result<Vec<String>> {
     let spinner = Spinner::new(MSG_RUNNING_SQL_FMT_SPINNER);
     let ignored_data = Some(IgnoredData {
         files: vec![],
         dirs: vec!["zkstack_cli".to_string()],
     });
     let rust_files = get_unignored_files(&shell, &Target::Rs, ignored_data)?;
+    let mut unformatted_files = vec![];
     for file in rust_files {
-        fmt_file(&shell, &file, check)?;
+        if fmt_file(&shell, &file, check)? {
+            unformatted_files.push(file);
+        }
     }
     spinner.finish();
-    Ok(())
+    Ok(unformatted_files)
 }
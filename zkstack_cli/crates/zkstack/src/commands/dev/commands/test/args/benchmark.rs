@@ -0,0 +1,15 @@
+use clap::Parser;
+
+use crate::commands::dev::messages::{
+    MSG_BENCHMARK_ACCOUNTS_HELP, MSG_BENCHMARK_DURATION_SECS_HELP, MSG_BENCHMARK_TARGET_TPS_HELP,
+};
+
+#[derive(Debug, Parser)]
+pub struct BenchmarkArgs {
+    #[clap(long, default_value_t = 300, help = MSG_BENCHMARK_DURATION_SECS_HELP)]
+    pub duration_secs: u64,
+    #[clap(long, help = MSG_BENCHMARK_TARGET_TPS_HELP)]
+    pub target_tps: Option<u64>,
+    #[clap(long, default_value_t = 80, help = MSG_BENCHMARK_ACCOUNTS_HELP)]
+    pub accounts: usize,
+}
@@ -2,6 +2,7 @@ use clap::Parser;
 use serde::{Deserialize, Serialize};
 
 use crate::commands::dev::messages::{
+    MSG_INTEGRATION_TESTS_CAPTURE_TRACES_HELP, MSG_INTEGRATION_TESTS_TIMEOUT_HELP,
     MSG_NO_DEPS_HELP, MSG_TESTS_EXTERNAL_NODE_HELP, MSG_TEST_PATTERN_HELP,
 };
 
@@ -13,4 +14,8 @@ pub struct IntegrationArgs {
     pub no_deps: bool,
     #[clap(short, long, help = MSG_TEST_PATTERN_HELP, allow_hyphen_values(true))]
     pub test_pattern: Option<String>,
+    #[clap(long, help = MSG_INTEGRATION_TESTS_TIMEOUT_HELP)]
+    pub timeout_secs: Option<u64>,
+    #[clap(long, help = MSG_INTEGRATION_TESTS_CAPTURE_TRACES_HELP)]
+    pub capture_traces: bool,
 }
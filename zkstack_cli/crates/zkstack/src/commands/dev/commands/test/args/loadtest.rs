@@ -0,0 +1,11 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::commands::dev::messages::MSG_LOADTEST_SCENARIO_HELP;
+
+#[derive(Debug, Parser)]
+pub struct LoadtestArgs {
+    #[clap(long, help = MSG_LOADTEST_SCENARIO_HELP)]
+    pub scenario: Option<PathBuf>,
+}
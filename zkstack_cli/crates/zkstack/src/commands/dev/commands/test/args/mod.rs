@@ -1,5 +1,8 @@
+pub mod benchmark;
 pub mod fees;
 pub mod integration;
+pub mod loadtest;
+pub mod prover;
 pub mod recovery;
 pub mod revert;
 pub mod rust;
@@ -0,0 +1,9 @@
+use clap::Parser;
+
+use crate::commands::dev::messages::MSG_PROVER_TEST_FILTER_HELP;
+
+#[derive(Debug, Parser)]
+pub struct ProverArgs {
+    #[clap(long, help = MSG_PROVER_TEST_FILTER_HELP)]
+    pub filter: Option<String>,
+}
@@ -1,10 +1,13 @@
 use clap::Parser;
 
 use crate::commands::dev::messages::{
-    MSG_NO_DEPS_HELP, MSG_NO_KILL_HELP, MSG_REVERT_TEST_ENABLE_CONSENSUS_HELP,
-    MSG_TESTS_EXTERNAL_NODE_HELP,
+    MSG_NO_DEPS_HELP, MSG_NO_KILL_HELP, MSG_REVERT_TEST_BLOCKS_BEFORE_REVERT_HELP,
+    MSG_REVERT_TEST_ENABLE_CONSENSUS_HELP, MSG_TESTS_EXTERNAL_NODE_HELP,
 };
 
+/// Number of L1 batches reverted by the test by default.
+pub const DEFAULT_BLOCKS_BEFORE_REVERT: u64 = 1;
+
 #[derive(Debug, Parser)]
 pub struct RevertArgs {
     #[clap(long, help = MSG_REVERT_TEST_ENABLE_CONSENSUS_HELP)]
@@ -15,4 +18,27 @@ pub struct RevertArgs {
     pub no_deps: bool,
     #[clap(long, help = MSG_NO_KILL_HELP)]
     pub no_kill: bool,
+    // The test must revert at least one batch. The upper bound depends on the chain state, so it's checked
+    // by the test itself.
+    #[clap(
+        long,
+        default_value_t = DEFAULT_BLOCKS_BEFORE_REVERT,
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = MSG_REVERT_TEST_BLOCKS_BEFORE_REVERT_HELP
+    )]
+    pub blocks_before_revert: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_blocks_before_revert() {
+        let args = RevertArgs::try_parse_from(["revert"]).unwrap();
+        assert_eq!(args.blocks_before_revert, DEFAULT_BLOCKS_BEFORE_REVERT);
+        let args = RevertArgs::try_parse_from(["revert", "--blocks-before-revert", "10"]).unwrap();
+        assert_eq!(args.blocks_before_revert, 10);
+        RevertArgs::try_parse_from(["revert", "--blocks-before-revert", "0"]).unwrap_err();
+    }
 }
@@ -1,9 +1,16 @@
 use clap::Parser;
+use zksync_basic_types::protocol_version::ProtocolSemanticVersion;
 
-use crate::commands::dev::messages::MSG_NO_DEPS_HELP;
+use crate::commands::dev::messages::{
+    MSG_NO_DEPS_HELP, MSG_UPGRADE_TEST_FROM_VERSION_HELP, MSG_UPGRADE_TEST_TO_VERSION_HELP,
+};
 
 #[derive(Debug, Parser)]
 pub struct UpgradeArgs {
     #[clap(short, long, help = MSG_NO_DEPS_HELP)]
     pub no_deps: bool,
+    #[clap(long, help = MSG_UPGRADE_TEST_FROM_VERSION_HELP)]
+    pub from_version: Option<ProtocolSemanticVersion>,
+    #[clap(long, help = MSG_UPGRADE_TEST_TO_VERSION_HELP)]
+    pub to_version: Option<ProtocolSemanticVersion>,
 }
@@ -0,0 +1,78 @@
+use std::{
+    io::{BufRead, BufReader},
+    process::{Command, Stdio},
+};
+
+use anyhow::Context;
+use xshell::Shell;
+use zkstack_cli_common::logger;
+
+use super::{args::benchmark::BenchmarkArgs, loadtest::loadtest_cmd};
+use crate::commands::dev::messages::{msg_benchmark_summary, MSG_BENCHMARK_TPS_NOT_REPORTED};
+
+/// Prefix of the reported TPS in the final loadtest report.
+const TPS_MARKER: &str = "TPS: ";
+
+pub async fn run(shell: &Shell, args: BenchmarkArgs) -> anyhow::Result<()> {
+    let mut command = loadtest_cmd(shell)
+        .await?
+        .env("DURATION_SEC", args.duration_secs.to_string())
+        .env("ACCOUNTS_AMOUNT", args.accounts.to_string())
+        // The final report is logged with the `info` level, so it must be enabled to get the reported TPS.
+        .env("RUST_LOG", "loadnext=info");
+    if let Some(target_tps) = args.target_tps {
+        let expected_tx_count = target_tps * args.duration_secs;
+        command = command.env("EXPECTED_TX_COUNT", expected_tx_count.to_string());
+    }
+
+    logger::debug(format!("Running: {command}"));
+    let mut child = Command::from(command)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .context("failed spawning loadtest")?;
+
+    // Stream the output while looking for the reported TPS.
+    let stdout = child
+        .stdout
+        .take()
+        .context("loadtest stdout is not piped")?;
+    let mut reported_tps = None;
+    for line in BufReader::new(stdout).lines() {
+        let line = line.context("failed reading loadtest output")?;
+        println!("{line}");
+        reported_tps = parse_tps(&line).or(reported_tps);
+    }
+    let status = child.wait().context("failed waiting for loadtest")?;
+
+    match reported_tps {
+        Some(tps) => logger::info(msg_benchmark_summary(tps, args.target_tps)),
+        None => logger::warn(MSG_BENCHMARK_TPS_NOT_REPORTED),
+    }
+    anyhow::ensure!(status.success(), "Loadtest failed: {status}");
+    logger::outro("Benchmark success");
+    Ok(())
+}
+
+fn parse_tps(line: &str) -> Option<f64> {
+    let (_, tail) = line.split_once(TPS_MARKER)?;
+    tail.split_whitespace().next()?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_tps_from_report() {
+        let line =
+            "INFO loadnext::report_collector::operation_results_collector: Ran loadtest for \
+            301.2s (requested duration: 300s). TPS: 52.5 (with requested duration: 52.71)";
+        assert_eq!(parse_tps(line), Some(52.5));
+        assert_eq!(
+            parse_tps("Transaction execution stats: 15813 successes"),
+            None
+        );
+        assert_eq!(parse_tps("TPS: n/a"), None);
+    }
+}
@@ -1,9 +1,17 @@
-use std::path::PathBuf;
+use std::{
+    collections::HashSet,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{self, ExitStatus, Stdio},
+    time::Duration,
+};
 
 use anyhow::Context;
+use ethers::providers::{Http, Middleware, Provider};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
 use xshell::{cmd, Shell};
 use zkstack_cli_common::{cmd::Cmd, config::global_config, logger};
-use zkstack_cli_config::EcosystemConfig;
+use zkstack_cli_config::{ChainConfig, EcosystemConfig};
 
 use super::{
     args::integration::IntegrationArgs,
@@ -13,10 +21,17 @@ use super::{
     },
 };
 use crate::commands::dev::messages::{
-    msg_integration_tests_run, MSG_CHAIN_NOT_FOUND_ERR, MSG_DESERIALIZE_TEST_WALLETS_ERR,
-    MSG_INTEGRATION_TESTS_RUN_SUCCESS,
+    msg_integration_tests_run, msg_integration_tests_timeout, msg_integration_tests_trace_failed,
+    msg_integration_tests_trace_saved, msg_integration_tests_traces_failed,
+    MSG_CHAIN_NOT_FOUND_ERR, MSG_DESERIALIZE_TEST_WALLETS_ERR, MSG_INTEGRATION_TESTS_INTERRUPTED,
+    MSG_INTEGRATION_TESTS_NO_TRACES_CAPTURED, MSG_INTEGRATION_TESTS_RUN_SUCCESS,
 };
 
+/// Exit code of the command if integration tests time out. Matches the exit code of GNU `timeout`.
+const TIMEOUT_EXIT_CODE: i32 = 124;
+/// Directory (relative to the repository root) to save transaction traces to.
+const TEST_TRACES_PATH: &str = "test-traces";
+
 pub async fn run(shell: &Shell, args: IntegrationArgs) -> anyhow::Result<()> {
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
 
@@ -59,9 +74,376 @@ pub async fn run(shell: &Shell, args: IntegrationArgs) -> anyhow::Result<()> {
         )
     }
 
-    Cmd::new(command).with_force_run().run()?;
+    if args.timeout_secs.is_none() && !args.capture_traces {
+        Cmd::new(command).with_force_run().run()?;
+        logger::outro(MSG_INTEGRATION_TESTS_RUN_SUCCESS);
+        return Ok(());
+    }
+
+    let timeout = args.timeout_secs.map(Duration::from_secs);
+    let Some(test_run) = run_tests(command, timeout, args.capture_traces).await? else {
+        // Use a distinct exit code, so that CI can distinguish a timeout from a test failure.
+        logger::error(msg_integration_tests_timeout(
+            args.timeout_secs.unwrap_or_default(),
+        ));
+        process::exit(TIMEOUT_EXIT_CODE);
+    };
+
+    if !test_run.status.success() {
+        if args.capture_traces {
+            let traces_dir = ecosystem_config.link_to_code.join(TEST_TRACES_PATH);
+            let tx_hashes = extract_tx_hashes(&test_run.output);
+            if let Err(err) = capture_traces(shell, &chain_config, &traces_dir, &tx_hashes).await {
+                logger::warn(msg_integration_tests_traces_failed(&err));
+            }
+        }
+        anyhow::bail!("Integration tests failed: {}", test_run.status);
+    }
 
     logger::outro(MSG_INTEGRATION_TESTS_RUN_SUCCESS);
 
     Ok(())
 }
+
+/// Completed run of integration tests.
+#[derive(Debug)]
+struct TestRun {
+    status: ExitStatus,
+    /// Combined stdout and stderr of the tests. Empty unless the output was requested to be captured.
+    output: String,
+}
+
+/// Runs the command, killing it if it doesn't complete within `timeout`. Returns `None` if the command has timed out.
+/// If `timeout` is set and the command is interrupted by `SIGINT` / `SIGTERM`, kills the command and returns an error.
+///
+/// If `capture_output` is set, the command output is still printed, but is additionally collected into [`TestRun`].
+async fn run_tests(
+    command: xshell::Cmd<'_>,
+    timeout: Option<Duration>,
+    capture_output: bool,
+) -> anyhow::Result<Option<TestRun>> {
+    logger::debug(format!("Running: {command}"));
+    let mut command = tokio::process::Command::from(process::Command::from(command));
+    command.kill_on_drop(true);
+    // If there's a timeout, run tests in a separate process group, so that they can be killed together with
+    // all processes they spawn (`yarn` spawns `jest`, which spawns workers etc.). Since a separate group
+    // doesn't receive signals from the terminal (e.g., on Ctrl+C), they are handled below.
+    #[cfg(unix)]
+    if timeout.is_some() {
+        command.process_group(0);
+    }
+    if capture_output {
+        command.stdout(Stdio::piped()).stderr(Stdio::piped());
+    }
+    let mut child = command
+        .spawn()
+        .context("failed spawning integration tests")?;
+    #[cfg(unix)]
+    let process_group_id = child.id();
+
+    let stdout = child.stdout.take();
+    let stderr = child.stderr.take();
+    let run = async {
+        let (stdout, stderr) = tokio::try_join!(
+            forward_output(stdout, io::stdout()),
+            forward_output(stderr, io::stderr())
+        )
+        .context("failed reading integration tests output")?;
+        let status = child
+            .wait()
+            .await
+            .context("failed waiting for integration tests")?;
+        anyhow::Ok(TestRun {
+            status,
+            output: stdout + &stderr,
+        })
+    };
+
+    let Some(timeout) = timeout else {
+        return run.await.map(Some);
+    };
+    let is_interrupted = tokio::select! {
+        test_run = run => return test_run.map(Some),
+        () = tokio::time::sleep(timeout) => false,
+        res = termination_signal() => {
+            res.context("failed listening to termination signals")?;
+            true
+        }
+    };
+
+    #[cfg(unix)]
+    if let Some(process_group_id) = process_group_id {
+        kill_process_group(process_group_id)
+            .context("failed killing integration tests process group")?;
+    }
+    // Reap the child process.
+    child
+        .kill()
+        .await
+        .context("failed killing integration tests")?;
+    anyhow::ensure!(!is_interrupted, MSG_INTEGRATION_TESTS_INTERRUPTED);
+    Ok(None)
+}
+
+/// Resolves once the command receives `SIGINT` (e.g., on Ctrl+C) or, on Unix, `SIGTERM`.
+async fn termination_signal() -> io::Result<()> {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut sigterm = signal(SignalKind::terminate())?;
+        tokio::select! {
+            res = tokio::signal::ctrl_c() => res,
+            _ = sigterm.recv() => Ok(()),
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        tokio::signal::ctrl_c().await
+    }
+}
+
+/// Sends `SIGKILL` to all processes in the specified process group.
+#[cfg(unix)]
+fn kill_process_group(process_group_id: u32) -> io::Result<()> {
+    let process_group_id = libc::pid_t::try_from(process_group_id)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidInput, err))?;
+    // SAFETY: `killpg` is safe to call with any arguments.
+    if unsafe { libc::killpg(process_group_id, libc::SIGKILL) } == 0 {
+        return Ok(());
+    }
+    let err = io::Error::last_os_error();
+    // `ESRCH` means that all processes in the group have already exited.
+    if err.raw_os_error() == Some(libc::ESRCH) {
+        Ok(())
+    } else {
+        Err(err)
+    }
+}
+
+/// Copies lines from `reader` (if any) to `sink`, returning the copied output. Output is not required to be valid UTF-8;
+/// invalid sequences are replaced in the returned output.
+async fn forward_output(
+    reader: Option<impl AsyncRead + Unpin>,
+    mut sink: impl Write,
+) -> io::Result<String> {
+    let mut output = String::new();
+    let Some(reader) = reader else {
+        return Ok(output);
+    };
+    let mut reader = BufReader::new(reader);
+    let mut line = vec![];
+    while reader.read_until(b'\n', &mut line).await? > 0 {
+        sink.write_all(&line)?;
+        output.push_str(&String::from_utf8_lossy(&line));
+        line.clear();
+    }
+    Ok(output)
+}
+
+/// Extracts unique transaction hash candidates (i.e., `0x`-prefixed 32-byte hex strings) from the test output,
+/// in the order of their first occurrence. Only lines mentioning a transaction are considered, so that unrelated
+/// 32-byte values (storage slots, block hashes etc.) don't result in extra RPC requests.
+fn extract_tx_hashes(output: &str) -> Vec<String> {
+    const HASH_HEX_LEN: usize = 64;
+
+    let mut seen = HashSet::new();
+    let mut hashes = vec![];
+    let tx_lines = output.lines().filter(|line| {
+        let line = line.to_ascii_lowercase();
+        line.contains("transaction") || line.contains("tx")
+    });
+    for line in tx_lines {
+        for (pos, _) in line.match_indices("0x") {
+            let hex = &line[pos + 2..];
+            let hex_len = hex.bytes().take_while(u8::is_ascii_hexdigit).count();
+            // Longer hex strings (e.g., ABI-encoded calldata) are not transaction hashes.
+            if hex_len != HASH_HEX_LEN {
+                continue;
+            }
+            let hash = format!("0x{}", hex[..HASH_HEX_LEN].to_ascii_lowercase());
+            if seen.insert(hash.clone()) {
+                hashes.push(hash);
+            }
+        }
+    }
+    hashes
+}
+
+/// Queries call traces for the specified transactions from the main node and saves them to `traces_dir`.
+/// Hashes that don't correspond to a transaction (e.g., block or storage slot hashes) are skipped.
+async fn capture_traces(
+    shell: &Shell,
+    chain_config: &ChainConfig,
+    traces_dir: &Path,
+    tx_hashes: &[String],
+) -> anyhow::Result<()> {
+    let l2_rpc_url: String = chain_config
+        .get_general_config()
+        .await?
+        .get("api.web3_json_rpc.http_url")?;
+    let provider = Provider::<Http>::try_from(l2_rpc_url.as_str())
+        .with_context(|| format!("Provider::try_from({l2_rpc_url})"))?;
+
+    let mut saved_count = 0;
+    for tx_hash in tx_hashes {
+        let trace = provider
+            .request::<_, serde_json::Value>(
+                "debug_traceTransaction",
+                (tx_hash, serde_json::json!({ "tracer": "callTracer" })),
+            )
+            .await;
+        let trace = match trace {
+            Ok(serde_json::Value::Null) => continue,
+            Ok(trace) => trace,
+            Err(err) => {
+                logger::warn(msg_integration_tests_trace_failed(tx_hash, &err.into()));
+                continue;
+            }
+        };
+
+        let path = traces_dir.join(format!("{tx_hash}.json"));
+        shell.create_dir(traces_dir)?;
+        shell.write_file(&path, serde_json::to_string_pretty(&trace)?)?;
+        logger::info(msg_integration_tests_trace_saved(&path));
+        saved_count += 1;
+    }
+
+    if saved_count == 0 {
+        logger::warn(MSG_INTEGRATION_TESTS_NO_TRACES_CAPTURED);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn command_is_killed_on_timeout() {
+        let shell = Shell::new().unwrap();
+        let timeout = Some(Duration::from_millis(100));
+        let test_run = run_tests(cmd!(shell, "sleep 10"), timeout, false)
+            .await
+            .unwrap();
+        assert!(test_run.is_none());
+
+        let timeout = Some(Duration::from_secs(10));
+        let test_run = run_tests(cmd!(shell, "true"), timeout, false)
+            .await
+            .unwrap();
+        assert!(test_run.unwrap().status.success());
+        let test_run = run_tests(cmd!(shell, "false"), timeout, false)
+            .await
+            .unwrap();
+        assert!(!test_run.unwrap().status.success());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn spawned_processes_are_killed_on_timeout() {
+        /// Checks whether the process is alive, i.e., exists and is not a zombie.
+        fn is_alive(pid: &str) -> bool {
+            let Ok(stat) = std::fs::read_to_string(format!("/proc/{pid}/stat")) else {
+                return false;
+            };
+            // The process state follows the parenthesized executable name.
+            let state = stat.rsplit_once(") ").map(|(_, rest)| rest.chars().next());
+            !matches!(state, Some(Some('Z' | 'X')))
+        }
+
+        let shell = Shell::new().unwrap();
+        let pid_file =
+            std::env::temp_dir().join(format!("zkstack-test-{}.pid", rand::random::<u64>()));
+        let pid_path = pid_file.to_str().unwrap();
+        let script = format!("sleep 10 & echo $! > {pid_path}; wait");
+        let timeout = Some(Duration::from_millis(500));
+        let test_run = run_tests(cmd!(shell, "sh -c {script}"), timeout, false)
+            .await
+            .unwrap();
+        assert!(test_run.is_none());
+
+        let grandchild_pid = std::fs::read_to_string(&pid_file).unwrap();
+        std::fs::remove_file(&pid_file).unwrap();
+        let grandchild_pid = grandchild_pid.trim();
+        for _ in 0..50 {
+            if !is_alive(grandchild_pid) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        panic!("spawned process {grandchild_pid} survived timeout");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn process_group_is_only_created_with_timeout() {
+        async fn process_group_id(timeout: Option<Duration>) -> libc::pid_t {
+            let shell = Shell::new().unwrap();
+            let test_run = run_tests(cmd!(shell, "cat /proc/self/stat"), timeout, true)
+                .await
+                .unwrap()
+                .unwrap();
+            // The process group ID is the third field after the parenthesized executable name.
+            let (_, fields) = test_run.output.rsplit_once(") ").unwrap();
+            fields.split_whitespace().nth(2).unwrap().parse().unwrap()
+        }
+
+        // SAFETY: `getpgrp` is always safe to call.
+        let own_group_id = unsafe { libc::getpgrp() };
+        assert_eq!(process_group_id(None).await, own_group_id);
+        let timeout = Some(Duration::from_secs(10));
+        assert_ne!(process_group_id(timeout).await, own_group_id);
+    }
+
+    #[tokio::test]
+    async fn command_output_is_captured() {
+        let shell = Shell::new().unwrap();
+        let script = "echo out; echo err >&2; exit 1";
+        let test_run = run_tests(cmd!(shell, "sh -c {script}"), None, true)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!test_run.status.success());
+        assert_eq!(test_run.output, "out\nerr\n");
+
+        let test_run = run_tests(cmd!(shell, "sh -c {script}"), None, false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(test_run.output.is_empty());
+    }
+
+    #[tokio::test]
+    async fn non_utf8_output_is_forwarded() {
+        let input: &[u8] = b"ok\n\xff\xfe broken\nno trailing newline";
+        let mut sink = vec![];
+        let output = forward_output(Some(input), &mut sink).await.unwrap();
+        assert_eq!(sink, input);
+        assert_eq!(output, "ok\n\u{fffd}\u{fffd} broken\nno trailing newline");
+    }
+
+    #[test]
+    fn extracting_tx_hashes() {
+        let hash = format!("0x{}", "aB".repeat(32));
+        let output = format!(
+            "Error: transaction {hash} reverted\n\
+             Expected: 0x{short}\n\
+             calldata: 0x{long}\n\
+             Receipt: {{ transactionHash: '{hash}' }}\n\
+             Storage slot: 0x{slot}\n\
+             Failed tx: 0x{other}.",
+            short = "1".repeat(40),
+            long = "2".repeat(128),
+            slot = "4".repeat(64),
+            other = "3".repeat(64),
+        );
+
+        let hashes = extract_tx_hashes(&output);
+        assert_eq!(
+            hashes,
+            [hash.to_ascii_lowercase(), format!("0x{}", "3".repeat(64))]
+        );
+        assert!(extract_tx_hashes("no hashes here 0x").is_empty());
+    }
+}
@@ -1,11 +1,39 @@
+use std::path::Path;
+
 use anyhow::Context;
+use serde::Deserialize;
 use xshell::{cmd, Shell};
 use zkstack_cli_common::{cmd::Cmd, config::global_config, logger};
 use zkstack_cli_config::EcosystemConfig;
 
-use crate::commands::dev::messages::MSG_CHAIN_NOT_FOUND_ERR;
+use super::args::loadtest::LoadtestArgs;
+use crate::commands::dev::messages::{msg_loadtest_scenario_err, MSG_CHAIN_NOT_FOUND_ERR};
+
+pub async fn run(shell: &Shell, args: LoadtestArgs) -> anyhow::Result<()> {
+    let scenario = args
+        .scenario
+        .map(|path| {
+            LoadtestScenario::load(shell, &path).with_context(|| msg_loadtest_scenario_err(&path))
+        })
+        .transpose()?;
+
+    let mut command = loadtest_cmd(shell).await?;
+    if let Some(scenario) = scenario {
+        command = command.envs(scenario.env_vars());
+    }
+    if global_config().verbose {
+        command = command.env("RUST_LOG", "loadnext=info")
+    }
+
+    Cmd::new(command).with_force_run().run()?;
+
+    logger::outro("Loadtest success");
+
+    Ok(())
+}
 
-pub async fn run(shell: &Shell) -> anyhow::Result<()> {
+/// Creates a command running the loadtest binary against the current chain.
+pub(super) async fn loadtest_cmd(shell: &Shell) -> anyhow::Result<xshell::Cmd<'_>> {
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
 
     let chain_config = ecosystem_config
@@ -14,7 +42,7 @@ pub async fn run(shell: &Shell) -> anyhow::Result<()> {
 
     let general_config = chain_config.get_general_config().await?;
 
-    let mut command = cmd!(
+    let command = cmd!(
         shell,
         "cargo run --manifest-path ./core/Cargo.toml --release --bin loadnext"
     )
@@ -45,14 +73,229 @@ pub async fn run(shell: &Shell) -> anyhow::Result<()> {
         "L2_WS_RPC_ADDRESS",
         general_config.get::<String>("api.web3_json_rpc.ws_url")?,
     );
+    Ok(command)
+}
 
-    if global_config().verbose {
-        command = command.env("RUST_LOG", "loadnext=info")
+/// Declarative loadtest scenario loaded from a YAML file. Unspecified parameters use the loadtest defaults.
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LoadtestScenario {
+    #[serde(default)]
+    accounts: AccountsScenario,
+    duration_secs: Option<u64>,
+    /// Relative weights used to randomly choose the type of each transaction.
+    transaction_weights: Option<TransactionWeights>,
+    /// Work performed by the loadtest contract in each L2 transaction.
+    contract_execution: Option<ContractExecutionParams>,
+}
+
+#[derive(Debug, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AccountsScenario {
+    amount: Option<usize>,
+    group_size: Option<usize>,
+    max_inflight_txs: Option<usize>,
+}
+
+/// Mirrors `TransactionWeights` in the loadtest, which requires all weights to be specified.
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TransactionWeights {
+    deposit: f32,
+    withdrawal: f32,
+    l1_transactions: f32,
+    l2_transactions: f32,
+}
+
+/// Mirrors `LoadnextContractExecutionParams` in the loadtest, which requires all params to be specified.
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ContractExecutionParams {
+    reads: usize,
+    initial_writes: usize,
+    repeated_writes: usize,
+    events: usize,
+    hashes: usize,
+    recursive_calls: usize,
+    deploys: usize,
+}
+
+impl LoadtestScenario {
+    fn load(shell: &Shell, path: &Path) -> anyhow::Result<Self> {
+        let scenario = Self::parse(&shell.read_file(path)?)?;
+        scenario.validate()?;
+        Ok(scenario)
     }
 
-    Cmd::new(command).with_force_run().run()?;
+    fn parse(yaml: &str) -> anyhow::Result<Self> {
+        // An empty file is a valid scenario using all defaults.
+        if yaml.trim().is_empty() {
+            return Ok(Self::default());
+        }
+        Ok(serde_yaml::from_str(yaml)?)
+    }
 
-    logger::outro("Loadtest success");
+    fn validate(&self) -> anyhow::Result<()> {
+        let accounts = &self.accounts;
+        anyhow::ensure!(
+            accounts.amount != Some(0),
+            "accounts.amount must be positive"
+        );
+        anyhow::ensure!(
+            accounts.group_size != Some(0),
+            "accounts.group_size must be positive"
+        );
+        if let (Some(amount), Some(group_size)) = (accounts.amount, accounts.group_size) {
+            anyhow::ensure!(
+                group_size <= amount,
+                "accounts.group_size ({group_size}) must not exceed accounts.amount ({amount})"
+            );
+        }
+        anyhow::ensure!(
+            self.duration_secs != Some(0),
+            "duration_secs must be positive"
+        );
 
-    Ok(())
+        if let Some(weights) = &self.transaction_weights {
+            let weights = [
+                weights.deposit,
+                weights.withdrawal,
+                weights.l1_transactions,
+                weights.l2_transactions,
+            ];
+            anyhow::ensure!(
+                weights
+                    .iter()
+                    .all(|weight| weight.is_finite() && *weight >= 0.0),
+                "transaction_weights must be non-negative numbers"
+            );
+            anyhow::ensure!(
+                weights.iter().any(|weight| *weight > 0.0),
+                "at least one of transaction_weights must be positive"
+            );
+        }
+        Ok(())
+    }
+
+    /// Returns env variables configuring the loadtest binary according to this scenario.
+    fn env_vars(&self) -> Vec<(&'static str, String)> {
+        let accounts = &self.accounts;
+        let mut vars = vec![];
+        let optional_vars = [
+            ("ACCOUNTS_AMOUNT", accounts.amount.map(|x| x.to_string())),
+            (
+                "ACCOUNTS_GROUP_SIZE",
+                accounts.group_size.map(|x| x.to_string()),
+            ),
+            (
+                "MAX_INFLIGHT_TXS",
+                accounts.max_inflight_txs.map(|x| x.to_string()),
+            ),
+            ("DURATION_SEC", self.duration_secs.map(|x| x.to_string())),
+        ];
+        for (name, value) in optional_vars {
+            if let Some(value) = value {
+                vars.push((name, value));
+            }
+        }
+
+        if let Some(weights) = &self.transaction_weights {
+            vars.extend([
+                ("TRANSACTION_WEIGHTS_DEPOSIT", weights.deposit.to_string()),
+                (
+                    "TRANSACTION_WEIGHTS_WITHDRAWAL",
+                    weights.withdrawal.to_string(),
+                ),
+                (
+                    "TRANSACTION_WEIGHTS_L1_TRANSACTIONS",
+                    weights.l1_transactions.to_string(),
+                ),
+                (
+                    "TRANSACTION_WEIGHTS_L2_TRANSACTIONS",
+                    weights.l2_transactions.to_string(),
+                ),
+            ]);
+        }
+        if let Some(params) = &self.contract_execution {
+            vars.extend([
+                ("CONTRACT_EXECUTION_PARAMS_READS", params.reads.to_string()),
+                (
+                    "CONTRACT_EXECUTION_PARAMS_INITIAL_WRITES",
+                    params.initial_writes.to_string(),
+                ),
+                (
+                    "CONTRACT_EXECUTION_PARAMS_REPEATED_WRITES",
+                    params.repeated_writes.to_string(),
+                ),
+                (
+                    "CONTRACT_EXECUTION_PARAMS_EVENTS",
+                    params.events.to_string(),
+                ),
+                (
+                    "CONTRACT_EXECUTION_PARAMS_HASHES",
+                    params.hashes.to_string(),
+                ),
+                (
+                    "CONTRACT_EXECUTION_PARAMS_RECURSIVE_CALLS",
+                    params.recursive_calls.to_string(),
+                ),
+                (
+                    "CONTRACT_EXECUTION_PARAMS_DEPLOYS",
+                    params.deploys.to_string(),
+                ),
+            ]);
+        }
+        vars
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parsing_example_scenario() {
+        let scenario = LoadtestScenario::parse(include_str!("loadtest_scenario.yaml")).unwrap();
+        scenario.validate().unwrap();
+        assert_eq!(scenario.accounts.amount, Some(40));
+        assert_eq!(scenario.duration_secs, Some(600));
+
+        let vars = scenario.env_vars();
+        assert_eq!(vars.len(), 15);
+        assert!(vars.contains(&("ACCOUNTS_GROUP_SIZE", "20".to_owned())));
+        assert!(vars.contains(&("TRANSACTION_WEIGHTS_L2_TRANSACTIONS", "1".to_owned())));
+        assert!(vars.contains(&("CONTRACT_EXECUTION_PARAMS_HASHES", "10".to_owned())));
+    }
+
+    #[test]
+    fn parsing_partial_scenario() {
+        assert_eq!(
+            LoadtestScenario::parse("").unwrap(),
+            LoadtestScenario::default()
+        );
+        assert!(LoadtestScenario::default().env_vars().is_empty());
+
+        let scenario = LoadtestScenario::parse("accounts:\n  amount: 10\n").unwrap();
+        assert_eq!(scenario.env_vars(), [("ACCOUNTS_AMOUNT", "10".to_owned())]);
+
+        // Weights must be specified together.
+        LoadtestScenario::parse("transaction_weights:\n  deposit: 1.0\n").unwrap_err();
+        // Typos are not silently ignored.
+        LoadtestScenario::parse("acounts:\n  amount: 10\n").unwrap_err();
+    }
+
+    #[test]
+    fn validating_scenario() {
+        let invalid_scenarios = [
+            "accounts:\n  amount: 0\n",
+            "accounts:\n  amount: 10\n  group_size: 20\n",
+            "duration_secs: 0\n",
+            "transaction_weights: { deposit: 0, withdrawal: 0, l1_transactions: 0, l2_transactions: 0 }\n",
+            "transaction_weights: { deposit: -1, withdrawal: 0, l1_transactions: 0, l2_transactions: 1 }\n",
+        ];
+        for yaml in invalid_scenarios {
+            let scenario = LoadtestScenario::parse(yaml).unwrap();
+            scenario.validate().unwrap_err();
+        }
+    }
 }
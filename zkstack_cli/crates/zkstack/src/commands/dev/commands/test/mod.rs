@@ -1,17 +1,19 @@
 use args::{
-    fees::FeesArgs, integration::IntegrationArgs, recovery::RecoveryArgs, revert::RevertArgs,
-    rust::RustArgs, upgrade::UpgradeArgs,
+    benchmark::BenchmarkArgs, fees::FeesArgs, integration::IntegrationArgs, loadtest::LoadtestArgs,
+    prover::ProverArgs, recovery::RecoveryArgs, revert::RevertArgs, rust::RustArgs,
+    upgrade::UpgradeArgs,
 };
 use clap::Subcommand;
 use xshell::Shell;
 
 use crate::commands::dev::messages::{
-    MSG_BUILD_ABOUT, MSG_INTEGRATION_TESTS_ABOUT, MSG_L1_CONTRACTS_ABOUT, MSG_LOADTEST_ABOUT,
-    MSG_PROVER_TEST_ABOUT, MSG_RECOVERY_TEST_ABOUT, MSG_REVERT_TEST_ABOUT, MSG_RUST_TEST_ABOUT,
-    MSG_TEST_WALLETS_INFO, MSG_UPGRADE_TEST_ABOUT,
+    MSG_BENCHMARK_ABOUT, MSG_BUILD_ABOUT, MSG_INTEGRATION_TESTS_ABOUT, MSG_L1_CONTRACTS_ABOUT,
+    MSG_LOADTEST_ABOUT, MSG_PROVER_TEST_ABOUT, MSG_RECOVERY_TEST_ABOUT, MSG_REVERT_TEST_ABOUT,
+    MSG_RUST_TEST_ABOUT, MSG_TEST_WALLETS_INFO, MSG_UPGRADE_TEST_ABOUT,
 };
 
 mod args;
+mod benchmark;
 mod build;
 mod db;
 mod fees;
@@ -45,11 +47,13 @@ pub enum TestCommands {
     #[clap(about = MSG_L1_CONTRACTS_ABOUT, alias = "l1")]
     L1Contracts,
     #[clap(about = MSG_PROVER_TEST_ABOUT, alias = "p")]
-    Prover,
+    Prover(ProverArgs),
     #[clap(about = MSG_TEST_WALLETS_INFO)]
     Wallet,
     #[clap(about = MSG_LOADTEST_ABOUT)]
-    Loadtest,
+    Loadtest(LoadtestArgs),
+    #[clap(about = MSG_BENCHMARK_ABOUT)]
+    Benchmark(BenchmarkArgs),
 }
 
 pub async fn run(shell: &Shell, args: TestCommands) -> anyhow::Result<()> {
@@ -62,8 +66,9 @@ pub async fn run(shell: &Shell, args: TestCommands) -> anyhow::Result<()> {
         TestCommands::Build => build::run(shell),
         TestCommands::Rust(args) => rust::run(shell, args).await,
         TestCommands::L1Contracts => l1_contracts::run(shell),
-        TestCommands::Prover => prover::run(shell).await,
+        TestCommands::Prover(args) => prover::run(shell, args).await,
         TestCommands::Wallet => wallet::run(shell),
-        TestCommands::Loadtest => loadtest::run(shell).await,
+        TestCommands::Loadtest(args) => loadtest::run(shell, args).await,
+        TestCommands::Benchmark(args) => benchmark::run(shell, args).await,
     }
 }
@@ -5,6 +5,7 @@ use xshell::{cmd, Shell};
 use zkstack_cli_common::{cmd::Cmd, logger};
 use zkstack_cli_config::EcosystemConfig;
 
+use super::args::prover::ProverArgs;
 use crate::commands::dev::{
     commands::test::db::reset_test_databases,
     dals::{Dal, PROVER_DAL_PATH},
@@ -12,7 +13,7 @@ use crate::commands::dev::{
     messages::MSG_PROVER_TEST_SUCCESS,
 };
 
-pub async fn run(shell: &Shell) -> anyhow::Result<()> {
+pub async fn run(shell: &Shell, args: ProverArgs) -> anyhow::Result<()> {
     let ecosystem = EcosystemConfig::from_file(shell)?;
     let dals = vec![Dal {
         url: Url::from_str(TEST_DATABASE_PROVER_URL)?,
@@ -21,10 +22,14 @@ pub async fn run(shell: &Shell) -> anyhow::Result<()> {
     reset_test_databases(shell, &ecosystem.link_to_code, dals).await?;
 
     let _dir_guard = shell.push_dir(ecosystem.link_to_code.join("prover"));
-    Cmd::new(cmd!(shell, "cargo test --release --workspace --locked"))
-        .with_force_run()
-        .env("TEST_DATABASE_PROVER_URL", TEST_DATABASE_PROVER_URL)
-        .run()?;
+    let filter = args.filter;
+    Cmd::new(cmd!(
+        shell,
+        "cargo test --release --workspace --locked {filter...}"
+    ))
+    .with_force_run()
+    .env("TEST_DATABASE_PROVER_URL", TEST_DATABASE_PROVER_URL)
+    .run()?;
 
     logger::outro(MSG_PROVER_TEST_SUCCESS);
     Ok(())
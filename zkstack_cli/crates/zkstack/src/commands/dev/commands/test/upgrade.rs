@@ -1,13 +1,17 @@
 use xshell::{cmd, Shell};
 use zkstack_cli_common::{cmd::Cmd, logger, spinner::Spinner};
 use zkstack_cli_config::EcosystemConfig;
+use zksync_basic_types::protocol_version::ProtocolSemanticVersion;
 
 use super::{args::upgrade::UpgradeArgs, utils::install_and_build_dependencies};
-use crate::commands::dev::messages::{MSG_UPGRADE_TEST_RUN_INFO, MSG_UPGRADE_TEST_RUN_SUCCESS};
+use crate::commands::dev::messages::{
+    msg_upgrade_test_invalid_versions, MSG_UPGRADE_TEST_RUN_INFO, MSG_UPGRADE_TEST_RUN_SUCCESS,
+};
 
 const UPGRADE_TESTS_PATH: &str = "core/tests/upgrade-test";
 
 pub fn run(shell: &Shell, args: UpgradeArgs) -> anyhow::Result<()> {
+    validate_versions(args.from_version, args.to_version)?;
     let ecosystem_config = EcosystemConfig::from_file(shell)?;
     shell.change_dir(ecosystem_config.link_to_code.join(UPGRADE_TESTS_PATH));
 
@@ -17,17 +21,73 @@ pub fn run(shell: &Shell, args: UpgradeArgs) -> anyhow::Result<()> {
         install_and_build_dependencies(shell, &ecosystem_config)?;
     }
 
-    run_test(shell, &ecosystem_config)?;
+    run_test(shell, &ecosystem_config, &args)?;
     logger::outro(MSG_UPGRADE_TEST_RUN_SUCCESS);
 
     Ok(())
 }
 
-fn run_test(shell: &Shell, ecosystem_config: &EcosystemConfig) -> anyhow::Result<()> {
+/// Checks that the upgrade goes to a newer minor version. Patch-only upgrades are not supported since the upgrade test
+/// always sends an L2 upgrade transaction with the nonce equal to the new minor version, which L1 rejects for
+/// patch upgrades. Versions themselves are validated when parsing args.
+fn validate_versions(
+    from_version: Option<ProtocolSemanticVersion>,
+    to_version: Option<ProtocolSemanticVersion>,
+) -> anyhow::Result<()> {
+    if let (Some(from), Some(to)) = (from_version, to_version) {
+        anyhow::ensure!(
+            from.minor < to.minor,
+            msg_upgrade_test_invalid_versions(from, to)
+        );
+    }
+    Ok(())
+}
+
+fn run_test(
+    shell: &Shell,
+    ecosystem_config: &EcosystemConfig,
+    args: &UpgradeArgs,
+) -> anyhow::Result<()> {
     Spinner::new(MSG_UPGRADE_TEST_RUN_INFO).freeze();
-    let cmd = Cmd::new(cmd!(shell, "yarn mocha tests/upgrade.test.ts"))
+    let mut cmd = Cmd::new(cmd!(shell, "yarn mocha tests/upgrade.test.ts"))
         .env("CHAIN_NAME", ecosystem_config.current_chain());
+    if let Some(from_version) = args.from_version {
+        cmd = cmd.env("UPGRADE_FROM_VERSION", from_version.to_string());
+    }
+    if let Some(to_version) = args.to_version {
+        cmd = cmd.env("UPGRADE_TO_VERSION", to_version.to_string());
+    }
     cmd.with_force_run().run()?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validating_versions() {
+        let version = |s: &str| Some(s.parse::<ProtocolSemanticVersion>().unwrap());
+
+        validate_versions(None, None).unwrap();
+        validate_versions(version("0.25.0"), None).unwrap();
+        validate_versions(None, version("0.26.0")).unwrap();
+        validate_versions(version("0.25.2"), version("0.26.0")).unwrap();
+        validate_versions(version("0.25.0"), version("0.27.1")).unwrap();
+
+        let err = validate_versions(version("0.26.0"), version("0.25.0")).unwrap_err();
+        assert!(err.to_string().contains("0.26.0"), "{err}");
+        validate_versions(version("0.26.0"), version("0.26.0")).unwrap_err();
+        // Patch-only upgrades
+        let err = validate_versions(version("0.25.0"), version("0.25.1")).unwrap_err();
+        assert!(err.to_string().contains("minor"), "{err}");
+    }
+
+    #[test]
+    fn malformed_versions_are_rejected() {
+        for version in ["24.1", "1.24.1", "0.24", "0.x.1", "v0.24.1"] {
+            version.parse::<ProtocolSemanticVersion>().unwrap_err();
+        }
+    }
+}
@@ -1,3 +1,7 @@
+use std::path::Path;
+
+use zksync_basic_types::{protocol_version::ProtocolSemanticVersion, Address};
+
 use super::commands::lint_utils::Target;
 
 // Ecosystem related messages
@@ -41,27 +45,61 @@ pub(super) fn msg_database_loading(gerund_verb: &str, dal: &str) -> String {
     format!("{gerund_verb} database for dal {dal}...")
 }
 
+pub(super) fn msg_database_dal_success(past_verb: &str, dal: &str) -> String {
+    format!("Database for dal {dal} {past_verb} successfully")
+}
+
+pub(super) fn msg_database_dal_migration_failed(dal: &str, err: &anyhow::Error) -> String {
+    format!("Failed migrating database for dal {dal}: {err:#}")
+}
+
+pub(super) fn msg_database_migration_failed_dals(dals: &[String]) -> String {
+    format!("Failed migrating databases for dals: {}", dals.join(", "))
+}
+
 pub(super) const MSG_DATABASE_CHECK_SQLX_DATA_GERUND: &str = "Checking";
 pub(super) const MSG_DATABASE_CHECK_SQLX_DATA_PAST: &str = "checked";
 pub(super) const MSG_DATABASE_DROP_GERUND: &str = "Dropping";
 pub(super) const MSG_DATABASE_DROP_PAST: &str = "dropped";
 pub(super) const MSG_DATABASE_MIGRATE_GERUND: &str = "Migrating";
 pub(super) const MSG_DATABASE_MIGRATE_PAST: &str = "migrated";
+pub(super) const MSG_DATABASE_MIGRATE_DRY_RUN_GERUND: &str = "Listing pending migrations for";
+pub(super) const MSG_DATABASE_MIGRATE_DRY_RUN_PAST: &str = "checked for pending migrations";
 pub(super) const MSG_DATABASE_PREPARE_GERUND: &str = "Preparing";
 pub(super) const MSG_DATABASE_PREPARE_PAST: &str = "prepared";
 pub(super) const MSG_DATABASE_RESET_GERUND: &str = "Resetting";
 pub(super) const MSG_DATABASE_RESET_PAST: &str = "reset";
 pub(super) const MSG_DATABASE_SETUP_GERUND: &str = "Setting up";
 pub(super) const MSG_DATABASE_SETUP_PAST: &str = "set up";
+pub(super) const MSG_DATABASE_VERIFY_SCHEMA_GERUND: &str = "Verifying schema of";
+pub(super) const MSG_DATABASE_VERIFY_SCHEMA_PAST: &str = "schema verified";
 pub(super) const MSG_DATABASE_COMMON_PROVER_HELP: &str = "Prover database";
 pub(super) const MSG_DATABASE_COMMON_PROVER_URL_HELP: &str =
     "URL of the Prover database. If not specified, it is used from the current chain's secrets";
 pub(super) const MSG_DATABASE_COMMON_CORE_URL_HELP: &str =
     "URL of the Core database. If not specified, it is used from the current chain's secrets.";
 pub(super) const MSG_DATABASE_COMMON_CORE_HELP: &str = "Core database";
+pub(super) const MSG_DATABASE_JSON_HELP: &str =
+    "Print results as newline-delimited JSON objects to stdout";
+pub(super) const MSG_DATABASE_MIGRATE_DRY_RUN_HELP: &str =
+    "Print pending migrations without applying them";
+pub(super) const MSG_DATABASE_MIGRATE_PARALLEL_HELP: &str =
+    "Migrate selected databases concurrently instead of one by one";
+pub(super) const MSG_DATABASE_MIGRATE_PARALLEL_DRY_RUN_WARNING: &str =
+    "`--parallel` has no effect with `--dry-run`; pending migrations will be listed sequentially";
 pub(super) const MSG_DATABASE_NEW_MIGRATION_DATABASE_HELP: &str =
     "Database to create new migration for";
 pub(super) const MSG_DATABASE_NEW_MIGRATION_NAME_HELP: &str = "Migration name";
+pub(super) const MSG_DATABASE_NEW_MIGRATION_TEMPLATE_HELP: &str =
+    "Pre-fill the migration with a SQL skeleton. Available templates: add-column, add-index, add-table";
+pub(super) const MSG_DATABASE_EXPORT_DATABASE_HELP: &str = "Database to export or import";
+pub(super) const MSG_DATABASE_EXPORT_URL_HELP: &str =
+    "URL of the database. If not specified, it is used from the current chain's secrets";
+pub(super) const MSG_DATABASE_EXPORT_TABLES_HELP: &str = "Comma-separated list of tables to export";
+pub(super) const MSG_DATABASE_EXPORT_TO_FILE_HELP: &str =
+    "Path to the gzip-compressed SQL file to write the dump to";
+pub(super) const MSG_DATABASE_EXPORT_FROM_FILE_HELP: &str =
+    "Path to the dump file created by `database export`";
 
 pub(super) const MSG_DATABASE_CHECK_SQLX_DATA_ABOUT: &str = "Check sqlx-data.json is up to date. If no databases are selected, all databases will be checked.";
 pub(super) const MSG_DATABASE_DROP_ABOUT: &str =
@@ -75,6 +113,11 @@ pub(super) const MSG_DATABASE_RESET_ABOUT: &str =
     "Reset databases. If no databases are selected, all databases will be reset.";
 pub(super) const MSG_DATABASE_SETUP_ABOUT: &str =
     "Setup databases. If no databases are selected, all databases will be setup.";
+pub(super) const MSG_DATABASE_EXPORT_ABOUT: &str =
+    "Dump selected tables of a database to a gzip-compressed SQL file using pg_dump";
+pub(super) const MSG_DATABASE_IMPORT_ABOUT: &str =
+    "Import a dump created by `database export`, replacing the dumped tables";
+pub(super) const MSG_DATABASE_VERIFY_SCHEMA_ABOUT: &str = "Verify that the schema of live databases matches the schema defined by migrations. If no databases are selected, all databases will be verified.";
 
 // Database new_migration messages
 pub(super) const MSG_DATABASE_NEW_MIGRATION_DB_PROMPT: &str =
@@ -86,8 +129,74 @@ pub(super) fn msg_database_new_migration_loading(dal: &str) -> String {
     format!("Creating new database migration for dal {}...", dal)
 }
 
+pub(super) fn msg_database_new_migration_not_found(name: &str) -> String {
+    format!("Cannot find files of the created migration `{name}`")
+}
+
 pub(super) const MSG_DATABASE_NEW_MIGRATION_SUCCESS: &str = "Migration created successfully";
 
+// Database export messages
+pub(super) fn msg_database_exporting(dal: &str) -> String {
+    format!("Exporting tables from database for dal {dal}...")
+}
+
+pub(super) fn msg_database_exported(dal: &str, file: &Path) -> String {
+    format!(
+        "Tables from database for dal {dal} exported to {}",
+        file.display()
+    )
+}
+
+pub(super) fn msg_database_importing(dal: &str) -> String {
+    format!("Importing dump into database for dal {dal}...")
+}
+
+pub(super) fn msg_database_imported(dal: &str, file: &Path) -> String {
+    format!(
+        "Dump {} imported into database for dal {dal}",
+        file.display()
+    )
+}
+
+// Database migrate messages
+pub(super) fn msg_database_no_pending_migrations(dal: &str) -> String {
+    format!("No pending migrations for dal {dal}")
+}
+
+pub(super) fn msg_database_pending_migration(
+    version: i64,
+    description: &str,
+    checksum: &str,
+) -> String {
+    format!("Pending migration {version} {description} (checksum: {checksum})")
+}
+
+// Database verify_schema messages
+pub(super) const MSG_DATABASE_VERIFY_SCHEMA_MISMATCH_ERR: &str =
+    "Database schema doesn't match migrations";
+
+pub(super) fn msg_database_schema_missing_column(dal: &str, table: &str, column: &str) -> String {
+    format!("[{dal}] Column `{table}.{column}` is missing in the database")
+}
+
+pub(super) fn msg_database_schema_unexpected_column(
+    dal: &str,
+    table: &str,
+    column: &str,
+) -> String {
+    format!("[{dal}] Column `{table}.{column}` is not defined by migrations")
+}
+
+pub(super) fn msg_database_schema_column_mismatch(
+    dal: &str,
+    table: &str,
+    column: &str,
+    expected: &str,
+    actual: &str,
+) -> String {
+    format!("[{dal}] Column `{table}.{column}` has definition `{actual}`, expected `{expected}`")
+}
+
 // Tests related messages
 pub(super) const MSG_INTEGRATION_TESTS_ABOUT: &str = "Run integration tests";
 pub(super) const MSG_REVERT_TEST_ABOUT: &str = "Run revert tests";
@@ -107,7 +216,9 @@ pub(super) const MSG_UNIT_TESTS_RUN_SUCCESS: &str = "Unit tests ran successfully
 pub(super) const MSG_USING_CARGO_NEXTEST: &str = "Using cargo-nextest for running tests";
 pub(super) const MSG_L1_CONTRACTS_ABOUT: &str = "Run L1 contracts tests";
 pub(super) const MSG_L1_CONTRACTS_TEST_SUCCESS: &str = "L1 contracts tests ran successfully";
-pub(super) const MSG_PROVER_TEST_ABOUT: &str = "Run prover tests";
+pub(super) const MSG_PROVER_TEST_ABOUT: &str = "Run prover tests, optionally filtered by test name";
+pub(super) const MSG_PROVER_TEST_FILTER_HELP: &str =
+    "Run just the prover tests whose names contain the given string. Passed to cargo test as a test name filter.";
 pub(super) const MSG_PROVER_TEST_SUCCESS: &str = "Prover tests ran successfully";
 pub(super) const MSG_RESETTING_TEST_DATABASES: &str = "Resetting test databases";
 
@@ -124,6 +235,64 @@ pub(super) const MSG_BUILD_L1_DA_CONTRACTS_HELP: &str = "Build L1 DA contracts";
 pub(super) const MSG_BUILD_L2_CONTRACTS_HELP: &str = "Build L2 contracts";
 pub(super) const MSG_BUILD_SYSTEM_CONTRACTS_HELP: &str = "Build system contracts";
 
+// Contract verification related messages
+pub(super) const MSG_CONTRACTS_VERIFY_ABOUT: &str =
+    "Verify a deployed L1 contract on a block explorer (Etherscan or Blockscout)";
+pub(super) const MSG_CONTRACTS_VERIFY_NETWORK_HELP: &str =
+    "Name of the network in `verification_networks` of the chain config specifying the explorer API";
+pub(super) const MSG_CONTRACTS_VERIFY_CONTRACT_HELP: &str =
+    "Contract to verify, as `<file>:<name>` or `<name>` if the contract is defined in `<name>.sol`";
+pub(super) const MSG_CONTRACTS_VERIFY_ADDRESS_HELP: &str = "Address of the deployed contract";
+pub(super) const MSG_CONTRACTS_VERIFY_CONSTRUCTOR_ARGS_HELP: &str =
+    "Hex-encoded ABI-encoded constructor arguments";
+pub(super) const MSG_CONTRACTS_VERIFY_TIMEOUT_ERR: &str =
+    "Contract verification is still pending; check its status on the explorer";
+
+pub(super) fn msg_verification_network_not_found(network: &str) -> String {
+    format!("Verification network `{network}` is not found in `verification_networks` of the chain config")
+}
+
+pub(super) fn msg_contract_artifact_read_err(path: &Path) -> String {
+    format!(
+        "Failed reading contract artifact at {}; make sure contracts are built",
+        path.display()
+    )
+}
+
+pub(super) fn msg_verifying_contract_spinner(contract: &str, address: Address) -> String {
+    format!("Verifying contract {contract} at {address:?}..")
+}
+
+pub(super) fn msg_contract_verified(contract: &str, address: Address) -> String {
+    format!("Contract {contract} at {address:?} verified successfully")
+}
+
+pub(super) fn msg_contract_already_verified(contract: &str, address: Address) -> String {
+    format!("Contract {contract} at {address:?} is already verified")
+}
+
+pub(super) fn msg_contract_verification_failed(contract: &str, reason: &str) -> String {
+    format!("Failed verifying contract {contract}: {reason}")
+}
+
+// Contract size report related messages
+pub(super) const MSG_CONTRACTS_SIZE_REPORT_ABOUT: &str =
+    "Print bytecode sizes of built contracts and highlight contracts close to the size limit";
+pub(super) const MSG_CONTRACTS_SIZE_REPORT_HEADER: &str = "Contract bytecode sizes";
+pub(super) const MSG_CONTRACTS_SIZE_REPORT_EMPTY: &str =
+    "No contract artifacts found; build contracts with `zkstack dev contracts` first";
+
+pub(super) fn msg_contract_artifacts_not_found(dir: &Path) -> String {
+    format!(
+        "Contract artifacts not found in {}; skipping",
+        dir.display()
+    )
+}
+
+pub(super) fn msg_contracts_near_size_limit(count: usize, threshold: f64) -> String {
+    format!("{count} contract(s) use at least {threshold}% of the bytecode size limit")
+}
+
 // Integration tests related messages
 pub(super) fn msg_integration_tests_run(external_node: bool) -> String {
     let base = "Running integration tests";
@@ -138,9 +307,36 @@ pub(super) const MSG_INTEGRATION_TESTS_RUN_SUCCESS: &str = "Integration tests ra
 pub(super) const MSG_INTEGRATION_TESTS_BUILDING_DEPENDENCIES: &str =
     "Building repository dependencies...";
 pub(super) const MSG_INTEGRATION_TESTS_BUILDING_CONTRACTS: &str = "Building test contracts...";
+pub(super) const MSG_INTEGRATION_TESTS_TIMEOUT_HELP: &str =
+    "Kill integration tests and exit with code 124 if they don't complete within the given number of seconds";
+
+pub(super) const MSG_INTEGRATION_TESTS_CAPTURE_TRACES_HELP: &str =
+    "On test failure, save call traces of transactions mentioned in the test output to the `test-traces` directory";
+pub(super) const MSG_INTEGRATION_TESTS_NO_TRACES_CAPTURED: &str =
+    "No transaction traces were captured for failed integration tests";
+pub(super) const MSG_INTEGRATION_TESTS_INTERRUPTED: &str =
+    "Integration tests were interrupted by a signal";
+
+pub(super) fn msg_integration_tests_timeout(timeout_secs: u64) -> String {
+    format!("Integration tests timed out after {timeout_secs}s")
+}
+
+pub(super) fn msg_integration_tests_trace_saved(path: &Path) -> String {
+    format!("Saved transaction trace to {}", path.display())
+}
+
+pub(super) fn msg_integration_tests_trace_failed(tx_hash: &str, err: &anyhow::Error) -> String {
+    format!("Failed capturing trace for transaction {tx_hash}: {err:#}")
+}
+
+pub(super) fn msg_integration_tests_traces_failed(err: &anyhow::Error) -> String {
+    format!("Failed capturing transaction traces for failed integration tests: {err:#}")
+}
 
 // Revert tests related messages
 pub(super) const MSG_REVERT_TEST_ENABLE_CONSENSUS_HELP: &str = "Enable consensus";
+pub(super) const MSG_REVERT_TEST_BLOCKS_BEFORE_REVERT_HELP: &str =
+    "Number of committed, but not executed L1 batches to revert";
 pub(super) const MSG_REVERT_TEST_RUN_INFO: &str = "Running revert and restart test";
 
 pub(super) fn msg_revert_tests_run(external_node: bool) -> String {
@@ -161,6 +357,21 @@ pub(super) const MSG_RECOVERY_TEST_RUN_SUCCESS: &str = "Recovery test ran succes
 // Upgrade tests related messages
 pub(super) const MSG_UPGRADE_TEST_RUN_INFO: &str = "Running upgrade test";
 pub(super) const MSG_UPGRADE_TEST_RUN_SUCCESS: &str = "Upgrade test ran successfully";
+pub(super) const MSG_UPGRADE_TEST_FROM_VERSION_HELP: &str =
+    "Protocol version the chain is expected to have before the upgrade, e.g. `0.25.0`";
+pub(super) const MSG_UPGRADE_TEST_TO_VERSION_HELP: &str =
+    "Protocol version to upgrade to, e.g. `0.26.0`; must have a newer minor version than the source version. \
+     By default, minor and patch versions are incremented";
+
+pub(super) fn msg_upgrade_test_invalid_versions(
+    from: ProtocolSemanticVersion,
+    to: ProtocolSemanticVersion,
+) -> String {
+    format!(
+        "Upgrade target version {to} must have a newer minor version than the source version {from}; \
+         patch-only upgrades are not supported"
+    )
+}
 
 // Cleaning related messages
 pub(super) const MSG_DOCKER_COMPOSE_DOWN: &str = "docker compose down -v";
@@ -171,6 +382,9 @@ pub(super) const MSG_CONTRACTS_CLEANING_FINISHED: &str =
 
 /// Snapshot creator related messages
 pub(super) const MSG_RUNNING_SNAPSHOT_CREATOR: &str = "Running snapshot creator";
+pub(super) const MSG_SNAPSHOT_EXPORT_FORMAT_HELP: &str = "Format of the exported snapshot metadata";
+pub(super) const MSG_SNAPSHOT_EXPORT_OUTPUT_HELP: &str =
+    "Path to the file to write the snapshot metadata to";
 
 // Lint related messages
 pub(super) fn msg_running_linters_for_files(targets: &[Target]) -> String {
@@ -194,6 +408,15 @@ pub(super) fn msg_running_fmt_for_extensions_spinner(targets: &[Target]) -> Stri
     format!("Running prettier for: {targets:?} and rustfmt")
 }
 
+pub(super) const MSG_LINT_CHECK_HELP: &str =
+    "Only report issues without applying fixes suggested by linters";
+pub(super) const MSG_LINT_FIX_HELP: &str =
+    "Apply fixes suggested by linters (the default unless `--check` is specified) and print how many files were modified";
+
+pub(super) fn msg_lint_fix_summary(modified_files: usize) -> String {
+    format!("Linters modified {modified_files} file(s)")
+}
+
 pub(super) const MSG_LINT_CONFIG_PATH_ERR: &str = "Lint config path error";
 pub(super) const MSG_RUNNING_CONTRACTS_LINTER_SPINNER: &str = "Running contracts linter..";
 pub(super) const MSG_RUNNING_CONTRACTS_FMT_SPINNER: &str = "Running prettier for contracts..";
@@ -204,12 +427,50 @@ pub(super) fn msg_file_is_not_formatted(file: &str) -> String {
     format!("File {} is not formatted", file)
 }
 
+pub(super) const MSG_FMT_CHECK_HELP: &str =
+    "Only check formatting without modifying files; fails if any file needs formatting";
+
+pub(super) const MSG_FMT_RUST_EDITION_HELP: &str =
+    "Rust edition to format code for; defaults to the edition specified in the crate manifests";
+
+pub(super) fn msg_fmt_check_failed(files_count: usize) -> String {
+    format!("{files_count} file(s) need formatting; run `zkstack dev fmt` to fix")
+}
+
+pub(super) fn msg_fmt_check_command_failed(stderr: &str) -> String {
+    format!("Formatter failed without reporting unformatted files:\n{stderr}")
+}
+
 // Test wallets related messages
 pub(super) const MSG_TEST_WALLETS_INFO: &str = "Print test wallets information";
 pub(super) const MSG_DESERIALIZE_TEST_WALLETS_ERR: &str = "Impossible to deserialize test wallets";
 pub(super) const MSG_WALLETS_TEST_SUCCESS: &str = "Wallets test success";
 
 pub(super) const MSG_LOADTEST_ABOUT: &str = "Run loadtest";
+pub(super) const MSG_LOADTEST_SCENARIO_HELP: &str =
+    "Path to a YAML file describing the loadtest scenario (accounts, duration, transaction weights and contract execution params)";
+
+pub(super) fn msg_loadtest_scenario_err(path: &Path) -> String {
+    format!("Failed loading loadtest scenario from {}", path.display())
+}
+pub(super) const MSG_BENCHMARK_ABOUT: &str =
+    "Run loadtest with the specified duration and print the last TPS reported by the loadtest";
+pub(super) const MSG_BENCHMARK_DURATION_SECS_HELP: &str = "Duration of the loadtest in seconds";
+pub(super) const MSG_BENCHMARK_TARGET_TPS_HELP: &str =
+    "Target TPS; passed to the loadtest as the expected transaction count (target TPS multiplied by the duration). \
+    The target is not checked against the reported TPS";
+pub(super) const MSG_BENCHMARK_ACCOUNTS_HELP: &str =
+    "Number of accounts sending transactions concurrently";
+pub(super) const MSG_BENCHMARK_TPS_NOT_REPORTED: &str = "Loadtest did not report TPS";
+
+pub(super) fn msg_benchmark_summary(reported_tps: f64, target_tps: Option<u64>) -> String {
+    match target_tps {
+        Some(target_tps) => {
+            format!("TPS reported by loadtest: {reported_tps:.2} (target: {target_tps})")
+        }
+        None => format!("TPS reported by loadtest: {reported_tps:.2}"),
+    }
+}
 
 pub(super) const MSG_OVERRIDE_CONFIG_PATH_HELP: &str = "Path to the config file to override";
 pub(super) const MSG_OVERRRIDE_CONFIG_PATH_PROMPT: &str =
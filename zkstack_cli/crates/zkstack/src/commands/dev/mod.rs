@@ -9,7 +9,7 @@ use xshell::Shell;
 
 use self::commands::{
     clean::CleanCommands, config_writer::ConfigWriterArgs, contracts::ContractsArgs,
-    database::DatabaseCommands, fmt::FmtArgs, lint::LintArgs, prover::ProverCommands,
+    database::DatabaseArgs, fmt::FmtArgs, lint::LintArgs, prover::ProverCommands,
     send_transactions::args::SendTransactionsArgs, snapshot::SnapshotCommands, test::TestCommands,
 };
 use crate::commands::dev::messages::{
@@ -27,8 +27,8 @@ mod messages;
 
 #[derive(Subcommand, Debug)]
 pub enum DevCommands {
-    #[command(subcommand, about = MSG_SUBCOMMAND_DATABASE_ABOUT, alias = "db")]
-    Database(DatabaseCommands),
+    #[command(about = MSG_SUBCOMMAND_DATABASE_ABOUT, alias = "db")]
+    Database(DatabaseArgs),
     #[command(subcommand, about = MSG_SUBCOMMAND_TESTS_ABOUT, alias = "t")]
     Test(TestCommands),
     #[command(subcommand, about = MSG_SUBCOMMAND_CLEAN)]
@@ -66,7 +66,7 @@ pub enum DevCommands {
 
 pub async fn run(shell: &Shell, args: DevCommands) -> anyhow::Result<()> {
     match args {
-        DevCommands::Database(command) => commands::database::run(shell, command).await?,
+        DevCommands::Database(args) => commands::database::run(shell, args).await?,
         DevCommands::Test(command) => commands::test::run(shell, command).await?,
         DevCommands::Clean(command) => commands::clean::run(shell, command)?,
         DevCommands::Snapshot(command) => commands::snapshot::run(shell, command).await?,